@@ -8,13 +8,15 @@ use std::{
 use bollard::service::Port;
 use jiff::{Timestamp, tz::TimeZone};
 use ratatui::{
-    style::Color,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{ListItem, ListState},
 };
+use regex::Regex;
 
-use crate::config::AppColors;
+use crate::config::{AppColors, CpuMode, MemoryFormula, SparklineMetric};
 
-use super::Header;
+use super::{Header, LogFilter, LogLevel, LogSearch};
 
 const ONE_KB: f64 = 1000.0;
 const ONE_MB: f64 = ONE_KB * 1000.0;
@@ -166,6 +168,37 @@ impl ContainerPorts {
     }
 }
 
+/// Cumulative totals for a single network interface of a container
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NetworkInterface {
+    pub name: String,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+}
+
+impl NetworkInterface {
+    /// Sum the rx & tx bytes across every interface
+    pub fn sum(networks: &[Self]) -> (u64, u64) {
+        networks.iter().fold((0, 0), |(rx, tx), i| {
+            (rx.saturating_add(i.rx_bytes), tx.saturating_add(i.tx_bytes))
+        })
+    }
+
+    /// Return as tuple of Strings, rx bytes, tx bytes, rx/tx errors, and rx/tx dropped
+    pub fn get_all(&self) -> (String, String, String, String) {
+        (
+            ByteStats::new(self.rx_bytes).to_string(),
+            ByteStats::new(self.tx_bytes).to_string(),
+            format!("{}/{}", self.rx_errors, self.tx_errors),
+            format!("{}/{}", self.rx_dropped, self.tx_dropped),
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StatefulList<T> {
     pub state: ListState,
@@ -241,11 +274,27 @@ impl From<String> for ContainerStatus {
 }
 
 impl ContainerStatus {
+    /// Every possible health label, used to emit the full health set in the metrics
+    pub const HEALTH_LABELS: [&str; 4] = ["healthy", "none", "starting", "unhealthy"];
+
     /// Check if a container is unhealthy
     pub fn unhealthy(&self) -> bool {
         self.contains("(unhealthy)")
     }
 
+    /// The health check status, `none` if the container doesn't have a health check
+    pub fn health(&self) -> &'static str {
+        if self.unhealthy() {
+            "unhealthy"
+        } else if self.contains("(healthy)") {
+            "healthy"
+        } else if self.contains("(health: starting)") {
+            "starting"
+        } else {
+            "none"
+        }
+    }
+
     /// Get a reference to the source string
     pub const fn get(&self) -> &String {
         &self.0
@@ -278,11 +327,35 @@ pub enum State {
 }
 
 impl State {
+    /// Every possible label, used to emit the full state set in the metrics
+    pub const LABELS: [&str; 7] = [
+        "dead",
+        "exited",
+        "paused",
+        "removing",
+        "restarting",
+        "running",
+        "unknown",
+    ];
+
     /// The container is alive if the start is Running, either healthy or unhealthy
     pub const fn is_alive(self) -> bool {
         matches!(self, Self::Running(_))
     }
 
+    /// The state as a single lowercase word, without the icon, used in the metrics & non-interactive output
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Dead => "dead",
+            Self::Exited => "exited",
+            Self::Paused => "paused",
+            Self::Removing => "removing",
+            Self::Restarting => "restarting",
+            Self::Running(_) => "running",
+            Self::Unknown => "unknown",
+        }
+    }
+
     /// Check if state is running & healthy
     pub const fn is_healthy(self) -> bool {
         match self {
@@ -294,7 +367,7 @@ impl State {
         }
     }
     /// Color of the state for the containers section
-    pub const fn get_color(self, colors: AppColors) -> Color {
+    pub const fn get_color(self, colors: &AppColors) -> Color {
         match self {
             Self::Dead => colors.container_state.dead,
             Self::Exited => colors.container_state.exited,
@@ -373,17 +446,19 @@ pub enum DockerCommand {
     Start,
     Stop,
     Resume,
+    Recreate,
     Delete,
 }
 
 impl DockerCommand {
-    pub const fn get_color(self, colors: AppColors) -> Color {
+    pub const fn get_color(self, colors: &AppColors) -> Color {
         match self {
             Self::Pause => colors.commands.pause,
             Self::Restart => colors.commands.restart,
             Self::Start => colors.commands.start,
             Self::Stop => colors.commands.stop,
             Self::Delete => colors.commands.delete,
+            Self::Recreate => colors.commands.recreate,
             Self::Resume => colors.commands.resume,
         }
     }
@@ -391,10 +466,18 @@ impl DockerCommand {
     /// Docker commands available depending on the containers state
     pub fn gen_vec(state: State) -> Vec<Self> {
         match state {
-            State::Dead | State::Exited => vec![Self::Start, Self::Restart, Self::Delete],
-            State::Paused => vec![Self::Resume, Self::Stop, Self::Delete],
+            State::Dead | State::Exited => {
+                vec![Self::Start, Self::Restart, Self::Recreate, Self::Delete]
+            }
+            State::Paused => vec![Self::Resume, Self::Stop, Self::Recreate, Self::Delete],
             State::Restarting => vec![Self::Stop, Self::Delete],
-            State::Running(_) => vec![Self::Pause, Self::Restart, Self::Stop, Self::Delete],
+            State::Running(_) => vec![
+                Self::Pause,
+                Self::Restart,
+                Self::Stop,
+                Self::Recreate,
+                Self::Delete,
+            ],
             _ => vec![Self::Delete],
         }
     }
@@ -405,6 +488,7 @@ impl fmt::Display for DockerCommand {
         let disp = match self {
             Self::Pause => "pause",
             Self::Delete => "delete",
+            Self::Recreate => "recreate",
             Self::Restart => "restart",
             Self::Start => "start",
             Self::Stop => "stop",
@@ -497,6 +581,9 @@ impl ByteStats {
     pub const fn new(value: u64) -> Self {
         Self(value)
     }
+    pub const fn get(self) -> u64 {
+        self.0
+    }
     pub const fn update(&mut self, value: u64) {
         self.0 = value;
     }
@@ -522,8 +609,346 @@ impl fmt::Display for ByteStats {
     }
 }
 
-pub type MemTuple = (Vec<(f64, f64)>, ByteStats, State);
+/// Struct for frequently updated per second rates, such as network or block I/O throughput
+/// Displayed as a ByteStats with a `/s` suffix
+/// Use trait Stats for use as generic in draw_chart function
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RateStats(u64);
+
+impl RateStats {
+    pub const fn new(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl Stats for RateStats {
+    fn get_value(&self) -> f64 {
+        self.0 as f64
+    }
+}
+
+impl fmt::Display for RateStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = format!("{}/s", ByteStats::new(self.0));
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+/// The current number of pids in a container, and the optional pids limit
+/// Docker reports an unlimited container as either no limit, a limit of 0, or a limit of u64::MAX
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ContainerPids {
+    pub current: u64,
+    pub limit: Option<u64>,
+}
+
+impl ContainerPids {
+    pub fn new(current: Option<u64>, limit: Option<u64>) -> Self {
+        Self {
+            current: current.unwrap_or_default(),
+            limit: limit.filter(|i| *i > 0 && *i < u64::MAX),
+        }
+    }
+
+    /// The color of the pids column, warning at 80% of the pids limit, critical at 95%
+    pub fn get_color(self, colors: &AppColors) -> Color {
+        let percentage = self.limit.map_or(0, |limit| {
+            u64::try_from(u128::from(self.current) * 100 / u128::from(limit)).unwrap_or(u64::MAX)
+        });
+        match percentage {
+            95.. => colors.containers.text_pids_critical,
+            80.. => colors.containers.text_pids_warning,
+            _ => colors.containers.text_pids,
+        }
+    }
+}
+
+/// Sort by number of pids
+impl Ord for ContainerPids {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.current.cmp(&other.current)
+    }
+}
+
+impl PartialOrd for ContainerPids {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for ContainerPids {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = self.limit.map_or_else(
+            || self.current.to_string(),
+            |limit| format!("{}/{limit}", self.current),
+        );
+        write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
+    }
+}
+
+/// Per second rates, and a history of said rates, calculated from a pair of cumulative byte counters
+/// Used for both network rx/tx and block I/O read/write, each rate has its own history, so that the spikes of both are kept when downsampled
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ByteRates {
+    pub current: (RateStats, RateStats),
+    pub history: (VecDeque<RateStats>, VecDeque<RateStats>),
+    buckets: (HistoryBucket<RateStats>, HistoryBucket<RateStats>),
+    /// The previous cumulative totals, and the time, in ms, at which docker recorded them
+    previous: Option<(u64, u64, i64)>,
+}
+
+impl ByteRates {
+    /// Use the previous cumulative totals to calculate the current per second rates
+    /// A counter that has gone backwards, i.e. the container has been restarted, is treated as zero
+    pub fn update(&mut self, totals: (u64, u64), timestamp_ms: i64, history: HistoryLength) {
+        let previous_elapsed = self.previous.and_then(|previous| {
+            u64::try_from(timestamp_ms - previous.2)
+                .ok()
+                .filter(|elapsed| *elapsed > 0)
+                .map(|elapsed| (previous, elapsed))
+        });
+        if let Some((previous, elapsed)) = previous_elapsed {
+            let per_second =
+                |current: u64, previous: u64| current.saturating_sub(previous) * 1000 / elapsed;
+            self.current = (
+                RateStats::new(per_second(totals.0, previous.0)),
+                RateStats::new(per_second(totals.1, previous.1)),
+            );
+            history.push(&mut self.history.0, &mut self.buckets.0, self.current.0);
+            history.push(&mut self.history.1, &mut self.buckets.1, self.current.1);
+        }
+        self.previous = Some((totals.0, totals.1, timestamp_ms));
+    }
+
+    /// Convert the rate histories, within the chart window, into two datasets for the charts function, alongside the max of either
+    #[allow(clippy::cast_precision_loss)]
+    pub fn get_chart_data(&self, state: State, points: usize) -> RateTuple {
+        let dataset = |history: &VecDeque<RateStats>| {
+            chart_window(history, points)
+                .enumerate()
+                .map(|(index, i)| (index as f64, i.get_value()))
+                .collect::<Vec<_>>()
+        };
+        let max = chart_window(&self.history.0, points)
+            .chain(chart_window(&self.history.1, points))
+            .max()
+            .copied()
+            .unwrap_or_default();
+        (
+            dataset(&self.history.0),
+            dataset(&self.history.1),
+            max,
+            state,
+        )
+    }
+}
+
+/// How many samples of each stats history are kept for the charts
+/// Long histories are downsampled, so that no more than MAX_POINTS are ever stored for each container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryLength {
+    /// Max number of points stored
+    capacity: usize,
+    /// Number of samples combined into each stored point
+    bucket_size: usize,
+}
+
+impl HistoryLength {
+    pub const MAX_POINTS: usize = 600;
+
+    /// Calculate the capacity & bucket size from the history length, in seconds, and the docker update interval, in ms
+    pub fn new(history_seconds: u32, interval_ms: u32) -> Self {
+        let samples = Self::samples(history_seconds, interval_ms);
+        let bucket_size = samples.div_ceil(Self::MAX_POINTS);
+        Self {
+            capacity: samples.div_ceil(bucket_size),
+            bucket_size,
+        }
+    }
+
+    /// The number of docker samples in the given number of seconds, at least 1
+    fn samples(seconds: u32, interval_ms: u32) -> usize {
+        usize::try_from((u64::from(seconds) * 1000).div_ceil(u64::from(interval_ms.max(1))))
+            .unwrap_or(Self::MAX_POINTS)
+            .max(1)
+    }
+
+    /// The number of stored points needed to cover the given chart window, in seconds
+    pub fn points(self, window_seconds: u32, interval_ms: u32) -> usize {
+        Self::samples(window_seconds, interval_ms)
+            .div_ceil(self.bucket_size)
+            .min(self.capacity)
+    }
+
+    /// Push a sample into a history, the last point is always the most recent sample, so that the current value is never downsampled
+    /// Once a bucket is complete, the last point is replaced with the max of the bucket, so spikes aren't lost
+    pub fn push<T: Copy + Ord>(
+        self,
+        history: &mut VecDeque<T>,
+        bucket: &mut HistoryBucket<T>,
+        value: T,
+    ) {
+        if bucket.count > 0 && bucket.count < self.bucket_size {
+            bucket.max = bucket.max.max(value);
+            bucket.count += 1;
+            if let Some(last) = history.back_mut() {
+                *last = value;
+            }
+        } else {
+            if bucket.count > 0
+                && let Some(last) = history.back_mut()
+            {
+                *last = bucket.max;
+            }
+            while history.len() >= self.capacity {
+                history.pop_front();
+            }
+            history.push_back(value);
+            *bucket = HistoryBucket {
+                max: value,
+                count: 1,
+            };
+        }
+    }
+}
+
+/// The currently incomplete bucket of a downsampled history
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryBucket<T> {
+    max: T,
+    count: usize,
+}
+
+/// Cpu throttling & per core usage, only shown in the cpu detail popup
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CpuDetail {
+    pub throttled: VecDeque<CpuStats>,
+    throttled_bucket: HistoryBucket<CpuStats>,
+    /// Cumulative time, in ns, that the container has been throttled for
+    pub throttled_time: u64,
+    pub per_core: Vec<CpuStats>,
+}
+
+impl CpuDetail {
+    pub fn update(
+        &mut self,
+        history: HistoryLength,
+        throttled: f64,
+        throttled_time: u64,
+        per_core: Vec<f64>,
+    ) {
+        history.push(
+            &mut self.throttled,
+            &mut self.throttled_bucket,
+            CpuStats::new(throttled),
+        );
+        self.throttled_time = throttled_time;
+        self.per_core = per_core.into_iter().map(CpuStats::new).collect();
+    }
+
+    /// Throttled in the most recent stats sample
+    pub fn is_throttled(&self) -> bool {
+        self.throttled.back().is_some_and(|i| i.get_value() > 0.0)
+    }
+
+    /// Dataset & max value for the throttled chart, within the chart window
+    #[allow(clippy::cast_precision_loss)]
+    pub fn get_chart_data(&self, points: usize) -> (Vec<(f64, f64)>, CpuStats) {
+        (
+            chart_window(&self.throttled, points)
+                .enumerate()
+                .map(|(index, i)| (index as f64, i.get_value()))
+                .collect(),
+            chart_window(&self.throttled, points)
+                .max()
+                .copied()
+                .unwrap_or_default(),
+        )
+    }
+}
+
+/// Breakdown of the memory usage of a container, only shown in the memory detail popup
+/// cgroup v1 doesn't report kernel memory, and only newer kernels report shmem, so these are optional
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryDetail {
+    pub usage: u64,
+    pub anon: u64,
+    pub file: u64,
+    pub active_file: u64,
+    pub inactive_file: u64,
+    pub kernel: Option<u64>,
+    pub shmem: Option<u64>,
+}
+
+impl MemoryDetail {
+    /// Calculate the memory usage of a container, using the given formula
+    pub const fn calculate(&self, formula: MemoryFormula) -> u64 {
+        match formula {
+            MemoryFormula::Docker => self.usage.saturating_sub(self.inactive_file),
+            MemoryFormula::Usage => self.usage,
+            MemoryFormula::Rss => self.anon,
+            MemoryFormula::WorkingSet => self.anon.saturating_add(self.active_file),
+        }
+    }
+}
+
+/// The cpus available to a container, used to normalise the cpu usage depending on the CpuMode
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CpuLimit {
+    /// Number of cpus on the host, as reported by docker stats
+    pub online_cpus: u64,
+    /// Cpu limit set on the container, in units of 10^-9 cpus, None if unlimited
+    pub nano_cpus: Option<u64>,
+    /// The container has been inspected in order to find the cpu limit
+    pub inspected: bool,
+}
+
+impl CpuLimit {
+    /// The value that the per core usage should be divided by
+    #[allow(clippy::cast_precision_loss)]
+    pub fn divisor(self, cpu_mode: CpuMode) -> f64 {
+        let host = self.online_cpus.max(1) as f64;
+        match cpu_mode {
+            CpuMode::PerCore => 1.0,
+            CpuMode::Host => host,
+            CpuMode::Quota => self
+                .nano_cpus
+                .filter(|i| *i > 0)
+                .map_or(host, |i| i as f64 / 1_000_000_000.0),
+        }
+    }
+}
+
+/// Memory dataset, the max value, the memory limit, and the state
+pub type MemTuple = (Vec<(f64, f64)>, ByteStats, ByteStats, State);
+/// Name, memory breakdown, memory limit, and state, for the memory detail popup
+pub type MemDetailTuple = (ContainerName, Option<MemoryDetail>, ByteStats, State);
 pub type CpuTuple = (Vec<(f64, f64)>, CpuStats, State);
+/// Two rate datasets, i.e. read & write, the max of either, and the state
+pub type RateTuple = (Vec<(f64, f64)>, Vec<(f64, f64)>, RateStats, State);
+/// The overview metric, the name & dataset of each plotted container, and the max value of all the datasets
+pub type OverviewTuple = (ChartOverview, Vec<(ContainerName, Vec<(f64, f64)>)>, f64);
+
+/// The overview chart plots the cpu, or memory, history of the busiest containers on a single chart
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChartOverview {
+    #[default]
+    Off,
+    Cpu,
+    Memory,
+}
+
+impl ChartOverview {
+    /// Cycle through each mode, used by the toggle_chart_overview key
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Cpu,
+            Self::Cpu => Self::Memory,
+            Self::Memory => Self::Off,
+        }
+    }
+}
 
 /// Used to make sure that each log entry, for each container, is unique,
 /// will only push a log entry into the logs vec if timestamp of said log entry isn't in the hashset
@@ -544,6 +969,11 @@ impl LogsTz {
         (Self(tz.to_owned()), content.to_owned())
     }
 
+    /// Parse the docker timestamp, used to order the log lines of multiple containers
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.0.parse::<Timestamp>().ok()
+    }
+
     /// Display the timestamp in a given format, and if provided, with a timezone offset
     pub fn display_with_formatter(&self, tz: Option<&TimeZone>, format: &str) -> Option<String> {
         self.0.parse::<Timestamp>().map_or(None, |t| {
@@ -558,92 +988,402 @@ impl LogsTz {
     }
 }
 
+/// A single, already sanitized, log entry, which may span multiple lines, alongside its docker timestamp
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    pub level: Option<LogLevel>,
+    pub lines: Vec<Line<'static>>,
+    /// The ansi stripped content of a JSON, or logfmt, line, kept so that it can be pretty printed, only set when structured_logs is enabled
+    pub structured: Option<String>,
+    pub tz: LogsTz,
+}
+
+impl LogLine {
+    /// Create a log line without a level, or structured content
+    #[cfg(test)]
+    pub const fn new(lines: Vec<Line<'static>>, tz: LogsTz) -> Self {
+        Self {
+            level: None,
+            lines,
+            structured: None,
+            tz,
+        }
+    }
+
+    /// Check if any of the lines of the entry match a regex
+    pub fn is_match(&self, regex: &Regex) -> bool {
+        self.lines.iter().any(|i| regex.is_match(&i.to_string()))
+    }
+}
+
 /// Store the logs alongside a HashSet, each log *should* generate a unique timestamp,
 /// so if we store the timestamp separately in a HashSet, we can then check if we should insert a log line into the
 /// stateful list dependent on whether the timestamp is in the HashSet or not
+/// Every line is kept, the stateful list holds the indexes of the lines that are visible with the current filter
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Logs {
-    logs: StatefulList<ListItem<'static>>,
+    filter: Option<LogFilter>,
+    lines: Vec<LogLine>,
+    /// Lines with a detected level below this are hidden, lines without a level are always shown
+    min_level: Option<LogLevel>,
+    visible: StatefulList<usize>,
+    /// Increased whenever the visible lines are rebuilt, rather than appended to
+    revision: usize,
     tz: HashSet<LogsTz>,
 }
 
 impl Default for Logs {
     fn default() -> Self {
-        let mut logs = StatefulList::new(vec![]);
-        logs.end();
+        let mut visible = StatefulList::new(vec![]);
+        visible.end();
         Self {
-            logs,
+            filter: None,
+            lines: vec![],
+            min_level: None,
+            visible,
+            revision: 0,
             tz: HashSet::new(),
         }
     }
 }
 
 impl Logs {
-    /// Only allow a new log line to be inserted if the log timestamp isn't in the tz HashSet
-    pub fn insert(&mut self, line: ListItem<'static>, tz: LogsTz) {
-        if self.tz.insert(tz) {
-            self.logs.items.push(line);
+    /// Create from already ordered lines, and select the last line
+    fn from_lines(lines: Vec<LogLine>) -> Self {
+        let mut logs = Self {
+            visible: StatefulList::new((0..lines.len()).collect()),
+            lines,
+            ..Self::default()
+        };
+        logs.end();
+        logs
+    }
+
+    /// Check if a line would be visible with the current filter & minimum level
+    fn is_visible(&self, line: &LogLine) -> bool {
+        self.filter.as_ref().is_none_or(|i| i.is_visible(line))
+            && self
+                .min_level
+                .is_none_or(|min| line.level.is_none_or(|level| level >= min))
+    }
+
+    /// Only allow a new log line to be inserted if the log timestamp isn't in the tz HashSet, returns true if inserted
+    pub fn insert(&mut self, line: LogLine) -> bool {
+        if self.tz.insert(line.tz.clone()) {
+            if self.is_visible(&line) {
+                self.visible.items.push(self.lines.len());
+            }
+            self.lines.push(line);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Insert a log line at a given position, rather than at the end, without checking the timestamp
+    /// The selected line is kept in place, unless the last line was selected, in which case the new last line is selected
+    fn insert_at(&mut self, position: usize, line: LogLine) {
+        let selected = self.visible.state.selected();
+        let follow = selected.is_none_or(|i| i + 1 >= self.visible.items.len());
+        let visible = self.is_visible(&line);
+        self.lines.insert(position, line);
+        for index in &mut self.visible.items {
+            if *index >= position {
+                *index += 1;
+            }
+        }
+        if visible {
+            let view_position = self.visible.items.partition_point(|i| *i < position);
+            self.visible.items.insert(view_position, position);
+            if follow {
+                self.visible.end();
+            } else if let Some(selected) = selected
+                && view_position <= selected
+            {
+                self.visible.state.select(Some(selected + 1));
+            }
+        }
+    }
+
+    /// The current filter
+    pub const fn get_filter(&self) -> Option<&LogFilter> {
+        self.filter.as_ref()
+    }
+
+    /// Set, or remove, the filter, and rebuild the visible lines, an empty, or invalid, filter shows every line
+    pub fn set_filter(&mut self, filter: Option<LogFilter>) {
+        self.filter = filter;
+        self.rebuild();
+    }
+
+    /// The current minimum level
+    pub const fn get_min_level(&self) -> Option<LogLevel> {
+        self.min_level
+    }
+
+    /// Set, or remove, the minimum level, and rebuild the visible lines
+    pub fn set_min_level(&mut self, min_level: Option<LogLevel>) {
+        self.min_level = min_level;
+        self.rebuild();
+    }
+
+    /// Rebuild the visible lines after the filter, or minimum level, has changed
+    /// The selected line stays selected if still visible, else the nearest visible line before it is selected, following the end of the logs if the last line was selected
+    fn rebuild(&mut self) {
+        let selected = self.visible.state.selected();
+        let follow = selected.is_none_or(|i| i + 1 >= self.visible.items.len());
+        let selected_line = selected.and_then(|i| self.visible.items.get(i).copied());
+
+        self.visible.items = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| self.is_visible(line))
+            .map(|(index, _)| index)
+            .collect();
+        self.revision += 1;
+
+        if follow || self.visible.items.is_empty() {
+            self.visible.state.select(None);
+            self.visible.end();
+        } else if let Some(selected_line) = selected_line {
+            let position = self.visible.items.partition_point(|i| *i <= selected_line);
+            self.visible.state.select(Some(position.saturating_sub(1)));
         }
     }
 
+    /// Edit the filter, creating an empty filter if one doesn't exist, and rebuild the visible lines
+    pub fn edit_filter(&mut self, edit: impl FnOnce(&mut LogFilter)) {
+        let mut filter = self.filter.take().unwrap_or_else(LogFilter::new);
+        edit(&mut filter);
+        self.set_filter(Some(filter));
+    }
+
     /// Get the logs vec, but instead of cloning to whole vec, only clone items with x of the currently selected index
     /// Where x is the abs different of the index plus the panel height & a padding
     /// The rest can be just empty list items
-    pub fn to_vec(&self, height: usize, padding: usize) -> Vec<ListItem<'static>> {
-        let current_index = self.logs.state.selected().unwrap_or_default();
-        self.logs
-            .items
-            .iter()
+    /// If a search is given, each match within the visible items is highlighted with the given style
+    pub fn to_vec(
+        &self,
+        height: usize,
+        padding: usize,
+        search: Option<(&LogSearch, Style)>,
+    ) -> Vec<ListItem<'static>> {
+        let current_index = self.visible.state.selected().unwrap_or_default();
+        self.lines()
             .enumerate()
             .map(|(index, item)| {
                 if current_index.abs_diff(index) <= height + padding {
-                    item.clone()
+                    search.map_or_else(
+                        || ListItem::new(item.lines.clone()),
+                        |(search, style)| {
+                            ListItem::new(
+                                item.lines
+                                    .iter()
+                                    .map(|line| search.highlight(line, style))
+                                    .collect::<Vec<_>>(),
+                            )
+                        },
+                    )
                 } else {
                     ListItem::from("")
                 }
             })
             .collect()
     }
+
+    /// The most recently inserted log line, whether visible or not
+    pub fn last(&self) -> Option<&LogLine> {
+        self.lines.last()
+    }
+
+    /// Every visible log line, in display order
+    pub fn lines(&self) -> impl Iterator<Item = &LogLine> {
+        self.visible.items.iter().filter_map(|i| self.lines.get(*i))
+    }
+
+    /// The revision of the visible lines, changes whenever the filter is changed
+    pub const fn revision(&self) -> usize {
+        self.revision
+    }
+
+    /// The index of the currently selected log line
+    pub const fn selected(&self) -> Option<usize> {
+        self.visible.state.selected()
+    }
+
+    /// Select a given log line, ignored if out of bounds
+    pub fn select(&mut self, index: usize) {
+        if index < self.visible.items.len() {
+            self.visible.state.select(Some(index));
+        }
+    }
+
     /// The rest of the methods are basically forwarding from the underlying StatefulList
     pub fn get_state_title(&self) -> String {
-        self.logs.get_state_title()
+        self.visible.get_state_title()
     }
 
     pub fn next(&mut self) {
-        self.logs.next();
+        self.visible.next();
     }
 
     pub fn previous(&mut self) {
-        self.logs.previous();
+        self.visible.previous();
     }
 
     pub fn end(&mut self) {
-        self.logs.end();
+        self.visible.end();
     }
     pub fn start(&mut self) {
-        self.logs.start();
+        self.visible.start();
     }
 
+    /// The number of visible log lines
     // TODO remove this once zigbuild uses Rust v1.87.0
     #[cfg(target_os = "macos")]
     #[allow(clippy::missing_const_for_fn)]
     pub fn len(&self) -> usize {
-        self.logs.items.len()
+        self.visible.items.len()
     }
 
     #[cfg(not(target_os = "macos"))]
     pub const fn len(&self) -> usize {
-        self.logs.items.len()
+        self.visible.items.len()
     }
 
     pub const fn state(&mut self) -> &mut ListState {
-        &mut self.logs.state
+        &mut self.visible.state
+    }
+}
+
+/// Colors used for the container name prefix of each line of the merged logs, assigned by the containers position
+const MERGED_LOGS_COLORS: [Color; 12] = [
+    Color::Cyan,
+    Color::Yellow,
+    Color::Green,
+    Color::Magenta,
+    Color::Blue,
+    Color::Red,
+    Color::LightCyan,
+    Color::LightYellow,
+    Color::LightGreen,
+    Color::LightMagenta,
+    Color::LightBlue,
+    Color::LightRed,
+];
+
+/// The logs of multiple containers, interleaved by their docker timestamps, with each line prefixed by a coloured container name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergedLogs {
+    ids: Vec<ContainerId>,
+    logs: Logs,
+    width: usize,
+}
+
+impl MergedLogs {
+    /// Merge the current logs of the given containers, and select the most recent line
+    pub fn new(containers: &[&ContainerItem]) -> Self {
+        let width = containers
+            .iter()
+            .map(|i| i.name.get().chars().count())
+            .max()
+            .unwrap_or_default();
+        let mut items = containers
+            .iter()
+            .enumerate()
+            .flat_map(|(index, container)| {
+                container
+                    .logs
+                    .lines
+                    .iter()
+                    .map(move |line| Self::prefix(line, container.name.get(), index, width))
+            })
+            .collect::<Vec<_>>();
+        items.sort_by_cached_key(|i| i.tz.timestamp());
+
+        Self {
+            ids: containers.iter().map(|i| i.id.clone()).collect(),
+            logs: Logs::from_lines(items),
+            width,
+        }
+    }
+
+    /// Prefix the first line of an entry with the padded container name, and indent any following lines to match
+    fn prefix(line: &LogLine, name: &str, index: usize, width: usize) -> LogLine {
+        let style = Style::default().fg(MERGED_LOGS_COLORS[index % MERGED_LOGS_COLORS.len()]);
+        let lines = line
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(line_index, content)| {
+                let name = if line_index == 0 { name } else { "" };
+                let mut content = content.clone();
+                content
+                    .spans
+                    .insert(0, Span::styled(format!("{name:<width$} │ "), style));
+                content
+            })
+            .collect();
+        LogLine {
+            level: line.level,
+            lines,
+            structured: line.structured.clone(),
+            tz: line.tz.clone(),
+        }
+    }
+
+    /// Check if the merged logs are made up of exactly the given containers, ignoring their order
+    pub fn matches(&self, containers: &[&ContainerItem]) -> bool {
+        self.ids.len() == containers.len() && containers.iter().all(|i| self.ids.contains(&i.id))
     }
+
+    /// Insert a new log line, of a given container, in timestamp order
+    /// The selected line is kept in place, unless the last line was selected, in which case the new last line is selected
+    pub fn insert(&mut self, id: &ContainerId, name: &str, line: &LogLine) {
+        if let Some(index) = self.ids.iter().position(|i| i == id) {
+            let line = Self::prefix(line, name, index, self.width);
+            let timestamp = line.tz.timestamp();
+            let position = self
+                .logs
+                .lines
+                .partition_point(|i| i.tz.timestamp() <= timestamp);
+            self.logs.insert_at(position, line);
+        }
+    }
+
+    /// The number of containers that have been merged
+    pub const fn container_count(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub const fn logs(&self) -> &Logs {
+        &self.logs
+    }
+
+    pub const fn logs_mut(&mut self) -> &mut Logs {
+        &mut self.logs
+    }
+}
+
+/// Block characters used to draw the sparkline column, from lowest to highest
+const SPARKLINE_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// The most recent points of a stats history
+fn chart_window<T>(stats: &VecDeque<T>, points: usize) -> impl Iterator<Item = &T> {
+    stats.iter().skip(stats.len().saturating_sub(points))
 }
 
 /// Info for each container
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ContainerItem {
+    pub block_io: ByteRates,
+    /// Cumulative block I/O totals, as reported by docker
+    pub block_read: ByteStats,
+    pub block_write: ByteStats,
+    pub cpu_detail: CpuDetail,
+    pub cpu_limit: CpuLimit,
+    pub cpu_bucket: HistoryBucket<CpuStats>,
     pub cpu_stats: VecDeque<CpuStats>,
     pub created: u64,
     pub docker_controls: StatefulList<DockerCommand>,
@@ -652,30 +1392,26 @@ pub struct ContainerItem {
     pub is_oxker: bool,
     pub last_updated: u64,
     pub logs: Logs,
+    /// Marked containers are merged in the logs panel, instead of every filtered container
+    pub marked: bool,
+    pub mem_detail: Option<MemoryDetail>,
     pub mem_limit: ByteStats,
+    pub mem_bucket: HistoryBucket<ByteStats>,
     pub mem_stats: VecDeque<ByteStats>,
     pub name: ContainerName,
+    pub net_io: ByteRates,
+    pub networks: Vec<NetworkInterface>,
+    pub pids: ContainerPids,
     pub ports: Vec<ContainerPorts>,
     pub rx: ByteStats,
     pub state: State,
+    /// The time, in ms, of each point in the cpu stats history
+    pub stats_times: VecDeque<i64>,
     pub status: ContainerStatus,
+    pub time_bucket: HistoryBucket<i64>,
     pub tx: ByteStats,
 }
 
-/// Basic display information, for when running in debug mode
-impl fmt::Display for ContainerItem {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{}, {}, {}, {}",
-            self.id.get_short(),
-            self.name,
-            self.cpu_stats.back().unwrap_or(&CpuStats::new(0.0)),
-            self.mem_stats.back().unwrap_or(&ByteStats::new(0))
-        )
-    }
-}
-
 impl ContainerItem {
     #[allow(clippy::too_many_arguments)]
     /// Create a new container item
@@ -693,6 +1429,10 @@ impl ContainerItem {
         docker_controls.start();
 
         Self {
+            block_io: ByteRates::default(),
+            block_read: ByteStats::default(),
+            block_write: ByteStats::default(),
+            cpu_bucket: HistoryBucket::default(),
             cpu_stats: VecDeque::with_capacity(60),
             created,
             docker_controls,
@@ -701,67 +1441,237 @@ impl ContainerItem {
             is_oxker,
             last_updated: 0,
             logs: Logs::default(),
+            marked: false,
+            mem_detail: None,
             mem_limit: ByteStats::default(),
+            mem_bucket: HistoryBucket::default(),
             mem_stats: VecDeque::with_capacity(60),
             name: name.into(),
+            net_io: ByteRates::default(),
+            networks: vec![],
+            cpu_detail: CpuDetail::default(),
+            cpu_limit: CpuLimit::default(),
+            pids: ContainerPids::default(),
             ports,
             rx: ByteStats::default(),
             state,
+            stats_times: VecDeque::with_capacity(60),
             status,
+            time_bucket: HistoryBucket::default(),
             tx: ByteStats::default(),
         }
     }
 
-    /// Find the max value in the cpu stats VecDeque
-    fn max_cpu_stats(&self) -> CpuStats {
-        self.cpu_stats
-            .iter()
+    /// Find the max value in the cpu stats VecDeque, within the chart window, normalised by the given divisor
+    fn max_cpu_stats(&self, divisor: f64, points: usize) -> CpuStats {
+        chart_window(&self.cpu_stats, points)
             .max()
-            .map_or_else(CpuStats::default, |value| *value)
+            .map_or_else(CpuStats::default, |value| {
+                CpuStats::new(value.get_value() / divisor)
+            })
     }
 
-    /// Find the max value in the mem stats VecDeque
-    fn max_mem_stats(&self) -> ByteStats {
-        self.mem_stats
-            .iter()
+    /// The current memory usage as a percentage of the memory limit, None if no limit is reported
+    #[allow(clippy::cast_precision_loss)]
+    pub fn mem_percentage(&self) -> Option<f64> {
+        let limit = self.mem_limit.get_value();
+        (limit > 0.0).then(|| {
+            self.mem_stats
+                .back()
+                .map_or(0.0, |current| current.0 as f64 / limit * 100.0)
+        })
+    }
+
+    /// The memory column, either "current / limit", or the current usage as a percentage of the limit
+    pub fn mem_display(&self, show_mem_percent: bool) -> Option<String> {
+        show_mem_percent.then(|| {
+            self.mem_percentage()
+                .map_or_else(|| "n/a".to_owned(), |i| format!("{i:05.2}%"))
+        })
+    }
+
+    /// The style of the whole row, when the memory usage is at, or above, the warning or critical percentage of the memory limit
+    pub fn get_mem_alert_style(
+        &self,
+        colors: &AppColors,
+        warning: u8,
+        critical: u8,
+    ) -> Option<Style> {
+        self.mem_percentage().and_then(|percentage| {
+            if percentage >= f64::from(critical) {
+                Some(
+                    Style::default()
+                        .fg(colors.containers.memory_critical)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else if percentage >= f64::from(warning) {
+                Some(Style::default().fg(colors.containers.memory_warning))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Find the max value in the mem stats VecDeque, within the chart window
+    fn max_mem_stats(&self, points: usize) -> ByteStats {
+        chart_window(&self.mem_stats, points)
             .max()
             .map_or_else(ByteStats::default, |value| *value)
     }
 
-    /// Convert cpu stats into a vec for the charts function
+    /// Convert cpu stats, within the chart window, into a vec for the charts function, normalised by the given divisor
     #[allow(clippy::cast_precision_loss)]
-    fn get_cpu_dataset(&self) -> Vec<(f64, f64)> {
-        self.cpu_stats
-            .iter()
+    fn get_cpu_dataset(&self, divisor: f64, points: usize) -> Vec<(f64, f64)> {
+        chart_window(&self.cpu_stats, points)
             .enumerate()
-            .map(|i| (i.0 as f64, i.1.0))
+            .map(|i| (i.0 as f64, i.1.0 / divisor))
             .collect::<Vec<_>>()
     }
 
-    /// Convert mem stats into a Vec for the charts function
+    /// Convert mem stats, within the chart window, into a Vec for the charts function
     #[allow(clippy::cast_precision_loss)]
-    fn get_mem_dataset(&self) -> Vec<(f64, f64)> {
-        self.mem_stats
-            .iter()
+    fn get_mem_dataset(&self, points: usize) -> Vec<(f64, f64)> {
+        chart_window(&self.mem_stats, points)
             .enumerate()
             .map(|i| (i.0 as f64, i.1.0 as f64))
             .collect::<Vec<_>>()
     }
 
-    /// Get all cpu chart data
-    fn get_cpu_chart_data(&self) -> CpuTuple {
-        (self.get_cpu_dataset(), self.max_cpu_stats(), self.state)
+    /// Get all cpu chart data, so that the y-axis follows the CpuMode, the dataset & max are normalised by the same divisor
+    fn get_cpu_chart_data(&self, cpu_mode: CpuMode, points: usize) -> CpuTuple {
+        let divisor = self.cpu_limit.divisor(cpu_mode);
+        (
+            self.get_cpu_dataset(divisor, points),
+            self.max_cpu_stats(divisor, points),
+            self.state,
+        )
     }
 
     /// Get all mem chart data
-    fn get_mem_chart_data(&self) -> MemTuple {
-        (self.get_mem_dataset(), self.max_mem_stats(), self.state)
+    fn get_mem_chart_data(&self, points: usize) -> MemTuple {
+        (
+            self.get_mem_dataset(points),
+            self.max_mem_stats(points),
+            self.mem_limit,
+            self.state,
+        )
     }
 
-    /// Get chart info for cpu & memory in one function
+    /// Get chart info for cpu & memory in one function, only the most recent points, as set by the chart window, are included
     /// So only need to call .lock() once
-    pub fn get_chart_data(&self) -> (CpuTuple, MemTuple) {
-        (self.get_cpu_chart_data(), self.get_mem_chart_data())
+    pub fn get_chart_data(&self, cpu_mode: CpuMode, points: usize) -> (CpuTuple, MemTuple) {
+        (
+            self.get_cpu_chart_data(cpu_mode, points),
+            self.get_mem_chart_data(points),
+        )
+    }
+
+    /// The latest cpu, or memory, value, used to rank containers on the overview chart
+    #[allow(clippy::cast_precision_loss)]
+    pub fn overview_current(&self, overview: ChartOverview, cpu_mode: CpuMode) -> f64 {
+        match overview {
+            ChartOverview::Off => 0.0,
+            ChartOverview::Cpu => self.cpu_current(cpu_mode).unwrap_or_default().get_value(),
+            ChartOverview::Memory => self.mem_stats.back().map_or(0.0, |i| i.0 as f64),
+        }
+    }
+
+    /// The cpu, or memory, dataset for the overview chart
+    /// Aligned to the right of the chart window, so that the latest points of every container line up
+    #[allow(clippy::cast_precision_loss)]
+    pub fn get_overview_dataset(
+        &self,
+        overview: ChartOverview,
+        cpu_mode: CpuMode,
+        points: usize,
+    ) -> Vec<(f64, f64)> {
+        let dataset = match overview {
+            ChartOverview::Off => vec![],
+            ChartOverview::Cpu => self.get_cpu_dataset(self.cpu_limit.divisor(cpu_mode), points),
+            ChartOverview::Memory => self.get_mem_dataset(points),
+        };
+        let offset = points.saturating_sub(dataset.len()) as f64;
+        dataset.into_iter().map(|(x, y)| (x + offset, y)).collect()
+    }
+
+    /// The time, in ms, of each point in the chart window
+    pub fn get_chart_times(&self, points: usize) -> Vec<i64> {
+        chart_window(&self.stats_times, points).copied().collect()
+    }
+
+    /// The latest cpu usage, normalised based on the CpuMode
+    pub fn cpu_current(&self, cpu_mode: CpuMode) -> Option<CpuStats> {
+        self.cpu_stats
+            .back()
+            .map(|i| CpuStats::new(i.get_value() / self.cpu_limit.divisor(cpu_mode)))
+    }
+
+    /// The most recent cpu, or memory, history as block characters, scaled between zero and the max value shown
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn sparkline(&self, metric: SparklineMetric, width: u8) -> String {
+        let width = usize::from(width);
+        let values = match metric {
+            SparklineMetric::Cpu => chart_window(&self.cpu_stats, width)
+                .map(Stats::get_value)
+                .collect::<Vec<_>>(),
+            SparklineMetric::Memory => chart_window(&self.mem_stats, width)
+                .map(|i| i.0 as f64)
+                .collect::<Vec<_>>(),
+        };
+        let max = values.iter().copied().fold(0.0, f64::max);
+        values
+            .into_iter()
+            .map(|value| {
+                if max > 0.0 {
+                    let index = (value / max * 7.0).round() as usize;
+                    SPARKLINE_BLOCKS[index.min(7)]
+                } else {
+                    SPARKLINE_BLOCKS[0]
+                }
+            })
+            .collect()
+    }
+
+    /// The latest cpu usage, with a trailing `!` if the container was throttled in the latest sample
+    pub fn cpu_display(&self, cpu_mode: CpuMode) -> String {
+        let cpu = self.cpu_current(cpu_mode).unwrap_or_default();
+        if self.cpu_detail.is_throttled() {
+            format!("{cpu}!")
+        } else {
+            cpu.to_string()
+        }
+    }
+
+    /// Get the block I/O chart data, within the chart window, read & write rates are plotted on the same chart
+    pub fn get_io_chart_data(&self, points: usize) -> RateTuple {
+        self.block_io.get_chart_data(self.state, points)
+    }
+
+    /// Get the network chart data, within the chart window, rx & tx rates are plotted on the same chart
+    pub fn get_net_chart_data(&self, points: usize) -> RateTuple {
+        self.net_io.get_chart_data(self.state, points)
+    }
+
+    /// Get the rx value to display, either the per second rate, or the cumulative total
+    pub fn rx_display(&self, show_net_total: bool) -> String {
+        if show_net_total {
+            self.rx.to_string()
+        } else {
+            self.net_io.current.0.to_string()
+        }
+    }
+
+    /// Get the tx value to display, either the per second rate, or the cumulative total
+    pub fn tx_display(&self, show_net_total: bool) -> String {
+        if show_net_total {
+            self.tx.to_string()
+        } else {
+            self.net_io.current.1.to_string()
+        }
     }
 }
 
@@ -773,10 +1683,14 @@ pub struct Columns {
     pub status: (Header, u8),
     pub cpu: (Header, u8),
     pub mem: (Header, u8, u8),
+    pub sparkline: Option<(Header, u8)>,
     pub id: (Header, u8),
     pub image: (Header, u8),
     pub net_rx: (Header, u8),
     pub net_tx: (Header, u8),
+    pub block_read: (Header, u8),
+    pub block_write: (Header, u8),
+    pub pids: (Header, u8),
 }
 
 impl Columns {
@@ -788,10 +1702,14 @@ impl Columns {
             status: (Header::Status, 6),
             cpu: (Header::Cpu, 3),
             mem: (Header::Memory, 7, 7),
+            sparkline: None,
             id: (Header::Id, 8),
             image: (Header::Image, 5),
             net_rx: (Header::Rx, 4),
             net_tx: (Header::Tx, 4),
+            block_read: (Header::BlockRead, 8),
+            block_write: (Header::BlockWrite, 9),
+            pids: (Header::Pids, 4),
         }
     }
 }
@@ -801,14 +1719,134 @@ impl Columns {
 mod tests {
 
     use jiff::tz::TimeZone;
-    use ratatui::widgets::ListItem;
+    use ratatui::style::Color;
 
     use crate::{
-        app_data::{ContainerImage, Logs, LogsTz, RunningState},
+        app_data::{
+            ContainerId, ContainerImage, ContainerItem, LogLine, Logs, LogsTz, MergedLogs,
+            RunningState,
+        },
         ui::log_sanitizer,
     };
 
-    use super::{ByteStats, ContainerName, ContainerStatus, CpuStats, State};
+    use crate::config::AppColors;
+
+    use std::collections::VecDeque;
+
+    use super::{
+        ByteStats, ContainerName, ContainerPids, ContainerStatus, CpuStats, HistoryBucket,
+        HistoryLength, MemoryDetail, State,
+    };
+    use crate::config::{MemoryFormula, SparklineMetric};
+
+    #[test]
+    /// Sparkline only includes the most recent samples, scaled between zero and the max value shown
+    fn test_container_state_sparkline() {
+        let (_, mut containers) = crate::tests::gen_containers();
+        let item = &mut containers[0];
+        assert_eq!(item.sparkline(SparklineMetric::Cpu, 5), "");
+
+        item.cpu_stats = (0..=10).map(|i| CpuStats::new(f64::from(i))).collect();
+        item.mem_stats = VecDeque::from([ByteStats::new(0), ByteStats::new(0)]);
+
+        assert_eq!(item.sparkline(SparklineMetric::Cpu, 8), "▃▄▅▅▆▇▇█");
+        assert_eq!(item.sparkline(SparklineMetric::Cpu, 20).chars().count(), 11);
+        assert_eq!(item.sparkline(SparklineMetric::Memory, 8), "▁▁");
+    }
+
+    #[test]
+    /// Health check status is parsed from the container status
+    fn test_container_state_status_health() {
+        for (status, expected) in [
+            ("Up 1 hour", "none"),
+            ("Up 1 hour (healthy)", "healthy"),
+            ("Up 1 hour (unhealthy)", "unhealthy"),
+            ("Up 2 seconds (health: starting)", "starting"),
+            ("Exited (0) 10 hours ago", "none"),
+        ] {
+            assert_eq!(ContainerStatus::from(status.to_owned()).health(), expected);
+            assert!(ContainerStatus::HEALTH_LABELS.contains(&expected));
+        }
+    }
+
+    #[test]
+    /// Every state label is included in the full set of labels used by the metrics
+    fn test_container_state_state_labels() {
+        for state in [
+            State::Dead,
+            State::Exited,
+            State::Paused,
+            State::Removing,
+            State::Restarting,
+            State::Running(RunningState::Healthy),
+            State::Running(RunningState::Unhealthy),
+            State::Unknown,
+        ] {
+            assert!(State::LABELS.contains(&state.label()), "{state:?}");
+        }
+    }
+
+    #[test]
+    /// A short history keeps every sample, a long history is downsampled, keeping the max of each bucket, and the latest sample is always last
+    fn test_container_state_history_length() {
+        let history = HistoryLength::new(60, 1000);
+        assert_eq!(history.points(60, 1000), 60);
+
+        let mut stats = VecDeque::new();
+        let mut bucket = HistoryBucket::default();
+        for i in 0..100 {
+            history.push(&mut stats, &mut bucket, ByteStats::new(i));
+        }
+        assert_eq!(stats.len(), 60);
+        assert_eq!(stats.front(), Some(&ByteStats::new(40)));
+        assert_eq!(stats.back(), Some(&ByteStats::new(99)));
+
+        // One hour at a 1 second interval, is 3600 samples, downsampled into 600 buckets of 6
+        let history = HistoryLength::new(3600, 1000);
+        assert_eq!(history.points(3600, 1000), HistoryLength::MAX_POINTS);
+        assert_eq!(history.points(60, 1000), 10);
+
+        let mut stats = VecDeque::new();
+        let mut bucket = HistoryBucket::default();
+        for i in [1, 9, 2, 3, 4, 5, 6, 7] {
+            history.push(&mut stats, &mut bucket, ByteStats::new(i));
+        }
+        assert_eq!(
+            stats,
+            VecDeque::from([ByteStats::new(9), ByteStats::new(7)])
+        );
+
+        for i in 0..10_000 {
+            history.push(&mut stats, &mut bucket, ByteStats::new(i));
+        }
+        assert_eq!(stats.len(), HistoryLength::MAX_POINTS);
+        assert_eq!(stats.back(), Some(&ByteStats::new(9999)));
+    }
+
+    #[test]
+    /// Memory usage is calculated using the given formula
+    fn test_container_state_memory_detail_calculate() {
+        let detail = MemoryDetail {
+            usage: 1000,
+            anon: 400,
+            file: 500,
+            active_file: 200,
+            inactive_file: 300,
+            kernel: Some(100),
+            shmem: None,
+        };
+        assert_eq!(detail.calculate(MemoryFormula::Docker), 700);
+        assert_eq!(detail.calculate(MemoryFormula::Usage), 1000);
+        assert_eq!(detail.calculate(MemoryFormula::Rss), 400);
+        assert_eq!(detail.calculate(MemoryFormula::WorkingSet), 600);
+
+        let detail = MemoryDetail {
+            usage: 100,
+            inactive_file: 300,
+            ..MemoryDetail::default()
+        };
+        assert_eq!(detail.calculate(MemoryFormula::Docker), 0);
+    }
 
     #[test]
     /// Display CpuStats as a string
@@ -842,6 +1880,36 @@ mod tests {
         test(150_000_000_000, "150.00 GB");
     }
 
+    #[test]
+    /// ContainerPids only shows a limit if one is set, with unlimited values ignored
+    fn test_container_state_pids_to_string() {
+        let test = |current: Option<u64>, limit: Option<u64>, s: &str| {
+            assert_eq!(ContainerPids::new(current, limit).to_string(), s);
+        };
+
+        test(None, None, "0");
+        test(Some(12), None, "12");
+        test(Some(12), Some(0), "12");
+        test(Some(12), Some(u64::MAX), "12");
+        test(Some(12), Some(4096), "12/4096");
+    }
+
+    #[test]
+    /// ContainerPids color changes as the number of pids approaches the pids limit
+    fn test_container_state_pids_color() {
+        let colors = AppColors::new();
+        let test = |current: u64, limit: Option<u64>| {
+            ContainerPids::new(Some(current), limit).get_color(&colors)
+        };
+
+        assert_eq!(test(1000, None), colors.containers.text_pids);
+        assert_eq!(test(79, Some(100)), colors.containers.text_pids);
+        assert_eq!(test(80, Some(100)), colors.containers.text_pids_warning);
+        assert_eq!(test(94, Some(100)), colors.containers.text_pids_warning);
+        assert_eq!(test(95, Some(100)), colors.containers.text_pids_critical);
+        assert_eq!(test(120, Some(100)), colors.containers.text_pids_critical);
+    }
+
     #[test]
     /// ContainerName as string truncated correctly
     fn test_container_state_container_name_to_string() {
@@ -941,21 +2009,150 @@ mod tests {
         let mut logs = Logs::default();
         let line = log_sanitizer::remove_ansi(input);
 
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line), tz);
+        logs.insert(LogLine::new(line.clone(), tz.clone()));
+        logs.insert(LogLine::new(line.clone(), tz.clone()));
+        logs.insert(LogLine::new(line, tz));
 
-        assert_eq!(logs.logs.items.len(), 1);
+        assert_eq!(logs.len(), 1);
 
         let input = "2023-01-15T19:13:30.783138328Z Lorem ipsum dolor sit amet";
         let (tz, _) = LogsTz::splitter(input);
         let line = log_sanitizer::remove_ansi(input);
 
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line.clone()), tz.clone());
-        logs.insert(ListItem::new(line), tz);
+        logs.insert(LogLine::new(line.clone(), tz.clone()));
+        logs.insert(LogLine::new(line.clone(), tz.clone()));
+        logs.insert(LogLine::new(line, tz));
 
-        assert_eq!(logs.logs.items.len(), 2);
+        assert_eq!(logs.len(), 2);
+    }
+
+    #[test]
+    /// Merged logs are sorted by timestamp, each line is prefixed with the padded container name, and new lines keep the selected line in place
+    fn test_container_state_merged_logs() {
+        let gen_container = |id: &str, name: &str, seconds: &[u8]| {
+            let mut container = ContainerItem::new(
+                1,
+                ContainerId::from(id),
+                "image".to_owned(),
+                false,
+                name.to_owned(),
+                vec![],
+                State::Running(RunningState::Healthy),
+                ContainerStatus::from("Up 1 hour".to_owned()),
+            );
+            for i in seconds {
+                let (tz, content) =
+                    LogsTz::splitter(&format!("2025-01-01T00:00:0{i}.000000000Z line {i}"));
+                container
+                    .logs
+                    .insert(LogLine::new(log_sanitizer::remove_ansi(&content), tz));
+            }
+            container
+        };
+        let to_strings = |merged: &MergedLogs| {
+            merged
+                .logs
+                .lines()
+                .map(|i| i.lines[0].to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let containers = [
+            gen_container("1", "db", &[1, 4]),
+            gen_container("2", "web_1", &[2, 3]),
+        ];
+        let containers = containers.iter().collect::<Vec<_>>();
+        let mut merged = MergedLogs::new(&containers);
+        assert!(merged.matches(&containers));
+        assert!(!merged.matches(&containers[..1]));
+        assert_eq!(
+            to_strings(&merged),
+            [
+                "db    │ line 1",
+                "web_1 │ line 2",
+                "web_1 │ line 3",
+                "db    │ line 4"
+            ]
+        );
+        assert_eq!(
+            merged.logs.lines[0].lines[0].spans[0].style.fg,
+            Some(Color::Cyan)
+        );
+        assert_eq!(
+            merged.logs.lines[1].lines[0].spans[0].style.fg,
+            Some(Color::Yellow)
+        );
+        assert_eq!(merged.logs.get_state_title(), " 4/4");
+
+        // An older line is inserted in order, and the selected line is unchanged
+        merged.logs.start();
+        let (tz, content) = LogsTz::splitter("2025-01-01T00:00:00.000000000Z line 0");
+        let line = LogLine::new(log_sanitizer::remove_ansi(&content), tz);
+        merged.insert(&ContainerId::from("2"), "web_1", &line);
+        assert_eq!(to_strings(&merged)[0], "web_1 │ line 0");
+        assert_eq!(merged.logs.get_state_title(), " 2/5");
+
+        // Lines from unknown containers are ignored
+        merged.insert(&ContainerId::from("3"), "other", &line);
+        assert_eq!(merged.logs.len(), 5);
+    }
+
+    #[test]
+    /// Filtered logs only show, and select, the visible lines, but every line is kept
+    fn test_container_state_logs_filter() {
+        let mut logs = Logs::default();
+        for (index, text) in ["info a", "error b", "info c", "error d"]
+            .iter()
+            .enumerate()
+        {
+            let (tz, _) = LogsTz::splitter(&format!("{index} x"));
+            logs.insert(LogLine::new(log_sanitizer::remove_ansi(text), tz));
+        }
+        let to_strings = |logs: &Logs| {
+            logs.lines()
+                .map(|i| i.lines[0].to_string())
+                .collect::<Vec<_>>()
+        };
+        logs.select(1);
+
+        logs.edit_filter(|f| "error".chars().for_each(|c| f.push(c)));
+        assert_eq!(to_strings(&logs), ["error b", "error d"]);
+        assert_eq!(logs.get_state_title(), " 1/2");
+        assert_eq!(logs.revision(), 1);
+
+        // New lines are only visible if they pass the filter
+        let (tz, _) = LogsTz::splitter("4 x");
+        assert!(logs.insert(LogLine::new(log_sanitizer::remove_ansi("info e"), tz)));
+        let (tz, _) = LogsTz::splitter("5 x");
+        assert!(logs.insert(LogLine::new(log_sanitizer::remove_ansi("error f"), tz)));
+        assert_eq!(logs.len(), 3);
+        assert_eq!(logs.last().unwrap().lines[0].to_string(), "error f");
+
+        // Lines inserted part way through, as merged logs are, keep the selected line in place
+        logs.insert_at(
+            0,
+            LogLine::new(
+                log_sanitizer::remove_ansi("error z"),
+                LogsTz::splitter("6 x").0,
+            ),
+        );
+        assert_eq!(
+            to_strings(&logs),
+            ["error z", "error b", "error d", "error f"]
+        );
+        assert_eq!(logs.get_state_title(), " 2/4");
+
+        // Include & exclude terms are applied together
+        logs.edit_filter(|f| {
+            f.toggle_editing();
+            f.push('z');
+        });
+        assert_eq!(to_strings(&logs), ["error b", "error d", "error f"]);
+        assert_eq!(logs.get_state_title(), " 1/3");
+
+        logs.set_filter(None);
+        assert_eq!(logs.len(), 7);
+        assert_eq!(logs.get_state_title(), " 3/7");
     }
 
     #[test]
@@ -0,0 +1,85 @@
+use bollard::{models::SystemInfo, system::Version};
+
+use super::{ByteStats, CpuStats};
+
+/// Host information, from docker info & docker version, shown in the optional host summary bar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostSummary {
+    pub api_version: String,
+    pub engine_version: String,
+    pub kernel: String,
+    pub mem_total: ByteStats,
+    pub ncpu: u64,
+    pub os: String,
+    pub paused: u64,
+    pub running: u64,
+    pub stopped: u64,
+}
+
+/// The host summary, and the combined cpu & memory usage of all containers, as a percentage of the hosts total
+pub type HostSummaryTuple = (HostSummary, CpuStats, CpuStats);
+
+impl From<(SystemInfo, Version)> for HostSummary {
+    fn from((info, version): (SystemInfo, Version)) -> Self {
+        let unknown = |x: Option<String>| x.unwrap_or_else(|| "unknown".to_owned());
+        let count = |x: Option<i64>| x.and_then(|i| u64::try_from(i).ok()).unwrap_or_default();
+        Self {
+            api_version: unknown(version.api_version),
+            engine_version: unknown(version.version.or(info.server_version)),
+            kernel: unknown(info.kernel_version.or(version.kernel_version)),
+            mem_total: ByteStats::new(count(info.mem_total)),
+            ncpu: count(info.ncpu),
+            os: unknown(info.operating_system.or(version.os)),
+            paused: count(info.containers_paused),
+            running: count(info.containers_running),
+            stopped: count(info.containers_stopped),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::{models::SystemInfo, system::Version};
+
+    use super::HostSummary;
+    use crate::app_data::ByteStats;
+
+    #[test]
+    /// Host summary is created from docker info & version, missing values are replaced
+    fn test_host_summary_from_info_version() {
+        let info = SystemInfo {
+            containers_paused: Some(1),
+            containers_running: Some(5),
+            containers_stopped: Some(3),
+            kernel_version: Some("6.8.0".to_owned()),
+            mem_total: Some(16_000_000_000),
+            ncpu: Some(8),
+            operating_system: Some("Ubuntu 24.04".to_owned()),
+            ..SystemInfo::default()
+        };
+        let version = Version {
+            api_version: Some("1.47".to_owned()),
+            version: Some("27.3.1".to_owned()),
+            ..Version::default()
+        };
+
+        assert_eq!(
+            HostSummary::from((info, version)),
+            HostSummary {
+                api_version: "1.47".to_owned(),
+                engine_version: "27.3.1".to_owned(),
+                kernel: "6.8.0".to_owned(),
+                mem_total: ByteStats::new(16_000_000_000),
+                ncpu: 8,
+                os: "Ubuntu 24.04".to_owned(),
+                paused: 1,
+                running: 5,
+                stopped: 3,
+            }
+        );
+
+        let result = HostSummary::from((SystemInfo::default(), Version::default()));
+        assert_eq!(result.engine_version, "unknown");
+        assert_eq!(result.ncpu, 0);
+    }
+}
@@ -0,0 +1,283 @@
+use regex::Regex;
+
+use super::{LogLine, SearchMode, build_regex};
+
+/// A single term of the filter, with its compiled regex
+#[derive(Debug, Clone, Default)]
+struct FilterTerm {
+    regex: Option<Regex>,
+    term: String,
+}
+
+/// The compiled regex is generated from the term, so can be ignored
+impl PartialEq for FilterTerm {
+    fn eq(&self, other: &Self) -> bool {
+        self.term == other.term
+    }
+}
+
+impl Eq for FilterTerm {}
+
+/// Hide log lines that don't match the include term, or that do match the exclude term, both terms can be set at once
+/// Each container has its own filter, so that it persists when the selected container changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    case_sensitive: bool,
+    editing_exclude: bool,
+    exclude: FilterTerm,
+    include: FilterTerm,
+    mode: SearchMode,
+}
+
+impl LogFilter {
+    pub const fn new() -> Self {
+        Self {
+            case_sensitive: false,
+            editing_exclude: false,
+            exclude: FilterTerm {
+                regex: None,
+                term: String::new(),
+            },
+            include: FilterTerm {
+                regex: None,
+                term: String::new(),
+            },
+            mode: SearchMode::Plain,
+        }
+    }
+
+    const fn editing(&mut self) -> &mut FilterTerm {
+        if self.editing_exclude {
+            &mut self.exclude
+        } else {
+            &mut self.include
+        }
+    }
+
+    /// The term currently being edited
+    pub fn get_term(&self) -> &str {
+        if self.editing_exclude {
+            &self.exclude.term
+        } else {
+            &self.include.term
+        }
+    }
+
+    #[cfg(test)]
+    pub fn get_include(&self) -> &str {
+        &self.include.term
+    }
+
+    #[cfg(test)]
+    pub fn get_exclude(&self) -> &str {
+        &self.exclude.term
+    }
+
+    pub const fn get_mode(&self) -> SearchMode {
+        self.mode
+    }
+
+    /// Whether the exclude term, rather than the include term, is being edited
+    pub const fn is_editing_exclude(&self) -> bool {
+        self.editing_exclude
+    }
+
+    pub const fn is_case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    /// Both terms are empty
+    pub const fn is_empty(&self) -> bool {
+        self.include.term.is_empty() && self.exclude.term.is_empty()
+    }
+
+    fn compile(&mut self) {
+        let case_insensitive = !self.case_sensitive;
+        for filter_term in [&mut self.include, &mut self.exclude] {
+            filter_term.regex = build_regex(&filter_term.term, self.mode, case_insensitive);
+        }
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.editing().term.push(c);
+        self.compile();
+    }
+
+    pub fn pop(&mut self) {
+        self.editing().term.pop();
+        self.compile();
+    }
+
+    pub fn mode_next(&mut self) {
+        self.mode = self.mode.next();
+        self.compile();
+    }
+
+    /// Switch between editing the include, and the exclude, term
+    pub const fn toggle_editing(&mut self) {
+        self.editing_exclude = !self.editing_exclude;
+    }
+
+    pub fn toggle_case_sensitive(&mut self) {
+        self.case_sensitive = !self.case_sensitive;
+        self.compile();
+    }
+
+    /// Check if a log line should be shown
+    pub fn is_visible(&self, line: &LogLine) -> bool {
+        self.include
+            .regex
+            .as_ref()
+            .is_none_or(|regex| line.is_match(regex))
+            && self
+                .exclude
+                .regex
+                .as_ref()
+                .is_none_or(|regex| !line.is_match(regex))
+    }
+
+    /// The filter section of the logs panel title, e.g. `include "ERROR" exclude "GET /health"` or `include /^err/ case sensitive`
+    pub fn title(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let terms = [("include", &self.include), ("exclude", &self.exclude)]
+            .into_iter()
+            .filter(|(_, filter_term)| !filter_term.term.is_empty())
+            .map(|(kind, filter_term)| {
+                if filter_term.regex.is_none() {
+                    return format!("invalid {kind}");
+                }
+                match self.mode {
+                    SearchMode::Plain => format!("{kind} \"{}\"", filter_term.term),
+                    SearchMode::Regex => format!("{kind} /{}/", filter_term.term),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let case = if self.case_sensitive {
+            " case sensitive"
+        } else {
+            ""
+        };
+        Some(format!("{terms}{case}"))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ratatui::text::Line;
+
+    use crate::app_data::{LogLine, LogsTz, SearchMode};
+
+    use super::LogFilter;
+
+    fn gen_line(text: &str) -> LogLine {
+        let (tz, _) = LogsTz::splitter("1 x");
+        LogLine::new(vec![Line::from(text.to_owned())], tz)
+    }
+
+    fn gen_filter(term: &str) -> LogFilter {
+        let mut filter = LogFilter::new();
+        for c in term.chars() {
+            filter.push(c);
+        }
+        filter
+    }
+
+    #[test]
+    /// Include & exclude, case sensitivity, and regex, filters
+    fn test_log_filter_is_visible() {
+        let error = gen_line("ERROR something failed");
+        let info = gen_line("info GET /health");
+
+        let mut filter = gen_filter("error");
+        assert!(filter.is_visible(&error));
+        assert!(!filter.is_visible(&info));
+
+        filter.toggle_case_sensitive();
+        assert!(!filter.is_visible(&error));
+        filter.toggle_case_sensitive();
+
+        let mut filter = LogFilter::new();
+        filter.toggle_editing();
+        "error".chars().for_each(|c| filter.push(c));
+        assert_eq!(filter.get_include(), "");
+        assert_eq!(filter.get_exclude(), "error");
+        assert!(!filter.is_visible(&error));
+        assert!(filter.is_visible(&info));
+
+        let mut filter = gen_filter("^[a-z]+ GET");
+        assert!(!filter.is_visible(&info));
+        filter.mode_next();
+        assert!(filter.is_visible(&info));
+        assert!(!filter.is_visible(&error));
+    }
+
+    #[test]
+    /// Include & exclude terms can be set at the same time, a line must match the include, and not match the exclude, term
+    fn test_log_filter_include_and_exclude() {
+        let error = gen_line("ERROR something failed");
+        let health = gen_line("ERROR healthcheck failed");
+        let info = gen_line("info GET /health");
+
+        let mut filter = gen_filter("error");
+        filter.toggle_editing();
+        assert!(filter.is_editing_exclude());
+        assert_eq!(filter.get_term(), "");
+        "healthcheck".chars().for_each(|c| filter.push(c));
+        assert!(filter.is_visible(&error));
+        assert!(!filter.is_visible(&health));
+        assert!(!filter.is_visible(&info));
+
+        // Editing only changes the selected term
+        filter.pop();
+        assert_eq!(filter.get_exclude(), "healthchec");
+        filter.toggle_editing();
+        assert_eq!(filter.get_term(), "error");
+        filter.pop();
+        assert_eq!(filter.get_include(), "erro");
+        assert_eq!(filter.get_exclude(), "healthchec");
+    }
+
+    #[test]
+    /// Empty, or invalid, filters show every line
+    fn test_log_filter_inactive() {
+        let line = gen_line("info");
+        let filter = LogFilter::new();
+        assert!(filter.is_visible(&line));
+        assert!(filter.title().is_none());
+
+        let mut filter = gen_filter("(");
+        filter.mode_next();
+        assert_eq!(filter.get_mode(), SearchMode::Regex);
+        assert!(filter.is_visible(&line));
+        assert_eq!(filter.title().unwrap(), "invalid include");
+    }
+
+    #[test]
+    /// Title shows the kind of filter, the term, and case sensitivity
+    fn test_log_filter_title() {
+        let mut filter = gen_filter("GET /health");
+        assert_eq!(filter.title().unwrap(), "include \"GET /health\"");
+        filter.toggle_editing();
+        filter.push('(');
+        assert_eq!(
+            filter.title().unwrap(),
+            "include \"GET /health\" exclude \"(\""
+        );
+        filter.mode_next();
+        filter.toggle_case_sensitive();
+        assert_eq!(
+            filter.title().unwrap(),
+            "include /GET /health/ invalid exclude case sensitive"
+        );
+        filter.pop();
+        filter.toggle_editing();
+        (0..11).for_each(|_| filter.pop());
+        filter.toggle_editing();
+        filter.push('x');
+        assert_eq!(filter.title().unwrap(), "exclude /x/ case sensitive");
+    }
+}
@@ -0,0 +1,230 @@
+use std::{fmt, sync::LazyLock};
+
+use ratatui::style::Color;
+use regex::Regex;
+use serde_json::Value;
+
+use crate::config::AppColors;
+
+/// Keys commonly used for the level of a structured log line
+pub const LEVEL_KEYS: [&str; 6] = [
+    "level",
+    "lvl",
+    "severity",
+    "log.level",
+    "levelname",
+    "loglevel",
+];
+
+/// A logfmt level pair, e.g. `level=warn` or `lvl="error"`
+static LOGFMT: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r#"(?:^|\s)(?i:level|lvl|severity)="?([A-Za-z]+)"#).ok());
+
+/// A level as an upper case word, e.g. `ERROR`, or as a bracketed word of any case, e.g. `[warn]`
+static PLAIN: LazyLock<Option<Regex>> = LazyLock::new(|| {
+    Regex::new(
+        r"\b(FATAL|PANIC|CRITICAL|CRIT|ERROR|ERR|WARNING|WARN|INFO|NOTICE|DEBUG|DBG|TRACE)\b|\[(?i:(fatal|panic|critical|crit|error|err|warning|warn|info|notice|debug|dbg|trace))\]",
+    )
+    .ok()
+});
+
+/// The severity of a log line, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Trace => "trace",
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+impl LogLevel {
+    /// Convert a level name, of any case, into a LogLevel, fatal & critical levels are treated as errors
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "trace" => Some(Self::Trace),
+            "debug" | "dbg" => Some(Self::Debug),
+            "info" | "information" | "notice" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warn),
+            "error" | "err" | "fatal" | "panic" | "critical" | "crit" | "alert" | "emergency" => {
+                Some(Self::Error)
+            }
+            _ => None,
+        }
+    }
+
+    /// Convert a numeric level, as used by pino & bunyan, into a LogLevel
+    const fn from_number(number: u64) -> Option<Self> {
+        match number {
+            10 => Some(Self::Trace),
+            20 => Some(Self::Debug),
+            30 => Some(Self::Info),
+            40 => Some(Self::Warn),
+            50 | 60 => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    /// Get the level from the first known level key of a JSON object
+    fn from_json(input: &str) -> Option<Self> {
+        let value = serde_json::from_str::<Value>(input).ok()?;
+        let object = value.as_object()?;
+        LEVEL_KEYS
+            .iter()
+            .find_map(|key| object.get(*key))
+            .and_then(|level| match level {
+                Value::String(name) => Self::from_name(name),
+                Value::Number(number) => number.as_u64().and_then(Self::from_number),
+                _ => None,
+            })
+    }
+
+    /// Heuristically detect the level of a log line, which should have already had any ansi formatting removed
+    /// JSON lines are checked for a level field, then logfmt lines for a `level=` pair, and finally the first level like word is used
+    /// A line that starts with whitespace, such as a stack trace frame, and has no level of its own, inherits the level of the previous line
+    pub fn detect(input: &str, previous: Option<Self>) -> Option<Self> {
+        let trimmed = input.trim();
+        let level = if trimmed.starts_with('{') {
+            Self::from_json(trimmed)
+        } else {
+            None
+        }
+        .or_else(|| {
+            LOGFMT
+                .as_ref()
+                .and_then(|regex| regex.captures(trimmed))
+                .and_then(|captures| captures.get(1))
+                .and_then(|name| Self::from_name(name.as_str()))
+        })
+        .or_else(|| {
+            PLAIN
+                .as_ref()
+                .and_then(|regex| regex.captures(trimmed))
+                .and_then(|captures| captures.get(1).or_else(|| captures.get(2)))
+                .and_then(|name| Self::from_name(name.as_str()))
+        });
+
+        if level.is_none() && input.starts_with(char::is_whitespace) {
+            previous
+        } else {
+            level
+        }
+    }
+
+    /// The next minimum severity threshold for the logs panel, None shows every line
+    pub const fn threshold_next(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Self::Debug),
+            Some(Self::Trace | Self::Debug) => Some(Self::Info),
+            Some(Self::Info) => Some(Self::Warn),
+            Some(Self::Warn) => Some(Self::Error),
+            Some(Self::Error) => None,
+        }
+    }
+
+    /// The color of a line of this level, `Color::Reset` leaves the line in the logs text color
+    pub const fn color(self, colors: &AppColors) -> Color {
+        match self {
+            Self::Trace => colors.logs.level_trace,
+            Self::Debug => colors.logs.level_debug,
+            Self::Info => colors.logs.level_info,
+            Self::Warn => colors.logs.level_warn,
+            Self::Error => colors.logs.level_error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LogLevel;
+
+    #[test]
+    /// Levels are detected from upper case, and bracketed, words, the first level like word is used
+    fn test_log_level_detect_plain() {
+        for (input, expected) in [
+            ("2025-01-01 ERROR something failed", Some(LogLevel::Error)),
+            ("WARN disk space low", Some(LogLevel::Warn)),
+            ("[info] server started", Some(LogLevel::Info)),
+            ("[Debug] cache miss", Some(LogLevel::Debug)),
+            ("TRACE entering fn", Some(LogLevel::Trace)),
+            ("FATAL out of memory", Some(LogLevel::Error)),
+            ("INFO request had an ERROR", Some(LogLevel::Info)),
+            ("no error here", None),
+            ("INFORMATION", None),
+        ] {
+            assert_eq!(LogLevel::detect(input, None), expected, "{input}");
+        }
+    }
+
+    #[test]
+    /// Levels are detected from JSON level fields, including numeric pino levels, and logfmt level pairs
+    fn test_log_level_detect_structured() {
+        for (input, expected) in [
+            (
+                r#"{"level":"warn","msg":"ERROR in msg"}"#,
+                Some(LogLevel::Warn),
+            ),
+            (
+                r#"{"severity":"ERROR","message":"x"}"#,
+                Some(LogLevel::Error),
+            ),
+            (r#"{"level":20,"msg":"x"}"#, Some(LogLevel::Debug)),
+            (r#"{"log.level":"info"}"#, Some(LogLevel::Info)),
+            (r#"{"msg":"no level"}"#, None),
+            ("time=1 level=debug msg=\"ERROR\"", Some(LogLevel::Debug)),
+            ("ts=1 lvl=\"error\" msg=x", Some(LogLevel::Error)),
+            ("sublevel=error", None),
+        ] {
+            assert_eq!(LogLevel::detect(input, None), expected, "{input}");
+        }
+    }
+
+    #[test]
+    /// Indented lines without a level inherit the previous level
+    fn test_log_level_detect_inherit() {
+        let previous = Some(LogLevel::Error);
+        assert_eq!(
+            LogLevel::detect("    at main.rs:10", previous),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(
+            LogLevel::detect("  WARN indented", previous),
+            Some(LogLevel::Warn)
+        );
+        assert_eq!(LogLevel::detect("server started", previous), None);
+    }
+
+    #[test]
+    /// The threshold cycles through each level, except trace, and then back to showing every line
+    fn test_log_level_threshold_next() {
+        let mut threshold = None;
+        let mut result = vec![];
+        for _ in 0..5 {
+            threshold = LogLevel::threshold_next(threshold);
+            result.push(threshold);
+        }
+        assert_eq!(
+            result,
+            [
+                Some(LogLevel::Debug),
+                Some(LogLevel::Info),
+                Some(LogLevel::Warn),
+                Some(LogLevel::Error),
+                None
+            ]
+        );
+    }
+}
@@ -0,0 +1,362 @@
+use std::fmt;
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+use regex::{Regex, RegexBuilder};
+
+use super::{ContainerId, Logs};
+
+/// How the log search term is matched against each log line
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Case insensitive substring
+    #[default]
+    Plain,
+    Regex,
+}
+
+impl SearchMode {
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Plain => Self::Regex,
+            Self::Regex => Self::Plain,
+        }
+    }
+}
+
+impl fmt::Display for SearchMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Plain => "Plain",
+            Self::Regex => "Regex",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// Compile a term into a regex, a plain term has any regex characters escaped, an empty, or invalid, term returns None
+pub fn build_regex(term: &str, mode: SearchMode, case_insensitive: bool) -> Option<Regex> {
+    if term.is_empty() {
+        return None;
+    }
+    let pattern = match mode {
+        SearchMode::Plain => regex::escape(term),
+        SearchMode::Regex => term.to_owned(),
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(case_insensitive)
+        .build()
+        .ok()
+}
+
+/// Search the currently displayed logs, the index of every matching log line is cached,
+/// alongside the logs it was matched against, so that only new lines need to be checked
+#[derive(Debug, Clone)]
+pub struct LogSearch {
+    matches: Vec<usize>,
+    mode: SearchMode,
+    regex: Option<Regex>,
+    /// The container id, None when merged, the revision of the logs filtered view, and the number of log lines, that the matches were generated from
+    source: Option<(Option<ContainerId>, usize, usize)>,
+    term: String,
+}
+
+impl LogSearch {
+    pub const fn new() -> Self {
+        Self {
+            matches: vec![],
+            mode: SearchMode::Plain,
+            regex: None,
+            source: None,
+            term: String::new(),
+        }
+    }
+
+    pub fn get_term(&self) -> &str {
+        &self.term
+    }
+
+    pub const fn get_mode(&self) -> SearchMode {
+        self.mode
+    }
+
+    /// A regex term which can't be compiled will never match
+    pub const fn is_valid(&self) -> bool {
+        self.term.is_empty() || self.regex.is_some()
+    }
+
+    /// Compile the term into a regex, a plain term is matched case insensitively, and reset the cached matches
+    fn compile(&mut self) {
+        self.source = None;
+        self.matches.clear();
+        self.regex = build_regex(&self.term, self.mode, self.mode == SearchMode::Plain);
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.term.push(c);
+        self.compile();
+    }
+
+    pub fn pop(&mut self) {
+        self.term.pop();
+        self.compile();
+    }
+
+    pub fn mode_next(&mut self) {
+        self.mode = self.mode.next();
+        self.compile();
+    }
+
+    /// Match the term against any lines that haven't been checked yet
+    /// The logs of a single container are only ever appended to, unless the log filter is changed, but merged logs can be inserted into, so are checked again in full when changed
+    pub fn refresh(&mut self, id: Option<&ContainerId>, logs: &Logs) {
+        let len = logs.len();
+        let revision = logs.revision();
+        let start = match self.source.as_ref() {
+            Some((source_id, source_revision, checked))
+                if source_id.as_ref() == id && *source_revision == revision =>
+            {
+                if *checked == len {
+                    return;
+                }
+                if id.is_some() && *checked < len {
+                    *checked
+                } else {
+                    0
+                }
+            }
+            _ => 0,
+        };
+        if start == 0 {
+            self.matches.clear();
+        }
+        if let Some(regex) = self.regex.as_ref() {
+            self.matches.extend(
+                logs.lines()
+                    .enumerate()
+                    .skip(start)
+                    .filter(|(_, line)| line.is_match(regex))
+                    .map(|(index, _)| index),
+            );
+        }
+        self.source = Some((id.cloned(), revision, len));
+    }
+
+    /// The nearest match at, or before, the selected line, else the first match
+    pub fn nearest(&self, selected: usize) -> Option<usize> {
+        self.matches
+            .iter()
+            .rev()
+            .find(|i| **i <= selected)
+            .or_else(|| self.matches.first())
+            .copied()
+    }
+
+    /// The first match after the selected line, wrapping around to the first match
+    pub fn next(&self, selected: usize) -> Option<usize> {
+        self.matches
+            .iter()
+            .find(|i| **i > selected)
+            .or_else(|| self.matches.first())
+            .copied()
+    }
+
+    /// The last match before the selected line, wrapping around to the last match
+    pub fn previous(&self, selected: usize) -> Option<usize> {
+        self.matches
+            .iter()
+            .rev()
+            .find(|i| **i < selected)
+            .or_else(|| self.matches.last())
+            .copied()
+    }
+
+    /// The search section of the logs panel title, e.g. "match 2/5", the position is the number of matches at, or before, the selected line
+    pub fn title(&self, selected: usize) -> Option<String> {
+        if self.term.is_empty() {
+            None
+        } else if !self.is_valid() {
+            Some(String::from("invalid regex"))
+        } else if self.matches.is_empty() {
+            Some(String::from("no matches"))
+        } else {
+            let position = self.matches.partition_point(|i| *i <= selected);
+            Some(format!("match {position}/{}", self.matches.len()))
+        }
+    }
+
+    /// Apply the given style to every match in the line, splitting spans where a match starts or ends part way through
+    pub fn highlight(&self, line: &Line<'static>, style: Style) -> Line<'static> {
+        let Some(regex) = self.regex.as_ref() else {
+            return line.clone();
+        };
+        let text = line.to_string();
+        let ranges = regex
+            .find_iter(&text)
+            .map(|i| i.range())
+            .filter(|i| !i.is_empty())
+            .collect::<Vec<_>>();
+        if ranges.is_empty() {
+            return line.clone();
+        }
+
+        let mut spans = vec![];
+        let mut offset = 0;
+        for span in &line.spans {
+            let end = offset + span.content.len();
+            let mut cursor = offset;
+            for range in ranges.iter().filter(|i| i.start < end && i.end > offset) {
+                let start = range.start.max(offset);
+                let stop = range.end.min(end);
+                if start > cursor {
+                    spans.push(Span::styled(text[cursor..start].to_owned(), span.style));
+                }
+                spans.push(Span::styled(
+                    text[start..stop].to_owned(),
+                    span.style.patch(style),
+                ));
+                cursor = stop;
+            }
+            if cursor < end {
+                spans.push(Span::styled(text[cursor..end].to_owned(), span.style));
+            }
+            offset = end;
+        }
+        let mut output = Line::from(spans).style(line.style);
+        output.alignment = line.alignment;
+        output
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ratatui::{
+        style::{Color, Style},
+        text::{Line, Span},
+    };
+
+    use crate::app_data::{ContainerId, LogLine, Logs, LogsTz};
+
+    use super::{LogSearch, SearchMode};
+
+    fn gen_logs(lines: &[&str]) -> Logs {
+        let mut logs = Logs::default();
+        for (index, line) in lines.iter().enumerate() {
+            let (tz, _) = LogsTz::splitter(&format!("{index} x"));
+            logs.insert(LogLine::new(vec![Line::from((*line).to_owned())], tz));
+        }
+        logs
+    }
+
+    fn gen_search(term: &str, mode: SearchMode) -> LogSearch {
+        let mut search = LogSearch::new();
+        if mode != search.get_mode() {
+            search.mode_next();
+        }
+        for c in term.chars() {
+            search.push(c);
+        }
+        search
+    }
+
+    #[test]
+    /// Plain terms are case insensitive substrings, regex terms are compiled as is
+    fn test_log_search_modes() {
+        let logs = gen_logs(&["Error one", "info two", "error three", "a.b"]);
+        let id = ContainerId::from("1");
+
+        let mut search = gen_search("error", SearchMode::Plain);
+        search.refresh(Some(&id), &logs);
+        assert_eq!(search.matches, [0, 2]);
+
+        // Regex characters in a plain term are escaped
+        let mut search = gen_search(".", SearchMode::Plain);
+        search.refresh(Some(&id), &logs);
+        assert_eq!(search.matches, [3]);
+
+        let mut search = gen_search("^[a-z]+ t", SearchMode::Regex);
+        search.refresh(Some(&id), &logs);
+        assert_eq!(search.matches, [1, 2]);
+
+        let mut search = gen_search("(", SearchMode::Regex);
+        search.refresh(Some(&id), &logs);
+        assert!(!search.is_valid());
+        assert_eq!(search.title(0).unwrap(), "invalid regex");
+
+        // Changing mode recompiles the term
+        search.mode_next();
+        search.refresh(Some(&id), &logs);
+        assert!(search.is_valid());
+        assert_eq!(search.title(0).unwrap(), "no matches");
+    }
+
+    #[test]
+    /// Only new lines are checked, unless the logs source has changed
+    fn test_log_search_refresh() {
+        let id = ContainerId::from("1");
+        let mut logs = gen_logs(&["error one", "info two"]);
+        let mut search = gen_search("error", SearchMode::Plain);
+        search.refresh(Some(&id), &logs);
+        assert_eq!(search.matches, [0]);
+
+        let (tz, _) = LogsTz::splitter("9 x");
+        logs.insert(LogLine::new(vec![Line::from("error three")], tz));
+        search.refresh(Some(&id), &logs);
+        assert_eq!(search.matches, [0, 2]);
+
+        let other = gen_logs(&["info", "error"]);
+        search.refresh(Some(&ContainerId::from("2")), &other);
+        assert_eq!(search.matches, [1]);
+        search.refresh(None, &logs);
+        assert_eq!(search.matches, [0, 2]);
+    }
+
+    #[test]
+    /// Next & previous wrap around, title shows the number of matches at or before the selected line
+    fn test_log_search_next_previous_title() {
+        let logs = gen_logs(&["a", "error", "b", "error", "c", "error"]);
+        let mut search = gen_search("error", SearchMode::Plain);
+        search.refresh(None, &logs);
+
+        assert_eq!(search.next(0), Some(1));
+        assert_eq!(search.next(3), Some(5));
+        assert_eq!(search.next(5), Some(1));
+        assert_eq!(search.previous(5), Some(3));
+        assert_eq!(search.previous(1), Some(5));
+        assert_eq!(search.nearest(4), Some(3));
+        assert_eq!(search.nearest(0), Some(1));
+
+        assert_eq!(search.title(0).unwrap(), "match 0/3");
+        assert_eq!(search.title(3).unwrap(), "match 2/3");
+        assert!(gen_search("", SearchMode::Plain).title(0).is_none());
+    }
+
+    #[test]
+    /// Matches are highlighted, spans are split where a match crosses a span boundary, and existing styles are kept
+    fn test_log_search_highlight() {
+        let highlight = Style::default().bg(Color::Yellow);
+        let red = Style::default().fg(Color::Red);
+        let line = Line::from(vec![
+            Span::styled("an err", red),
+            Span::raw("or here error"),
+        ]);
+
+        let result = gen_search("error", SearchMode::Plain).highlight(&line, highlight);
+        assert_eq!(
+            result.spans,
+            [
+                Span::styled("an ", red),
+                Span::styled("err", red.patch(highlight)),
+                Span::styled("or", highlight),
+                Span::raw(" here "),
+                Span::styled("error", highlight),
+            ]
+        );
+
+        let result = gen_search("missing", SearchMode::Plain).highlight(&line, highlight);
+        assert_eq!(result, line);
+    }
+}
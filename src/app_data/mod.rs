@@ -1,7 +1,10 @@
 use bollard::models::ContainerSummary;
 use core::fmt;
 use parking_lot::Mutex;
-use ratatui::widgets::{ListItem, ListState};
+use ratatui::{
+    style::{Color, Style},
+    widgets::{ListItem, ListState},
+};
 use std::{
     hash::Hash,
     sync::Arc,
@@ -9,14 +12,27 @@ use std::{
 };
 
 mod container_state;
+mod host_summary;
+mod log_filter;
+mod log_level;
+mod log_search;
+mod structured_log;
 
 use crate::{
     ENTRY_POINT,
     app_error::AppError,
-    config::Config,
+    config::{Config, CpuMode, SparklineMetric},
     ui::{GuiState, Rerender, Status, log_sanitizer},
 };
 pub use container_state::*;
+pub use host_summary::*;
+pub use log_filter::*;
+pub use log_level::*;
+pub use log_search::*;
+pub use structured_log::*;
+
+/// The time windows, in seconds, that the charts can be zoomed between, limited by the stats history length
+const CHART_WINDOWS: [u32; 8] = [60, 300, 600, 1800, 3600, 21_600, 43_200, 86_400];
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum SortedOrder {
@@ -30,11 +46,15 @@ pub enum Header {
     Status,
     Cpu,
     Memory,
+    Sparkline,
     Id,
     Name,
     Image,
     Rx,
     Tx,
+    BlockRead,
+    BlockWrite,
+    Pids,
 }
 
 /// Convert Header enum into strings to display
@@ -45,11 +65,15 @@ impl fmt::Display for Header {
             Self::Status => "status",
             Self::Cpu => "cpu",
             Self::Memory => "memory/limit",
+            Self::Sparkline => "trend",
             Self::Id => "id",
             Self::Name => "name",
             Self::Image => "image",
             Self::Rx => "↓ rx",
             Self::Tx => "↑ tx",
+            Self::BlockRead => "blk read",
+            Self::BlockWrite => "blk write",
+            Self::Pids => "pids",
         };
         write!(f, "{disp:>x$}", x = f.width().unwrap_or(1))
     }
@@ -118,11 +142,20 @@ impl Filter {
 #[derive(Debug, Clone)]
 #[cfg(not(test))]
 pub struct AppData {
+    chart_overview: ChartOverview,
+    chart_window: u32,
     containers: StatefulList<ContainerItem>,
+    cpu_mode: CpuMode,
     error: Option<AppError>,
     filter: Filter,
     hidden_containers: Vec<ContainerItem>,
+    history: HistoryLength,
+    host_summary: Option<HostSummary>,
+    log_search: Option<LogSearch>,
+    merged_logs: Option<MergedLogs>,
     redraw: Arc<Rerender>,
+    show_mem_percent: bool,
+    show_net_total: bool,
     sorted_by: Option<(Header, SortedOrder)>,
     current_sorted_id: Vec<ContainerId>,
     pub config: Config,
@@ -131,13 +164,22 @@ pub struct AppData {
 #[derive(Debug, Clone)]
 #[cfg(test)]
 pub struct AppData {
+    pub chart_overview: ChartOverview,
+    pub chart_window: u32,
     pub config: Config,
     pub containers: StatefulList<ContainerItem>,
+    pub cpu_mode: CpuMode,
     pub error: Option<AppError>,
     pub filter: Filter,
     pub hidden_containers: Vec<ContainerItem>,
+    pub history: HistoryLength,
+    pub host_summary: Option<HostSummary>,
+    pub log_search: Option<LogSearch>,
+    pub merged_logs: Option<MergedLogs>,
     pub current_sorted_id: Vec<ContainerId>,
     pub redraw: Arc<Rerender>,
+    pub show_mem_percent: bool,
+    pub show_net_total: bool,
     pub sorted_by: Option<(Header, SortedOrder)>,
 }
 
@@ -145,17 +187,174 @@ impl AppData {
     /// Generate a default app_state
     pub fn new(config: Config, redraw: &Arc<Rerender>) -> Self {
         Self {
+            chart_overview: ChartOverview::default(),
+            chart_window: config.stats_history_seconds.min(CHART_WINDOWS[0]),
+            cpu_mode: config.cpu_mode,
+            history: HistoryLength::new(config.stats_history_seconds, config.docker_interval_ms),
             config,
             containers: StatefulList::new(vec![]),
             current_sorted_id: vec![],
             error: None,
             filter: Filter::new(),
             hidden_containers: vec![],
+            host_summary: None,
+            log_search: None,
+            merged_logs: None,
             redraw: Arc::clone(redraw),
+            show_mem_percent: false,
+            show_net_total: false,
             sorted_by: None,
         }
     }
 
+    /// Network related methods
+    /// Whether the rx/tx columns show the cumulative totals, rather than the per second rates
+    pub const fn get_show_net_total(&self) -> bool {
+        self.show_net_total
+    }
+
+    /// Switch the rx/tx columns between per second rates & cumulative totals, and re-sort, as the order may have changed
+    pub fn toggle_show_net_total(&mut self) {
+        self.show_net_total = !self.show_net_total;
+        self.sort_containers();
+        self.redraw.update();
+    }
+
+    /// Cpu related methods
+    /// How the cpu usage is currently being displayed
+    pub const fn get_cpu_mode(&self) -> CpuMode {
+        self.cpu_mode
+    }
+
+    /// Change to the next cpu mode, and re-sort, as when using the Quota mode the order may have changed
+    pub fn cycle_cpu_mode(&mut self) {
+        self.cpu_mode = self.cpu_mode.next();
+        self.sort_containers();
+        self.redraw.update();
+    }
+
+    /// Memory related methods
+    /// Whether the memory column, and chart, show the usage as a percentage of the memory limit, rather than in bytes
+    pub const fn get_show_mem_percent(&self) -> bool {
+        self.show_mem_percent
+    }
+
+    /// Switch the memory column between bytes & percentage of the limit, and re-sort, as the order may have changed
+    pub fn toggle_show_mem_percent(&mut self) {
+        self.show_mem_percent = !self.show_mem_percent;
+        self.sort_containers();
+        self.redraw.update();
+    }
+
+    /// Chart related methods
+    /// The number of stored points covered by the current chart window
+    pub fn get_chart_points(&self) -> usize {
+        self.history
+            .points(self.chart_window, self.config.docker_interval_ms)
+    }
+
+    /// The length of the current chart window, formatted as seconds, minutes, or hours
+    pub fn get_chart_window_label(&self) -> String {
+        match self.chart_window {
+            x if x >= 3600 && x % 3600 == 0 => format!("{}h", x / 3600),
+            x if x >= 60 && x % 60 == 0 => format!("{}m", x / 60),
+            x => format!("{x}s"),
+        }
+    }
+
+    /// Show a shorter time window in the charts
+    pub fn chart_zoom_in(&mut self) {
+        if let Some(window) = CHART_WINDOWS.iter().rev().find(|i| **i < self.chart_window) {
+            self.chart_window = *window;
+        }
+        self.redraw.update();
+    }
+
+    /// Show a longer time window in the charts, up to the full stats history
+    pub fn chart_zoom_out(&mut self) {
+        let history = self.config.stats_history_seconds;
+        self.chart_window = CHART_WINDOWS
+            .iter()
+            .find(|i| **i > self.chart_window)
+            .map_or(history, |i| (*i).min(history));
+        self.redraw.update();
+    }
+
+    /// Change to the next overview chart mode, off, cpu, then memory
+    pub fn cycle_chart_overview(&mut self) {
+        self.chart_overview = self.chart_overview.next();
+        self.redraw.update();
+    }
+
+    /// Get the overview chart data, the cpu or memory history of the containers with the highest current usage
+    /// Only visible containers are included, up to the configured count, None if the overview chart is off
+    pub fn get_overview_chart_data(&self) -> Option<OverviewTuple> {
+        let overview = self.chart_overview;
+        if overview == ChartOverview::Off {
+            return None;
+        }
+        let points = self.get_chart_points();
+        let mut items = self
+            .containers
+            .items
+            .iter()
+            .map(|i| (i.overview_current(overview, self.cpu_mode), i))
+            .collect::<Vec<_>>();
+        items.sort_by(|a, b| b.0.total_cmp(&a.0));
+        let series = items
+            .into_iter()
+            .take(usize::from(self.config.chart_overview_count))
+            .map(|(_, i)| {
+                (
+                    i.name.clone(),
+                    i.get_overview_dataset(overview, self.cpu_mode, points),
+                )
+            })
+            .collect::<Vec<_>>();
+        let max = series
+            .iter()
+            .flat_map(|(_, dataset)| dataset.iter().map(|i| i.1))
+            .fold(0.0, f64::max);
+        Some((overview, series, max))
+    }
+
+    /// Host summary related methods
+    /// Set the host summary, from docker info & docker version
+    pub fn update_host_summary(&mut self, host_summary: HostSummary) {
+        self.host_summary = Some(host_summary);
+        self.redraw.update();
+    }
+
+    /// Get the host summary, with the combined cpu & memory usage of every running container, including those hidden by the filter, as a percentage of the hosts total
+    /// Containers that aren't running are ignored, as their stats history keeps the last values from when they were
+    #[allow(clippy::cast_precision_loss)]
+    pub fn get_host_summary(&self) -> Option<HostSummaryTuple> {
+        let host_summary = self.host_summary.as_ref()?;
+        let containers = || {
+            self.containers
+                .items
+                .iter()
+                .chain(&self.hidden_containers)
+                .filter(|i| i.state.is_alive())
+        };
+        let share =
+            |total: f64, host: f64| CpuStats::new(if host > 0.0 { total / host } else { 0.0 });
+        let cpu = containers()
+            .filter_map(|i| i.cpu_current(CpuMode::PerCore))
+            .map(|i| i.get_value())
+            .sum::<f64>();
+        let mem = containers()
+            .filter_map(|i| i.mem_stats.back())
+            .map(Stats::get_value)
+            .sum::<f64>();
+        // Cpu usage is already a percentage of a single core
+        Some((
+            host_summary.clone(),
+            share(cpu, host_summary.ncpu as f64),
+            share(mem * 100.0, host_summary.mem_total.get_value()),
+        ))
+    }
+
     /// Current time as unix timestamp
     #[allow(clippy::expect_used)]
     fn get_systemtime() -> u64 {
@@ -222,6 +421,7 @@ impl AppData {
         if self.get_container_len() != pre_len {
             self.containers.start();
         }
+        self.sync_merged_logs();
     }
 
     /// Re-filter the containers, used after the filter.by has been changed
@@ -278,6 +478,7 @@ impl AppData {
             }
         }
         self.sort_containers();
+        self.sync_merged_logs();
     }
 
     /// Container sort related methods
@@ -302,12 +503,12 @@ impl AppData {
     /// Sort containers based on a given header, if headings match, and already ascending, remove sorting
     pub fn set_sort_by_header(&mut self, selected_header: Header) {
         let mut output = Some((selected_header, SortedOrder::Asc));
-        if let Some((current_header, order)) = self.get_sorted() {
-            if current_header == selected_header {
-                match order {
-                    SortedOrder::Desc => output = None,
-                    SortedOrder::Asc => output = Some((selected_header, SortedOrder::Desc)),
-                }
+        if let Some((current_header, order)) = self.get_sorted()
+            && current_header == selected_header
+        {
+            match order {
+                SortedOrder::Desc => output = None,
+                SortedOrder::Asc => output = Some((selected_header, SortedOrder::Desc)),
             }
         }
         self.set_sorted(output);
@@ -327,9 +528,18 @@ impl AppData {
     }
     /// Sort the containers vec, based on a heading (and if clash, then by name), either ascending or descending,
     /// If not sort set, then sort by created time
+    #[allow(clippy::too_many_lines)]
     pub fn sort_containers(&mut self) {
         if let Some((head, ord)) = self.sorted_by {
             let pre_order = self.get_current_ids();
+            let show_net_total = self.show_net_total;
+            let cpu_mode = self.cpu_mode;
+            let show_mem_percent = self.show_mem_percent;
+            // The sparkline column is sorted by the current value of the stat it shows
+            let head = match (head, self.config.sparkline) {
+                (Header::Sparkline, Some(SparklineMetric::Memory)) => Header::Memory,
+                (head, _) => head,
+            };
             let sort_closure = |a: &ContainerItem, b: &ContainerItem| -> std::cmp::Ordering {
                 let item_ord = match ord {
                     SortedOrder::Asc => (a, b),
@@ -348,11 +558,16 @@ impl AppData {
                         .get()
                         .cmp(item_ord.1.status.get())
                         .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
-                    Header::Cpu => item_ord
+                    Header::Cpu | Header::Sparkline => item_ord
                         .0
-                        .cpu_stats
-                        .back()
-                        .cmp(&item_ord.1.cpu_stats.back())
+                        .cpu_current(cpu_mode)
+                        .cmp(&item_ord.1.cpu_current(cpu_mode))
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Memory if show_mem_percent => item_ord
+                        .0
+                        .mem_percentage()
+                        .unwrap_or_default()
+                        .total_cmp(&item_ord.1.mem_percentage().unwrap_or_default())
                         .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
                     Header::Memory => item_ord
                         .0
@@ -371,16 +586,49 @@ impl AppData {
                         .get()
                         .cmp(item_ord.1.image.get())
                         .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
-                    Header::Rx => item_ord
+                    Header::Rx if show_net_total => item_ord
                         .0
                         .rx
                         .cmp(&item_ord.1.rx)
                         .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
-                    Header::Tx => item_ord
+                    Header::Rx => item_ord
+                        .0
+                        .net_io
+                        .current
+                        .0
+                        .cmp(&item_ord.1.net_io.current.0)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Tx if show_net_total => item_ord
                         .0
                         .tx
                         .cmp(&item_ord.1.tx)
                         .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Tx => item_ord
+                        .0
+                        .net_io
+                        .current
+                        .1
+                        .cmp(&item_ord.1.net_io.current.1)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::BlockRead => item_ord
+                        .0
+                        .block_io
+                        .current
+                        .0
+                        .cmp(&item_ord.1.block_io.current.0)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::BlockWrite => item_ord
+                        .0
+                        .block_io
+                        .current
+                        .1
+                        .cmp(&item_ord.1.block_io.current.1)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
+                    Header::Pids => item_ord
+                        .0
+                        .pids
+                        .cmp(&item_ord.1.pids)
+                        .then_with(|| item_ord.0.name.get().cmp(item_ord.1.name.get())),
                     Header::Name => item_ord
                         .0
                         .name
@@ -418,10 +666,12 @@ impl AppData {
         self.containers.items.len()
     }
 
+    /// The state & id of every container, including those hidden by the filter, so that stats are still polled, and recorded, for hidden containers
     pub fn get_all_id_state(&self) -> Vec<(State, ContainerId)> {
         self.containers
             .items
             .iter()
+            .chain(&self.hidden_containers)
             .map(|i| (i.state, i.id.clone()))
             .collect::<Vec<_>>()
     }
@@ -433,6 +683,14 @@ impl AppData {
         &self.containers.items
     }
 
+    /// Get all the ContainerItems, including those hidden by the filter
+    pub fn get_all_container_items(&self) -> impl Iterator<Item = &ContainerItem> {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+    }
+
     /// Get title for containers section, add a suffix indicating if the containers are currently under filter
     pub fn get_container_title(&self) -> String {
         let suffix = if !self.hidden_containers.is_empty() && !self.containers.items.is_empty() {
@@ -517,12 +775,37 @@ impl AppData {
     pub fn get_selected_ports(&self) -> Option<(Vec<ContainerPorts>, State)> {
         if let Some(item) = self.get_selected_container() {
             let mut ports = item.ports.clone();
-            ports.sort_by(|a, b| a.private.cmp(&b.private));
+            ports.sort_by_key(|a| a.private);
             return Some((ports, item.state));
         }
         None
     }
 
+    /// Get the network interfaces of the selected container, None if it has no interfaces
+    pub fn get_selected_networks(&self) -> Option<(Vec<NetworkInterface>, State)> {
+        self.get_selected_container()
+            .filter(|item| !item.networks.is_empty())
+            .map(|item| (item.networks.clone(), item.state))
+    }
+
+    /// Get the name, cpu detail, and state of the selected container
+    pub fn get_selected_cpu_detail(&self) -> Option<(ContainerName, CpuDetail, State)> {
+        self.get_selected_container()
+            .map(|item| (item.name.clone(), item.cpu_detail.clone(), item.state))
+    }
+
+    /// Get the name, memory breakdown, memory limit, and state of the selected container
+    pub fn get_selected_mem_detail(&self) -> Option<MemDetailTuple> {
+        self.get_selected_container().map(|item| {
+            (
+                item.name.clone(),
+                item.mem_detail,
+                item.mem_limit,
+                item.state,
+            )
+        })
+    }
+
     /// Get mutable Option of the current selected container
     fn get_mut_selected_container(&mut self) -> Option<&mut ContainerItem> {
         self.containers
@@ -630,78 +913,388 @@ impl AppData {
     /// Get the title for log panel for selected container, will be either
     /// 1) "logs x/x - container_name - container_image"
     /// 2) "logs - container_name - container_image" when no logs found
-    /// 3) " " no container currently selected - aka no containers on system
+    /// 3) "logs x/x - merged - x containers" when the logs of every marked, or filtered, container are merged
+    /// 4) " " no container currently selected - aka no containers on system
+    ///
+    /// If a minimum level is set, it's appended to the title, then the filter, and if searching, the match position & count, or search status, is appended after that
     pub fn get_log_title(&self) -> String {
+        let mut title = self.get_log_source_title();
+        if let Some(logs) = self.get_current_logs() {
+            let level = logs.get_min_level().map(|i| format!("{i} & above"));
+            let filter = logs.get_filter().and_then(LogFilter::title);
+            let search = self
+                .log_search
+                .as_ref()
+                .and_then(|search| search.title(logs.selected().unwrap_or_default()));
+            for section in [level, filter, search].into_iter().flatten() {
+                title.push_str(" - ");
+                title.push_str(&section);
+            }
+        }
+        title
+    }
+
+    /// The log title, without any search information
+    fn get_log_source_title(&self) -> String {
+        let prefix = |logs: &Logs| {
+            let logs_len = logs.get_state_title();
+            if logs_len.is_empty() {
+                String::from(" ")
+            } else {
+                format!("{logs_len} ")
+            }
+        };
+        if let Some(merged) = self.merged_logs.as_ref() {
+            return format!(
+                "{}- merged - {} containers",
+                prefix(merged.logs()),
+                merged.container_count()
+            );
+        }
         self.get_selected_container()
             .map_or_else(String::new, |ci| {
-                let logs_len = ci.logs.get_state_title();
-                let prefix = if logs_len.is_empty() {
-                    String::from(" ")
-                } else {
-                    format!("{logs_len} ")
-                };
-                format!("{}- {} - {}", prefix, ci.name.get(), ci.image.get())
+                format!(
+                    "{}- {} - {}",
+                    prefix(&ci.logs),
+                    ci.name.get(),
+                    ci.image.get()
+                )
             })
     }
 
+    /// The logs currently shown in the logs panel, either the merged logs, or the selected containers logs
+    fn get_current_logs(&self) -> Option<&Logs> {
+        self.merged_logs.as_ref().map_or_else(
+            || self.get_selected_container().map(|i| &i.logs),
+            |merged| Some(merged.logs()),
+        )
+    }
+
+    /// Mutable version of get_current_logs()
+    fn get_mut_current_logs(&mut self) -> Option<&mut Logs> {
+        if self.merged_logs.is_some() {
+            self.merged_logs.as_mut().map(MergedLogs::logs_mut)
+        } else {
+            self.get_mut_selected_container().map(|i| &mut i.logs)
+        }
+    }
+
+    /// The containers whose logs are merged, every marked container, or every filtered container if none are marked
+    fn get_merge_containers(&self) -> Vec<&ContainerItem> {
+        let marked = self
+            .containers
+            .items
+            .iter()
+            .filter(|i| i.marked)
+            .collect::<Vec<_>>();
+        if marked.is_empty() {
+            self.containers.items.iter().collect()
+        } else {
+            marked
+        }
+    }
+
+    /// Switch the logs panel between the selected containers logs, and the merged logs of every marked, or filtered, container
+    pub fn toggle_merged_logs(&mut self) {
+        self.merged_logs = if self.merged_logs.is_some() {
+            None
+        } else {
+            Some(MergedLogs::new(&self.get_merge_containers()))
+        };
+        self.redraw.update();
+    }
+
+    /// Mark, or unmark, the selected container, if the merged logs are shown, they're rebuilt to match the marked containers
+    pub fn toggle_mark(&mut self) {
+        if let Some(container) = self.get_mut_selected_container() {
+            container.marked = !container.marked;
+            self.sync_merged_logs();
+            self.redraw.update();
+        }
+    }
+
+    /// Rebuild the merged logs if the marked, or filtered, containers have changed since they were merged, keeping any log filter
+    fn sync_merged_logs(&mut self) {
+        if self
+            .merged_logs
+            .as_ref()
+            .is_some_and(|merged| !merged.matches(&self.get_merge_containers()))
+        {
+            let filter = self
+                .merged_logs
+                .as_ref()
+                .and_then(|i| i.logs().get_filter().cloned());
+            let min_level = self
+                .merged_logs
+                .as_ref()
+                .and_then(|i| i.logs().get_min_level());
+            let mut merged = MergedLogs::new(&self.get_merge_containers());
+            if filter.is_some() {
+                merged.logs_mut().set_filter(filter);
+            }
+            if min_level.is_some() {
+                merged.logs_mut().set_min_level(min_level);
+            }
+            self.merged_logs = Some(merged);
+            self.redraw.update();
+        }
+    }
+
+    /// The ids, and last log update time, of the containers whose logs are currently shown, either every merged container, or just the selected container
+    pub fn get_log_update_targets(&self) -> Vec<(ContainerId, u64)> {
+        if self.merged_logs.is_some() {
+            self.get_merge_containers()
+                .into_iter()
+                .filter(|i| !i.is_oxker)
+                .map(|i| (i.id.clone(), i.last_updated))
+                .collect()
+        } else {
+            self.get_selected_container()
+                .map(|i| vec![(i.id.clone(), i.last_updated)])
+                .unwrap_or_default()
+        }
+    }
+
     /// select next selected log line
     pub fn log_next(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.logs.next();
+        if let Some(i) = self.get_mut_current_logs() {
+            i.next();
             self.redraw.update();
         }
     }
 
     /// select previous selected log line
     pub fn log_previous(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.logs.previous();
+        if let Some(i) = self.get_mut_current_logs() {
+            i.previous();
             self.redraw.update();
         }
     }
 
     /// select last selected log line
     pub fn log_end(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.logs.end();
+        if let Some(i) = self.get_mut_current_logs() {
+            i.end();
             self.redraw.update();
         }
     }
 
     /// select first selected log line
     pub fn log_start(&mut self) {
-        if let Some(i) = self.get_mut_selected_container() {
-            i.logs.start();
+        if let Some(i) = self.get_mut_current_logs() {
+            i.start();
             self.redraw.update();
         }
     }
 
-    /// Get mutable Vec of current containers logs
+    /// Get mutable Vec of current containers logs, with any log search matches highlighted
     pub fn get_logs(&self, height: u16, padding: usize) -> Vec<ListItem<'static>> {
+        let search = self.log_search.as_ref().map(|search| {
+            (
+                search,
+                Style::default()
+                    .bg(self.config.app_colors.logs.search_match_background)
+                    .fg(self.config.app_colors.logs.search_match_text),
+            )
+        });
+        self.get_current_logs()
+            .map_or(vec![], |i| i.to_vec(height.into(), padding, search))
+    }
+
+    /// Get mutable Option of the currently shown Logs state
+    pub fn get_log_state(&mut self) -> Option<&mut ListState> {
+        self.get_mut_current_logs().map(Logs::state)
+    }
+
+    /// Log filter related methods
+    /// The filter of the currently shown logs
+    pub fn get_log_filter(&self) -> Option<LogFilter> {
+        self.get_current_logs().and_then(Logs::get_filter).cloned()
+    }
+
+    /// Edit the filter of the currently shown logs, creating one if needed
+    pub fn log_filter_edit(&mut self, edit: impl FnOnce(&mut LogFilter)) {
+        if let Some(logs) = self.get_mut_current_logs() {
+            logs.edit_filter(edit);
+            self.redraw.update();
+        }
+    }
+
+    /// Remove the filter of the currently shown logs
+    pub fn log_filter_clear(&mut self) {
+        if let Some(logs) = self.get_mut_current_logs() {
+            logs.set_filter(None);
+            self.redraw.update();
+        }
+    }
+
+    /// Remove the filter of the currently shown logs if both terms are empty, used once editing is finished
+    pub fn log_filter_confirm(&mut self) {
+        if self.get_log_filter().is_some_and(|i| i.is_empty()) {
+            self.log_filter_clear();
+        }
+    }
+
+    /// Cycle the minimum level of the currently shown logs, lines with a lower detected level are hidden
+    pub fn log_level_next(&mut self) {
+        if let Some(logs) = self.get_mut_current_logs() {
+            logs.set_min_level(LogLevel::threshold_next(logs.get_min_level()));
+            self.redraw.update();
+        }
+    }
+
+    /// The selected log line of the currently shown logs, pretty printed if it's a structured line, to be shown in a popup
+    pub fn get_log_detail(&self) -> Option<LogDetail> {
+        let logs = self.get_current_logs()?;
+        let line = logs.lines().nth(logs.selected()?)?;
+        let lines = line
+            .structured
+            .as_deref()
+            .and_then(StructuredLog::parse)
+            .map_or_else(
+                || line.lines.iter().map(ToString::to_string).collect(),
+                |structured| structured.pretty(),
+            );
+        Some(LogDetail {
+            lines,
+            timestamp: line
+                .tz
+                .display_with_formatter(
+                    self.config.timezone.as_ref(),
+                    &self.config.timestamp_format,
+                )
+                .unwrap_or_else(|| line.tz.to_string()),
+        })
+    }
+
+    /// Log search related methods
+    /// The term, and mode, of the current log search
+    pub fn get_log_search(&self) -> Option<(String, SearchMode)> {
+        self.log_search
+            .as_ref()
+            .map(|i| (i.get_term().to_owned(), i.get_mode()))
+    }
+
+    /// Start searching the logs, an existing search is kept, so that the term can be edited
+    pub fn log_search_start(&mut self) {
+        if self.log_search.is_none() {
+            self.log_search = Some(LogSearch::new());
+        }
+        self.redraw.update();
+    }
+
+    /// Remove the log search, and with it any highlighted matches
+    pub fn log_search_clear(&mut self) {
+        self.log_search = None;
+        self.redraw.update();
+    }
+
+    /// Add a char to the search term, and select the nearest match
+    pub fn log_search_push(&mut self, c: char) {
+        if let Some(search) = self.log_search.as_mut() {
+            search.push(c);
+            self.log_search_select(LogSearch::nearest);
+        }
+    }
+
+    /// Remove the last char from the search term, and select the nearest match
+    pub fn log_search_pop(&mut self) {
+        if let Some(search) = self.log_search.as_mut() {
+            search.pop();
+            self.log_search_select(LogSearch::nearest);
+        }
+    }
+
+    /// Switch between plain & regex searching, and select the nearest match
+    pub fn log_search_mode_next(&mut self) {
+        if let Some(search) = self.log_search.as_mut() {
+            search.mode_next();
+            self.log_search_select(LogSearch::nearest);
+        }
+    }
+
+    /// Select the next log search match, wrapping around to the first
+    pub fn log_search_next(&mut self) {
+        self.log_search_select(LogSearch::next);
+    }
+
+    /// Select the previous log search match, wrapping around to the last
+    pub fn log_search_previous(&mut self) {
+        self.log_search_select(LogSearch::previous);
+    }
+
+    /// Select a log search match, relative to the currently selected log line
+    fn log_search_select(&mut self, find: fn(&LogSearch, usize) -> Option<usize>) {
+        self.refresh_log_search();
+        let selected = self
+            .get_current_logs()
+            .and_then(Logs::selected)
+            .unwrap_or_default();
+        if let Some(index) = self.log_search.as_ref().and_then(|i| find(i, selected))
+            && let Some(logs) = self.get_mut_current_logs()
+        {
+            logs.select(index);
+        }
+        self.redraw.update();
+    }
+
+    /// Match the log search against any log lines, of the currently shown logs, that haven't yet been searched
+    pub fn refresh_log_search(&mut self) {
+        if let Some(mut search) = self.log_search.take() {
+            let id = if self.merged_logs.is_some() {
+                None
+            } else {
+                self.get_selected_container_id()
+            };
+            if let Some(logs) = self.get_current_logs() {
+                search.refresh(id.as_ref(), logs);
+            }
+            self.log_search = Some(search);
+        }
+    }
+
+    /// Chart data related methods
+    /// Get mutable Option of the currently selected container chart data
+    pub fn get_chart_data(&self) -> Option<(CpuTuple, MemTuple)> {
         self.containers
             .state
             .selected()
             .and_then(|i| self.containers.items.get(i))
-            .map_or(vec![], |i| i.logs.to_vec(height.into(), padding))
+            .map(|i| i.get_chart_data(self.cpu_mode, self.get_chart_points()))
     }
 
-    /// Get mutable Option of the currently selected container Logs state
-    pub fn get_log_state(&mut self) -> Option<&mut ListState> {
+    /// Get the time, in ms, of each point in the cpu & memory charts of the currently selected container
+    pub fn get_chart_times(&self) -> Vec<i64> {
         self.containers
             .state
             .selected()
-            .and_then(|i| self.containers.items.get_mut(i))
-            .map(|i| i.logs.state())
+            .and_then(|i| self.containers.items.get(i))
+            .map(|i| i.get_chart_times(self.get_chart_points()))
+            .unwrap_or_default()
     }
 
-    /// Chart data related methods
-    /// Get mutable Option of the currently selected container chart data
-    pub fn get_chart_data(&self) -> Option<(CpuTuple, MemTuple)> {
+    /// Get the network chart data of the currently selected container, within the chart window, only if the network chart is enabled
+    pub fn get_net_chart_data(&self) -> Option<RateTuple> {
+        if !self.config.show_net_chart {
+            return None;
+        }
+        self.containers
+            .state
+            .selected()
+            .and_then(|i| self.containers.items.get(i))
+            .map(|i| i.get_net_chart_data(self.get_chart_points()))
+    }
+
+    /// Get the block I/O chart data of the currently selected container, within the chart window, only if the I/O chart is enabled
+    pub fn get_io_chart_data(&self) -> Option<RateTuple> {
+        if !self.config.show_io_chart {
+            return None;
+        }
         self.containers
             .state
             .selected()
             .and_then(|i| self.containers.items.get(i))
-            .map(container_state::ContainerItem::get_chart_data)
+            .map(|i| i.get_io_chart_data(self.get_chart_points()))
     }
 
     /// Error related methods
@@ -742,13 +1335,18 @@ impl AppData {
     pub fn get_width(&self) -> Columns {
         let mut columns = Columns::new();
         let count = |x: &str| u8::try_from(x.chars().count()).unwrap_or(12);
+        columns.cpu.1 = count(&format!("{}{}", Header::Cpu, self.cpu_mode.header_suffix()));
+        columns.sparkline = self.config.sparkline.map(|metric| {
+            (
+                Header::Sparkline,
+                count(&format!("{}{}", Header::Sparkline, metric.header_suffix()))
+                    .max(self.config.sparkline_width),
+            )
+        });
 
         for container in [&self.containers.items, &self.hidden_containers] {
             for container in container {
-                let cpu_count = container.cpu_stats.back().map_or_else(
-                    || count(&CpuStats::default().to_string()),
-                    |i| count(&i.to_string()),
-                );
+                let cpu_count = count(&container.cpu_display(self.cpu_mode));
 
                 let mem_current_count = container.mem_stats.back().map_or_else(
                     || count(&ByteStats::default().to_string()),
@@ -760,8 +1358,23 @@ impl AppData {
                 columns.mem.1 = columns.mem.1.max(mem_current_count);
                 columns.mem.2 = columns.mem.2.max(count(&container.mem_limit.to_string()));
                 columns.name.1 = columns.name.1.max(count(&container.name.to_string()));
-                columns.net_rx.1 = columns.net_rx.1.max(count(&container.rx.to_string()));
-                columns.net_tx.1 = columns.net_tx.1.max(count(&container.tx.to_string()));
+                columns.net_rx.1 = columns
+                    .net_rx
+                    .1
+                    .max(count(&container.rx_display(self.show_net_total)));
+                columns.net_tx.1 = columns
+                    .net_tx
+                    .1
+                    .max(count(&container.tx_display(self.show_net_total)));
+                columns.block_read.1 = columns
+                    .block_read
+                    .1
+                    .max(count(&container.block_io.current.0.to_string()));
+                columns.block_write.1 = columns
+                    .block_write
+                    .1
+                    .max(count(&container.block_io.current.1.to_string()));
+                columns.pids.1 = columns.pids.1.max(count(&container.pids.to_string()));
                 columns.state.1 = columns.state.1.max(count(&container.state.to_string()));
                 columns.status.1 = columns.status.1.max(count(container.status.get()));
             }
@@ -779,7 +1392,8 @@ impl AppData {
         }
     }
 
-    /// Update container mem, cpu, & network stats, in single function so only need to call .lock() once
+    #[allow(clippy::too_many_arguments)]
+    /// Update container mem, cpu, network, & block I/O stats, in single function so only need to call .lock() once
     /// Will also, if a sort is set, sort the containers
     pub fn update_stats_by_id(
         &mut self,
@@ -787,26 +1401,42 @@ impl AppData {
         cpu_stat: Option<f64>,
         mem_stat: Option<u64>,
         mem_limit: u64,
-        rx: u64,
-        tx: u64,
+        networks: Vec<NetworkInterface>,
+        block: (u64, u64),
+        pids: ContainerPids,
+        timestamp_ms: i64,
     ) {
+        let history = self.history;
         if let Some(container) = self.get_any_container_by_id(id) {
-            if container.cpu_stats.len() >= 60 {
-                container.cpu_stats.pop_front();
-            }
-            if container.mem_stats.len() >= 60 {
-                container.mem_stats.pop_front();
-            }
-
             if let Some(cpu) = cpu_stat {
-                container.cpu_stats.push_back(CpuStats::new(cpu));
+                history.push(
+                    &mut container.cpu_stats,
+                    &mut container.cpu_bucket,
+                    CpuStats::new(cpu),
+                );
+                history.push(
+                    &mut container.stats_times,
+                    &mut container.time_bucket,
+                    timestamp_ms,
+                );
             }
             if let Some(mem) = mem_stat {
-                container.mem_stats.push_back(ByteStats::new(mem));
+                history.push(
+                    &mut container.mem_stats,
+                    &mut container.mem_bucket,
+                    ByteStats::new(mem),
+                );
             }
 
-            container.rx.update(rx);
-            container.tx.update(tx);
+            let net = NetworkInterface::sum(&networks);
+            container.rx.update(net.0);
+            container.tx.update(net.1);
+            container.net_io.update(net, timestamp_ms, history);
+            container.networks = networks;
+            container.pids = pids;
+            container.block_read.update(block.0);
+            container.block_write.update(block.1);
+            container.block_io.update(block, timestamp_ms, history);
             container.mem_limit.update(mem_limit);
         }
         if self.is_selected_container(id) {
@@ -815,6 +1445,60 @@ impl AppData {
         self.sort_containers();
     }
 
+    /// Update the cpu throttling & per core usage of a container, as well as the number of cpus on the host
+    pub fn update_cpu_detail_by_id(
+        &mut self,
+        id: &ContainerId,
+        throttled: f64,
+        throttled_time: u64,
+        per_core: Vec<f64>,
+        online_cpus: u64,
+    ) {
+        let history = self.history;
+        if let Some(container) = self.get_any_container_by_id(id) {
+            container
+                .cpu_detail
+                .update(history, throttled, throttled_time, per_core);
+            container.cpu_limit.online_cpus = online_cpus;
+        }
+    }
+
+    /// Update the memory breakdown of a container
+    pub fn update_mem_detail_by_id(&mut self, id: &ContainerId, mem_detail: Option<MemoryDetail>) {
+        if let Some(container) = self.get_any_container_by_id(id) {
+            container.mem_detail = mem_detail;
+        }
+    }
+
+    /// Check if a container still needs to be inspected in order to find its cpu limit
+    pub fn cpu_limit_uninspected(&self, id: &ContainerId) -> bool {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .find(|i| &i.id == id)
+            .is_some_and(|i| !i.cpu_limit.inspected)
+    }
+
+    /// Get the name of a container, either visible or hidden, by id
+    pub fn get_any_container_name_by_id(&self, id: &ContainerId) -> Option<String> {
+        self.containers
+            .items
+            .iter()
+            .chain(self.hidden_containers.iter())
+            .find(|i| &i.id == id)
+            .map(|i| i.name.get().to_owned())
+    }
+
+    /// Set the cpu limit of a container, from the result of a docker inspect, and re-sort, as the order may have changed
+    pub fn update_cpu_quota_by_id(&mut self, id: &ContainerId, nano_cpus: Option<u64>) {
+        if let Some(container) = self.get_any_container_by_id(id) {
+            container.cpu_limit.nano_cpus = nano_cpus;
+            container.cpu_limit.inspected = true;
+        }
+        self.sort_containers();
+    }
+
     /// Update, or insert, containers
     pub fn update_containers(&mut self, mut all_containers: Vec<ContainerSummary>) {
         let all_ids = self
@@ -826,7 +1510,7 @@ impl AppData {
 
         // Only sort it no containers currently set, as afterwards the order is fixed
         if self.containers.items.is_empty() {
-            all_containers.sort_by(|a, b| a.created.cmp(&b.created));
+            all_containers.sort_by_key(|a| a.created);
         }
 
         if !all_containers.is_empty() && self.containers.state.selected().is_none() {
@@ -861,7 +1545,7 @@ impl AppData {
                         if f.starts_with('/') {
                             f.remove(0);
                         }
-                        (*f).to_string()
+                        (*f).clone()
                     })
                 });
 
@@ -930,6 +1614,7 @@ impl AppData {
             }
             // self.redraw.set_true("update_containers");
         }
+        self.sync_merged_logs();
     }
 
     /// Update logs of a given container, based on id
@@ -940,32 +1625,65 @@ impl AppData {
         let config_tz = self.config.timezone.clone();
 
         let show_timestamp = self.config.show_timestamp;
+        let structured_logs = self.config.structured_logs;
+        let colors = self.config.app_colors;
+
+        let merge = self.merged_logs.is_some();
+        let mut merged_lines = vec![];
 
         if let Some(container) = self.get_any_container_by_id(id) {
             if !container.is_oxker {
                 container.last_updated = Self::get_systemtime();
                 let current_len = container.logs.len();
-                for mut i in logs {
+                for i in logs {
                     let (log_tz, log_content) = LogsTz::splitter(i.as_str());
-                    if show_timestamp {
-                        i = format!(
-                            "{} {}",
-                            log_tz
-                                .display_with_formatter(config_tz.as_ref(), &format)
-                                .unwrap_or_else(|| log_tz.to_string()),
-                            log_content
-                        );
-                    } else {
-                        i = log_content;
+                    let stripped = log_sanitizer::strip_ansi(&log_content);
+                    let level =
+                        LogLevel::detect(&stripped, container.logs.last().and_then(|i| i.level));
+                    let structured = structured_logs
+                        .then(|| StructuredLog::parse(&stripped))
+                        .flatten();
+                    let timestamp = show_timestamp.then(|| {
+                        log_tz
+                            .display_with_formatter(config_tz.as_ref(), &format)
+                            .unwrap_or_else(|| log_tz.to_string())
+                    });
+                    let i = timestamp.as_ref().map_or_else(
+                        || log_content.clone(),
+                        |timestamp| format!("{timestamp} {log_content}"),
+                    );
+                    let mut lines = structured.as_ref().map_or_else(
+                        || {
+                            if color {
+                                log_sanitizer::colorize_logs(&i)
+                            } else if raw {
+                                log_sanitizer::raw(&i)
+                            } else {
+                                log_sanitizer::remove_ansi(&i)
+                            }
+                        },
+                        |structured| vec![structured.to_line(timestamp, level, &colors)],
+                    );
+                    if let Some(level_color) = level.map(|i| i.color(&colors))
+                        && level_color != Color::Reset
+                    {
+                        lines = lines
+                            .into_iter()
+                            .map(|line| line.patch_style(Style::default().fg(level_color)))
+                            .collect();
                     }
-                    let lines = if color {
-                        log_sanitizer::colorize_logs(&i)
-                    } else if raw {
-                        log_sanitizer::raw(&i)
-                    } else {
-                        log_sanitizer::remove_ansi(&i)
+                    let line = LogLine {
+                        level,
+                        lines,
+                        structured: structured.is_some().then_some(stripped),
+                        tz: log_tz,
                     };
-                    container.logs.insert(ListItem::new(lines), log_tz);
+                    if container.logs.insert(line)
+                        && merge
+                        && let Some(line) = container.logs.last()
+                    {
+                        merged_lines.push(line.clone());
+                    }
                 }
 
                 // Set the logs selected row for each container
@@ -976,7 +1694,13 @@ impl AppData {
                     container.logs.end();
                 }
             }
-            if self.is_selected_container(id) {
+            let name = container.name.get().to_owned();
+            if let Some(merged) = self.merged_logs.as_mut() {
+                for line in &merged_lines {
+                    merged.insert(id, &name, line);
+                }
+            }
+            if self.is_selected_container(id) || !merged_lines.is_empty() {
                 self.redraw.update();
             }
         }
@@ -988,7 +1712,9 @@ impl AppData {
 mod tests {
 
     use super::*;
-    use crate::tests::{gen_appdata, gen_container_summary, gen_containers};
+    use crate::config::AppColors;
+    use crate::tests::{gen_appdata, gen_container_summary, gen_containers, gen_networks};
+    use ratatui::style::{Modifier, Style};
     use std::collections::VecDeque;
 
     // ******* //
@@ -1224,6 +1950,108 @@ mod tests {
         assert_eq!(c.id, ContainerId::from("3"));
     }
 
+    #[test]
+    /// Sort by header: block read
+    fn test_app_data_set_sort_by_header_block_read() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.block_io.current.0 = RateStats::new(40);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.block_io.current.0 = RateStats::new(80);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.block_io.current.0 = RateStats::new(2);
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::BlockRead, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("3"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::BlockRead, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("2"));
+    }
+
+    #[test]
+    /// Sort by header: block write
+    fn test_app_data_set_sort_by_header_block_write() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.block_io.current.1 = RateStats::new(400);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.block_io.current.1 = RateStats::new(80);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.block_io.current.1 = RateStats::new(83);
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::BlockWrite, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("2"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::BlockWrite, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+    }
+
+    #[test]
+    /// Sort by header: pids
+    fn test_app_data_set_sort_by_header_pids() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.pids = ContainerPids::new(Some(40), Some(100));
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.pids = ContainerPids::new(Some(8), None);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.pids = ContainerPids::new(Some(9), Some(10));
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::Pids, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("2"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Pids, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+    }
+
     #[test]
     /// Sort by header: rx
     fn test_app_data_set_sort_by_header_rx() {
@@ -1235,13 +2063,13 @@ mod tests {
         assert_eq!(result, &containers);
 
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.rx = ByteStats::new(40);
+            i.net_io.current.0 = RateStats::new(40);
         }
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.rx = ByteStats::new(80);
+            i.net_io.current.0 = RateStats::new(80);
         }
         if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.rx = ByteStats::new(2);
+            i.net_io.current.0 = RateStats::new(2);
         }
 
         // descending
@@ -1252,50 +2080,355 @@ mod tests {
         assert_eq!(b.id, ContainerId::from("1"));
         assert_eq!(c.id, ContainerId::from("3"));
 
-        // ascending
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("3"));
-        assert_eq!(b.id, ContainerId::from("1"));
-        assert_eq!(c.id, ContainerId::from("2"));
+        // ascending
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("3"));
+        assert_eq!(b.id, ContainerId::from("1"));
+        assert_eq!(c.id, ContainerId::from("2"));
+    }
+
+    #[test]
+    /// Sort by header: tx
+    fn test_app_data_set_sort_by_header_tx() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+        app_data.show_net_total = true;
+
+        let result = app_data.get_container_items();
+        assert_eq!(result, &containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.rx = ByteStats::new(400);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.rx = ByteStats::new(80);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.rx = ByteStats::new(83);
+        }
+
+        // descending
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("2"));
+
+        // ascending
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+    }
+
+    #[test]
+    /// Toggling between network rates & totals re-sorts the containers, and changes the rx/tx column widths
+    fn test_app_data_toggle_show_net_total() {
+        let (_ids, containers) = gen_containers();
+
+        let mut app_data = gen_appdata(&containers);
+
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
+            i.rx = ByteStats::new(1_500_000_000);
+            i.net_io.current.0 = RateStats::new(2);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
+            i.rx = ByteStats::new(80);
+            i.net_io.current.0 = RateStats::new(400);
+        }
+        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
+            i.rx = ByteStats::new(83);
+            i.net_io.current.0 = RateStats::new(83);
+        }
+
+        app_data.set_sorted(Some((Header::Rx, SortedOrder::Desc)));
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("2"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("1"));
+        assert_eq!(app_data.get_width().net_rx.1, 9);
+
+        app_data.toggle_show_net_total();
+        assert!(app_data.get_show_net_total());
+        let result = app_data.get_container_items();
+        let (a, b, c) = (&result[0], &result[1], &result[2]);
+        assert_eq!(a.id, ContainerId::from("1"));
+        assert_eq!(b.id, ContainerId::from("3"));
+        assert_eq!(c.id, ContainerId::from("2"));
+        assert_eq!(app_data.get_width().net_rx.1, 7);
+    }
+
+    #[test]
+    /// Cpu usage is normalised by the cpu mode, which changes the sort order, the displayed values, and the column width
+    fn test_app_data_cycle_cpu_mode() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        for (id, cpu) in [(&ids[0], 100.0), (&ids[1], 150.0), (&ids[2], 10.0)] {
+            app_data.update_stats_by_id(
+                id,
+                Some(cpu),
+                Some(10),
+                10,
+                vec![],
+                (0, 0),
+                ContainerPids::default(),
+                0,
+            );
+            app_data.update_cpu_detail_by_id(id, 0.0, 0, vec![], 4);
+        }
+        assert!(app_data.cpu_limit_uninspected(&ids[0]));
+        app_data.update_cpu_quota_by_id(&ids[0], Some(500_000_000));
+        assert!(!app_data.cpu_limit_uninspected(&ids[0]));
+
+        app_data.set_sorted(Some((Header::Cpu, SortedOrder::Desc)));
+        let order = |app_data: &AppData| {
+            app_data
+                .get_container_items()
+                .iter()
+                .map(|i| i.id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(app_data.get_cpu_mode(), CpuMode::PerCore);
+        assert_eq!(
+            order(&app_data),
+            [ids[1].clone(), ids[0].clone(), ids[2].clone()]
+        );
+        assert_eq!(app_data.get_width().cpu.1, 7);
+
+        app_data.cycle_cpu_mode();
+        assert_eq!(app_data.get_cpu_mode(), CpuMode::Host);
+        assert_eq!(
+            order(&app_data),
+            [ids[1].clone(), ids[0].clone(), ids[2].clone()]
+        );
+        let item = &app_data.get_container_items()[1];
+        assert_eq!(item.cpu_display(CpuMode::Host), "25.00%");
+        assert_eq!(app_data.get_width().cpu.1, 8);
+
+        // Without a quota, the host cpus are used instead
+        app_data.cycle_cpu_mode();
+        assert_eq!(app_data.get_cpu_mode(), CpuMode::Quota);
+        assert_eq!(
+            order(&app_data),
+            [ids[0].clone(), ids[1].clone(), ids[2].clone()]
+        );
+        let items = app_data.get_container_items();
+        assert_eq!(items[0].cpu_display(CpuMode::Quota), "200.00%");
+        assert_eq!(items[1].cpu_display(CpuMode::Quota), "37.50%");
+        assert_eq!(app_data.get_width().cpu.1, 9);
+
+        // Chart data follows the cpu mode
+        app_data.containers_start();
+        let ((dataset, max, _), _) = app_data.get_chart_data().unwrap();
+        assert_eq!(dataset, vec![(0.0, 200.0)]);
+        assert_eq!(max, CpuStats::new(200.0));
+
+        app_data.cycle_cpu_mode();
+        assert_eq!(app_data.get_cpu_mode(), CpuMode::PerCore);
+    }
+
+    #[test]
+    /// Memory can be shown as a percentage of the limit, which changes the sort order, and the row is styled at the warning & critical thresholds
+    fn test_app_data_toggle_show_mem_percent() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let colors = AppColors::new();
+
+        for (id, mem, limit) in [(&ids[0], 90, 100), (&ids[1], 100, 1000), (&ids[2], 10, 0)] {
+            app_data.update_stats_by_id(
+                id,
+                None,
+                Some(mem),
+                limit,
+                vec![],
+                (0, 0),
+                ContainerPids::default(),
+                0,
+            );
+        }
+
+        app_data.set_sorted(Some((Header::Memory, SortedOrder::Desc)));
+        let order = |app_data: &AppData| {
+            app_data
+                .get_container_items()
+                .iter()
+                .map(|i| i.id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert!(!app_data.get_show_mem_percent());
+        assert_eq!(
+            order(&app_data),
+            [ids[1].clone(), ids[0].clone(), ids[2].clone()]
+        );
+
+        app_data.toggle_show_mem_percent();
+        assert!(app_data.get_show_mem_percent());
+        assert_eq!(
+            order(&app_data),
+            [ids[0].clone(), ids[1].clone(), ids[2].clone()]
+        );
+
+        let items = app_data.get_container_items();
+        assert_eq!(items[0].mem_display(true), Some("90.00%".to_owned()));
+        assert_eq!(items[1].mem_display(true), Some("10.00%".to_owned()));
+        assert_eq!(items[2].mem_display(true), Some("n/a".to_owned()));
+        assert_eq!(items[0].mem_display(false), None);
+
+        assert_eq!(
+            items[0].get_mem_alert_style(&colors, 80, 95),
+            Some(Style::default().fg(colors.containers.memory_warning))
+        );
+        assert_eq!(
+            items[0].get_mem_alert_style(&colors, 80, 90),
+            Some(
+                Style::default()
+                    .fg(colors.containers.memory_critical)
+                    .add_modifier(Modifier::BOLD)
+            )
+        );
+        assert!(items[1].get_mem_alert_style(&colors, 80, 95).is_none());
+        assert!(items[2].get_mem_alert_style(&colors, 80, 95).is_none());
+
+        app_data.toggle_show_mem_percent();
+        assert!(!app_data.get_show_mem_percent());
     }
 
     #[test]
-    /// Sort by header: tx
-    fn test_app_data_set_sort_by_header_tx() {
-        let (_ids, containers) = gen_containers();
-
+    /// The chart window can be zoomed between the preset windows, limited by the length of the stats history, and the chart data, including the rate & throttled charts, only includes the points in the window
+    fn test_app_data_chart_zoom() {
+        let (ids, containers) = gen_containers();
         let mut app_data = gen_appdata(&containers);
+        app_data.config.stats_history_seconds = 600;
+        app_data.history = HistoryLength::new(600, 1000);
+
+        for i in 0..600u32 {
+            app_data.update_stats_by_id(
+                &ids[0],
+                Some(f64::from(i)),
+                Some(u64::from(i)),
+                1000,
+                vec![],
+                (u64::from(i) * 1000, 0),
+                ContainerPids::default(),
+                i64::from(i) * 1000,
+            );
+            app_data.update_cpu_detail_by_id(&ids[0], f64::from(i), 0, vec![], 1);
+        }
+        app_data.containers_start();
+        app_data.config.show_io_chart = true;
+        let throttled_len = |app_data: &AppData| {
+            let points = app_data.get_chart_points();
+            app_data
+                .get_selected_cpu_detail()
+                .map(|(_, cpu_detail, _)| cpu_detail.get_chart_data(points).0.len())
+        };
 
-        let result = app_data.get_container_items();
-        assert_eq!(result, &containers);
+        assert_eq!(app_data.get_chart_window_label(), "1m");
+        assert_eq!(app_data.get_chart_points(), 60);
+        let ((dataset, max, _), _) = app_data.get_chart_data().unwrap();
+        assert_eq!(dataset.len(), 60);
+        assert_eq!(dataset[0], (0.0, 540.0));
+        assert_eq!(max, CpuStats::new(599.0));
+        let (read, _, _, _) = app_data.get_io_chart_data().unwrap();
+        assert_eq!(read.len(), 60);
+        assert_eq!(throttled_len(&app_data), Some(60));
+
+        app_data.chart_zoom_out();
+        assert_eq!(app_data.get_chart_window_label(), "5m");
+        assert_eq!(app_data.get_chart_points(), 300);
+
+        app_data.chart_zoom_out();
+        assert_eq!(app_data.get_chart_window_label(), "10m");
+        let ((dataset, _, _), _) = app_data.get_chart_data().unwrap();
+        assert_eq!(dataset.len(), 600);
+        // The first sample only sets the baseline of the rates
+        let (read, _, max, _) = app_data.get_io_chart_data().unwrap();
+        assert_eq!(read.len(), 599);
+        assert_eq!(max, RateStats::new(1000));
+        assert_eq!(throttled_len(&app_data), Some(600));
+
+        // Can't zoom out past the history length
+        app_data.chart_zoom_out();
+        assert_eq!(app_data.get_chart_window_label(), "10m");
+
+        app_data.chart_zoom_in();
+        app_data.chart_zoom_in();
+        assert_eq!(app_data.get_chart_window_label(), "1m");
+
+        // Can't zoom in past the smallest window
+        app_data.chart_zoom_in();
+        assert_eq!(app_data.get_chart_window_label(), "1m");
+    }
 
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("1")) {
-            i.rx = ByteStats::new(400);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("2")) {
-            i.rx = ByteStats::new(80);
-        }
-        if let Some(i) = app_data.get_container_by_id(&ContainerId::from("3")) {
-            i.rx = ByteStats::new(83);
+    #[test]
+    /// The overview chart cycles between off, cpu, and memory, and only includes the containers with the highest current usage, up to the configured count
+    fn test_app_data_chart_overview() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.config.chart_overview_count = 2;
+
+        for (id, cpu, mem) in [
+            (&ids[0], 10.0, 300),
+            (&ids[1], 30.0, 100),
+            (&ids[2], 20.0, 200),
+        ] {
+            for _ in 0..3 {
+                app_data.update_stats_by_id(
+                    id,
+                    Some(cpu),
+                    Some(mem),
+                    1000,
+                    vec![],
+                    (0, 0),
+                    ContainerPids::default(),
+                    0,
+                );
+            }
         }
 
-        // descending
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Desc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("1"));
-        assert_eq!(b.id, ContainerId::from("3"));
-        assert_eq!(c.id, ContainerId::from("2"));
+        assert_eq!(app_data.chart_overview, ChartOverview::Off);
+        assert!(app_data.get_overview_chart_data().is_none());
 
-        // ascending
-        app_data.set_sorted(Some((Header::Rx, SortedOrder::Asc)));
-        let result = app_data.get_container_items();
-        let (a, b, c) = (&result[0], &result[1], &result[2]);
-        assert_eq!(a.id, ContainerId::from("2"));
-        assert_eq!(b.id, ContainerId::from("3"));
-        assert_eq!(c.id, ContainerId::from("1"));
+        app_data.cycle_chart_overview();
+        assert_eq!(app_data.chart_overview, ChartOverview::Cpu);
+        let (overview, series, max) = app_data.get_overview_chart_data().unwrap();
+        assert_eq!(overview, ChartOverview::Cpu);
+        assert_eq!(CpuStats::new(max), CpuStats::new(30.0));
+        assert_eq!(
+            series,
+            vec![
+                (
+                    ContainerName::from("container_2"),
+                    vec![(57.0, 30.0), (58.0, 30.0), (59.0, 30.0)]
+                ),
+                (
+                    ContainerName::from("container_3"),
+                    vec![(57.0, 20.0), (58.0, 20.0), (59.0, 20.0)]
+                ),
+            ]
+        );
+
+        app_data.cycle_chart_overview();
+        assert_eq!(app_data.chart_overview, ChartOverview::Memory);
+        let (overview, series, max) = app_data.get_overview_chart_data().unwrap();
+        assert_eq!(overview, ChartOverview::Memory);
+        assert_eq!(CpuStats::new(max), CpuStats::new(300.0));
+        assert_eq!(
+            series.iter().map(|i| i.0.get()).collect::<Vec<_>>(),
+            vec!["container_1", "container_3"]
+        );
+
+        app_data.cycle_chart_overview();
+        assert_eq!(app_data.chart_overview, ChartOverview::Off);
+        assert!(app_data.get_overview_chart_data().is_none());
     }
 
     #[test]
@@ -1331,6 +2464,7 @@ mod tests {
         let (_ids, containers) = gen_containers();
 
         let mut app_data = gen_appdata(&containers);
+        app_data.show_net_total = true;
 
         let result = app_data.get_container_items();
         assert_eq!(result, &containers);
@@ -1626,7 +2760,7 @@ mod tests {
         app_data.docker_controls_previous();
 
         let result = app_data.selected_docker_controls();
-        assert_eq!(result, Some(DockerCommand::Stop));
+        assert_eq!(result, Some(DockerCommand::Recreate));
 
         // previous has no effect when at start
         app_data.docker_controls_start();
@@ -1664,6 +2798,7 @@ mod tests {
             &mut vec![
                 DockerCommand::Start,
                 DockerCommand::Restart,
+                DockerCommand::Recreate,
                 DockerCommand::Delete,
             ],
         );
@@ -1672,6 +2807,7 @@ mod tests {
             &mut vec![
                 DockerCommand::Start,
                 DockerCommand::Restart,
+                DockerCommand::Recreate,
                 DockerCommand::Delete,
             ],
         );
@@ -1680,6 +2816,7 @@ mod tests {
             &mut vec![
                 DockerCommand::Resume,
                 DockerCommand::Stop,
+                DockerCommand::Recreate,
                 DockerCommand::Delete,
             ],
         );
@@ -1694,6 +2831,7 @@ mod tests {
                 DockerCommand::Pause,
                 DockerCommand::Restart,
                 DockerCommand::Stop,
+                DockerCommand::Recreate,
                 DockerCommand::Delete,
             ],
         );
@@ -1730,6 +2868,59 @@ mod tests {
         assert!(!app_data.can_insert(&containers[2]));
     }
 
+    #[test]
+    /// Containers hidden by the filter are still included when polling stats
+    fn test_app_data_filter_get_all_id_state() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        app_data.filter_term_push('_');
+        app_data.filter_term_push('2');
+        app_data.filter_containers();
+        assert_eq!(app_data.containers.items.len(), 1);
+
+        let mut result = app_data
+            .get_all_id_state()
+            .into_iter()
+            .map(|(_, id)| id)
+            .collect::<Vec<_>>();
+        result.sort();
+        assert_eq!(result, ids);
+    }
+
+    #[test]
+    /// Host summary combines the usage of every running container, including hidden ones, but ignores stopped containers with a stats history
+    fn test_app_data_get_host_summary() {
+        let (_, mut containers) = gen_containers();
+        for item in &mut containers {
+            item.cpu_stats = VecDeque::from([CpuStats::new(50.0)]);
+            item.mem_stats = VecDeque::from([ByteStats::new(100_000)]);
+        }
+        containers[1].state = State::Exited;
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_host_summary().is_none());
+
+        app_data.update_host_summary(HostSummary {
+            api_version: "1.47".to_owned(),
+            engine_version: "27.3.1".to_owned(),
+            kernel: "6.8.0".to_owned(),
+            mem_total: ByteStats::new(1_000_000),
+            ncpu: 4,
+            os: "Ubuntu".to_owned(),
+            paused: 0,
+            running: 2,
+            stopped: 1,
+        });
+        app_data.filter_term_push('3');
+        app_data.filter_containers();
+        assert_eq!(app_data.hidden_containers.len(), 2);
+
+        // 2 running containers using 50% of a single core, on a 4 core host, and 2 x 100 kB of 1 MB
+        let (_, cpu, mem) = app_data.get_host_summary().unwrap();
+        assert_eq!(cpu, CpuStats::new(25.0));
+        assert_eq!(mem, CpuStats::new(20.0));
+    }
+
     #[test]
     /// Data is filtered correctly by image
     fn test_app_data_filter_by_image() {
@@ -1899,6 +3090,319 @@ mod tests {
         assert_eq!(result, " 2/3 - container_1 - image_1");
     }
 
+    #[test]
+    /// Merged logs interleave the logs of every filtered container by timestamp, and are rebuilt when the filter changes
+    fn test_app_data_toggle_merged_logs() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers.start();
+
+        let gen_logs = |seconds: &[u8]| {
+            seconds
+                .iter()
+                .map(|i| format!("2025-01-01T00:00:0{i}.000000000Z line {i}"))
+                .collect::<Vec<_>>()
+        };
+        app_data.update_log_by_id(gen_logs(&[1, 3]), &ids[0]);
+        app_data.update_log_by_id(gen_logs(&[2]), &ids[1]);
+
+        app_data.toggle_merged_logs();
+        assert_eq!(app_data.get_log_title(), " 3/3 - merged - 3 containers");
+        assert_eq!(app_data.get_log_update_targets().len(), 3);
+
+        // New lines are inserted in timestamp order, and the last line stays selected
+        app_data.update_log_by_id(gen_logs(&[4]), &ids[2]);
+        app_data.update_log_by_id(gen_logs(&[0]), &ids[2]);
+        assert_eq!(app_data.get_log_title(), " 5/5 - merged - 3 containers");
+
+        // Navigation moves through the merged logs
+        app_data.log_start();
+        assert_eq!(app_data.get_log_title(), " 1/5 - merged - 3 containers");
+
+        // Filtering the containers rebuilds the merged logs
+        app_data.filter_term_push('1');
+        assert_eq!(app_data.get_log_title(), " 2/2 - merged - 1 containers");
+        assert_eq!(app_data.get_log_update_targets().len(), 1);
+        app_data.filter_term_clear();
+        assert_eq!(app_data.get_log_title(), " 5/5 - merged - 3 containers");
+
+        app_data.toggle_merged_logs();
+        assert!(app_data.merged_logs.is_none());
+        assert_eq!(app_data.get_log_title(), " 2/2 - container_1 - image_1");
+        assert_eq!(app_data.get_log_update_targets().len(), 1);
+    }
+
+    #[test]
+    /// Only marked containers are merged, if any are marked, and changing the marks rebuilds the merged logs
+    fn test_app_data_merged_logs_marked() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers.start();
+        for (index, id) in ids.iter().enumerate() {
+            app_data.update_log_by_id(
+                vec![format!("2025-01-01T00:00:0{index}.000000000Z line {index}")],
+                id,
+            );
+        }
+
+        app_data.toggle_mark();
+        app_data.containers_next();
+        app_data.containers_next();
+        app_data.toggle_mark();
+        assert!(app_data.containers.items[0].marked);
+        assert!(!app_data.containers.items[1].marked);
+
+        app_data.toggle_merged_logs();
+        assert_eq!(app_data.get_log_title(), " 2/2 - merged - 2 containers");
+        let targets = app_data
+            .get_log_update_targets()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        assert_eq!(targets, [ids[0].clone(), ids[2].clone()]);
+
+        // Unmarking rebuilds the merged logs, and once none are marked, every filtered container is merged
+        app_data.toggle_mark();
+        assert_eq!(app_data.get_log_title(), " 1/1 - merged - 1 containers");
+        app_data.containers_start();
+        app_data.toggle_mark();
+        assert_eq!(app_data.get_log_title(), " 3/3 - merged - 3 containers");
+    }
+
+    #[test]
+    /// Log search selects the nearest match as the term is typed, next & previous wrap around, and the match counter is in the title
+    fn test_app_data_log_search() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers.start();
+        app_data.update_log_by_id(
+            ["info a", "ERROR b", "info c", "error d", "info e"]
+                .iter()
+                .enumerate()
+                .map(|(index, line)| format!("2025-01-01T00:00:0{index}.000000000Z {line}"))
+                .collect(),
+            &ids[0],
+        );
+        assert_eq!(app_data.get_log_title(), " 5/5 - container_1 - image_1");
+
+        app_data.log_search_start();
+        assert_eq!(app_data.get_log_title(), " 5/5 - container_1 - image_1");
+        for c in "error".chars() {
+            app_data.log_search_push(c);
+        }
+        assert_eq!(
+            app_data.get_log_title(),
+            " 4/5 - container_1 - image_1 - match 2/2"
+        );
+
+        app_data.log_search_next();
+        assert_eq!(
+            app_data.get_log_title(),
+            " 2/5 - container_1 - image_1 - match 1/2"
+        );
+        app_data.log_search_previous();
+        assert_eq!(
+            app_data.get_log_title(),
+            " 4/5 - container_1 - image_1 - match 2/2"
+        );
+
+        // Plain terms are case insensitive, regex terms are not
+        app_data.log_search_mode_next();
+        assert_eq!(
+            app_data.get_log_search(),
+            Some(("error".to_owned(), SearchMode::Regex))
+        );
+        assert_eq!(
+            app_data.get_log_title(),
+            " 4/5 - container_1 - image_1 - match 1/1"
+        );
+        app_data.log_search_push('[');
+        assert_eq!(
+            app_data.get_log_title(),
+            " 4/5 - container_1 - image_1 - invalid regex"
+        );
+
+        app_data.log_search_clear();
+        assert!(app_data.get_log_search().is_none());
+        assert_eq!(app_data.get_log_title(), " 4/5 - container_1 - image_1");
+    }
+
+    #[test]
+    /// Log filter hides lines, is kept per container, and is shown in the log title
+    fn test_app_data_log_filter() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers.start();
+        app_data.update_log_by_id(
+            ["info a", "ERROR b", "info c", "error d", "info e"]
+                .iter()
+                .enumerate()
+                .map(|(index, line)| format!("2025-01-01T00:00:0{index}.000000000Z {line}"))
+                .collect(),
+            &ids[0],
+        );
+
+        for c in "error".chars() {
+            app_data.log_filter_edit(|f| f.push(c));
+        }
+        assert_eq!(
+            app_data.get_log_title(),
+            " 2/2 - container_1 - image_1 - include \"error\""
+        );
+
+        // Both an include, and an exclude, term can be set at once
+        app_data.log_filter_edit(|f| {
+            f.toggle_editing();
+            f.push('d');
+        });
+        assert_eq!(
+            app_data.get_log_title(),
+            " 1/1 - container_1 - image_1 - include \"error\" exclude \"d\""
+        );
+
+        // The filter is kept when the selected container changes
+        app_data.containers_next();
+        assert!(app_data.get_log_filter().is_none());
+        app_data.containers_previous();
+        assert_eq!(app_data.get_log_filter().unwrap().get_include(), "error");
+
+        // Confirming a filter with an empty term removes it
+        app_data.log_filter_clear();
+        assert!(app_data.get_log_filter().is_none());
+        app_data.log_filter_edit(|_| ());
+        assert!(app_data.get_log_filter().is_some());
+        app_data.log_filter_confirm();
+        assert!(app_data.get_log_filter().is_none());
+        assert_eq!(app_data.get_log_title(), " 5/5 - container_1 - image_1");
+    }
+
+    #[test]
+    /// Log levels are detected & coloured, and the minimum level hides lower level lines, but not lines without a level
+    fn test_app_data_log_level() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers.start();
+        app_data.update_log_by_id(
+            [
+                "DEBUG a",
+                "INFO b",
+                "level=warn msg=c",
+                r#"{"level":"error","msg":"d"}"#,
+                "    at e",
+                "f",
+            ]
+            .iter()
+            .enumerate()
+            .map(|(index, line)| format!("2025-01-01T00:00:0{index}.000000000Z {line}"))
+            .collect(),
+            &ids[0],
+        );
+
+        let logs = app_data.get_current_logs().unwrap();
+        assert_eq!(
+            logs.lines().map(|i| i.level).collect::<Vec<_>>(),
+            [
+                Some(LogLevel::Debug),
+                Some(LogLevel::Info),
+                Some(LogLevel::Warn),
+                Some(LogLevel::Error),
+                Some(LogLevel::Error),
+                None
+            ]
+        );
+        assert_eq!(
+            logs.lines()
+                .map(|i| i.lines[0].style.fg)
+                .collect::<Vec<_>>(),
+            [
+                Some(Color::Blue),
+                None,
+                Some(Color::Yellow),
+                Some(Color::Red),
+                Some(Color::Red),
+                None
+            ]
+        );
+
+        app_data.log_level_next();
+        assert_eq!(
+            app_data.get_log_title(),
+            " 6/6 - container_1 - image_1 - debug & above"
+        );
+        app_data.log_level_next();
+        app_data.log_level_next();
+        assert_eq!(
+            app_data.get_log_title(),
+            " 4/4 - container_1 - image_1 - warn & above"
+        );
+        app_data.log_level_next();
+        assert_eq!(
+            app_data.get_log_title(),
+            " 3/3 - container_1 - image_1 - error & above"
+        );
+        app_data.log_level_next();
+        assert_eq!(app_data.get_log_title(), " 6/6 - container_1 - image_1");
+    }
+
+    #[test]
+    /// Structured lines are only rendered when enabled, and the selected line can be expanded, pretty printed if structured
+    fn test_app_data_structured_logs() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers.start();
+        let logs = [
+            r#"{"level":"warn","msg":"slow query","ms":120}"#,
+            "ts=1 level=info msg=\"cache hit\" key=a",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(index, line)| format!("2025-01-01T00:00:0{index}.000000000Z {line}"))
+        .collect::<Vec<_>>();
+
+        app_data.update_log_by_id(logs.clone(), &ids[0]);
+        let detail = app_data.get_log_detail().unwrap();
+        assert_eq!(detail.lines, ["ts=1 level=info msg=\"cache hit\" key=a"]);
+        assert_eq!(
+            app_data
+                .get_current_logs()
+                .unwrap()
+                .lines()
+                .next()
+                .unwrap()
+                .structured,
+            None
+        );
+
+        app_data.config.structured_logs = true;
+        app_data.config.timestamp_format = "%H:%M:%S".to_owned();
+        app_data.update_log_by_id(logs, &ids[1]);
+        app_data.containers_next();
+        let current = app_data.get_current_logs().unwrap();
+        assert_eq!(
+            current
+                .lines()
+                .map(|i| i.lines[0].to_string())
+                .collect::<Vec<_>>(),
+            ["WARN  slow query ms=120", "INFO  cache hit key=a"]
+        );
+        assert_eq!(
+            current.lines().next().unwrap().lines[0].style.fg,
+            Some(Color::Yellow)
+        );
+
+        let detail = app_data.get_log_detail().unwrap();
+        assert_eq!(
+            detail.lines,
+            [
+                "ts    = 1",
+                "level = info",
+                "msg   = cache hit",
+                "key   = a"
+            ]
+        );
+        assert_eq!(detail.timestamp, "00:00:01");
+    }
+
     #[test]
     /// log title string generated correctly after container change
     fn test_app_data_get_log_title_after_container_change() {
@@ -2135,6 +3639,7 @@ mod tests {
                 (
                     vec![(0.0, 1.0), (1.0, 2.0)],
                     ByteStats::new(2),
+                    ByteStats::new(0),
                     State::Running(RunningState::Healthy),
                 )
             ))
@@ -2149,21 +3654,34 @@ mod tests {
     /// Header widths return correctly
     fn test_app_data_get_width() {
         let (_ids, containers) = gen_containers();
-        let app_data = gen_appdata(&containers);
+        let mut app_data = gen_appdata(&containers);
 
         let result = app_data.get_width();
-        let expected = Columns {
+        let mut expected = Columns {
             name: (Header::Name, 11),
             state: (Header::State, 9),
             status: (Header::Status, 9),
             cpu: (Header::Cpu, 6),
             mem: (Header::Memory, 7, 7),
+            sparkline: None,
             id: (Header::Id, 8),
             image: (Header::Image, 7),
-            net_rx: (Header::Rx, 7),
-            net_tx: (Header::Tx, 7),
+            net_rx: (Header::Rx, 9),
+            net_tx: (Header::Tx, 9),
+            block_read: (Header::BlockRead, 9),
+            block_write: (Header::BlockWrite, 9),
+            pids: (Header::Pids, 4),
         };
         assert_eq!(result, expected);
+
+        // The sparkline column is at least as wide as its header
+        app_data.config.sparkline = Some(SparklineMetric::Cpu);
+        expected.sparkline = Some((Header::Sparkline, 10));
+        assert_eq!(app_data.get_width(), expected);
+
+        app_data.config.sparkline_width = 5;
+        expected.sparkline = Some((Header::Sparkline, 9));
+        assert_eq!(app_data.get_width(), expected);
     }
 
     #[test]
@@ -2180,10 +3698,14 @@ mod tests {
             status: (Header::Status, 9),
             cpu: (Header::Cpu, 6),
             mem: (Header::Memory, 7, 7),
+            sparkline: None,
             id: (Header::Id, 8),
             image: (Header::Image, 7),
-            net_rx: (Header::Rx, 7),
-            net_tx: (Header::Tx, 7),
+            net_rx: (Header::Rx, 9),
+            net_tx: (Header::Tx, 9),
+            block_read: (Header::BlockRead, 9),
+            block_write: (Header::BlockWrite, 9),
+            pids: (Header::Pids, 4),
         };
 
         assert_eq!(result, expected);
@@ -2196,6 +3718,131 @@ mod tests {
     // Ports //
     // ***** //
 
+    #[test]
+    /// Cpu detail is updated, and the cpu column shows a throttled indicator when throttled in the latest sample
+    fn test_app_data_update_cpu_detail() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.update_stats_by_id(
+            &ids[0],
+            Some(10.0),
+            Some(10),
+            10,
+            vec![],
+            (0, 0),
+            ContainerPids::default(),
+            0,
+        );
+        assert!(app_data.get_selected_cpu_detail().is_none());
+        assert_eq!(
+            app_data.containers.items[0].cpu_display(CpuMode::PerCore),
+            "10.00%"
+        );
+
+        app_data.update_cpu_detail_by_id(&ids[0], 25.0, 5000, vec![6.0, 4.0], 2);
+        app_data.containers_start();
+        let (name, cpu_detail, _) = app_data.get_selected_cpu_detail().unwrap();
+        assert_eq!(name, ContainerName::from("container_1"));
+        assert_eq!(cpu_detail.throttled, VecDeque::from([CpuStats::new(25.0)]));
+        assert_eq!(cpu_detail.throttled_time, 5000);
+        assert_eq!(
+            cpu_detail.per_core,
+            vec![CpuStats::new(6.0), CpuStats::new(4.0)]
+        );
+        assert_eq!(
+            app_data.containers.items[0].cpu_display(CpuMode::PerCore),
+            "10.00%!"
+        );
+        assert_eq!(app_data.get_width().cpu.1, 7);
+
+        app_data.update_cpu_detail_by_id(&ids[0], 0.0, 5000, vec![], 2);
+        assert_eq!(
+            app_data.containers.items[0].cpu_display(CpuMode::PerCore),
+            "10.00%"
+        );
+    }
+
+    #[test]
+    /// Memory detail of the selected container is updated, and can be removed
+    fn test_app_data_update_mem_detail() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_selected_mem_detail().is_none());
+
+        let mem_detail = MemoryDetail {
+            usage: 1000,
+            anon: 400,
+            ..MemoryDetail::default()
+        };
+        app_data.update_mem_detail_by_id(&ids[0], Some(mem_detail));
+        app_data.containers_start();
+        let (name, result, _, _) = app_data.get_selected_mem_detail().unwrap();
+        assert_eq!(name, ContainerName::from("container_1"));
+        assert_eq!(result, Some(mem_detail));
+
+        app_data.update_mem_detail_by_id(&ids[0], None);
+        let (_, result, _, _) = app_data.get_selected_mem_detail().unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    /// Networks of the selected container, summed for the rx & tx columns, None if no interfaces
+    fn test_app_data_get_selected_networks() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+
+        let networks = vec![
+            NetworkInterface {
+                name: "eth0".to_owned(),
+                rx_bytes: 100,
+                tx_bytes: 200,
+                ..NetworkInterface::default()
+            },
+            NetworkInterface {
+                name: "eth1".to_owned(),
+                rx_bytes: 300,
+                tx_bytes: 400,
+                rx_errors: 2,
+                ..NetworkInterface::default()
+            },
+        ];
+        app_data.update_stats_by_id(
+            &ids[0],
+            None,
+            None,
+            0,
+            networks.clone(),
+            (0, 0),
+            ContainerPids::default(),
+            0,
+        );
+
+        // No containers selected
+        assert!(app_data.get_selected_networks().is_none());
+
+        app_data.containers_start();
+        assert_eq!(
+            app_data.get_selected_networks(),
+            Some((networks, State::Running(RunningState::Healthy)))
+        );
+        assert_eq!(app_data.containers.items[0].rx, ByteStats::new(400));
+        assert_eq!(app_data.containers.items[0].tx, ByteStats::new(600));
+
+        // Selected container with no interfaces
+        app_data.update_stats_by_id(
+            &ids[0],
+            None,
+            None,
+            0,
+            vec![],
+            (0, 0),
+            ContainerPids::default(),
+            0,
+        );
+        assert!(app_data.get_selected_networks().is_none());
+        assert_eq!(app_data.containers.items[0].rx, ByteStats::new(0));
+    }
+
     #[test]
     /// Returns selected containers ports ordered by private ip
     fn test_app_data_get_selected_ports() {
@@ -2280,7 +3927,16 @@ mod tests {
         let result = app_data.get_container_items();
         assert_eq!(result[0], containers[0]);
 
-        app_data.update_stats_by_id(&ids[0], Some(10.0), Some(10), 10, 10, 10);
+        app_data.update_stats_by_id(
+            &ids[0],
+            Some(10.0),
+            Some(10),
+            10,
+            gen_networks(10, 10),
+            (0, 0),
+            ContainerPids::default(),
+            0,
+        );
 
         let result = app_data.get_container_items();
         assert_ne!(result[0], containers[0]);
@@ -2291,6 +3947,65 @@ mod tests {
         assert_eq!(result[0].tx, ByteStats::new(10));
     }
 
+    #[test]
+    /// Network & block I/O rates are calculated from the difference between two cumulative totals, and a counter reset is treated as zero
+    fn test_app_data_update_stats_rates() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        let mut update = |net: (u64, u64), block: (u64, u64), timestamp_ms: i64| {
+            app_data.update_stats_by_id(
+                &ids[0],
+                None,
+                None,
+                0,
+                gen_networks(net.0, net.1),
+                block,
+                ContainerPids::default(),
+                timestamp_ms,
+            );
+        };
+
+        // First value only sets the baseline
+        update((100, 200), (1000, 2000), 1000);
+        update((300, 400), (5000, 4000), 3000);
+        update((500, 700), (1000, 10000), 4000);
+
+        let result = app_data.get_container_items();
+        assert_eq!(result[0].rx, ByteStats::new(500));
+        assert_eq!(result[0].tx, ByteStats::new(700));
+        assert_eq!(
+            result[0].net_io.current,
+            (RateStats::new(200), RateStats::new(300))
+        );
+        assert_eq!(
+            result[0].block_io.current,
+            (RateStats::new(0), RateStats::new(6000))
+        );
+        assert_eq!(
+            result[0].block_io.history,
+            (
+                VecDeque::from([RateStats::new(2000), RateStats::new(0)]),
+                VecDeque::from([RateStats::new(1000), RateStats::new(6000)])
+            )
+        );
+
+        app_data.containers_start();
+        assert!(app_data.get_io_chart_data().is_none());
+        assert!(app_data.get_net_chart_data().is_none());
+
+        app_data.config.show_io_chart = true;
+        let result = app_data.get_io_chart_data().unwrap();
+        assert_eq!(result.0, vec![(0.0, 2000.0), (1.0, 0.0)]);
+        assert_eq!(result.1, vec![(0.0, 1000.0), (1.0, 6000.0)]);
+        assert_eq!(result.2, RateStats::new(6000));
+
+        app_data.config.show_net_chart = true;
+        let result = app_data.get_net_chart_data().unwrap();
+        assert_eq!(result.0, vec![(0.0, 100.0), (1.0, 200.0)]);
+        assert_eq!(result.1, vec![(0.0, 100.0), (1.0, 300.0)]);
+        assert_eq!(result.2, RateStats::new(300));
+    }
+
     #[test]
     /// Update stats functioning
     fn test_app_data_update_containers() {
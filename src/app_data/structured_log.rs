@@ -0,0 +1,312 @@
+use std::borrow::Cow;
+
+use ratatui::{
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+};
+use serde_json::{Map, Value};
+
+use super::{LEVEL_KEYS, LogLevel};
+use crate::config::AppColors;
+
+/// Keys commonly used for the message of a structured log line
+const MESSAGE_KEYS: [&str; 4] = ["msg", "message", "@message", "event"];
+
+/// Keys commonly used for the logger, or source, of a structured log line
+const LOGGER_KEYS: [&str; 6] = [
+    "logger",
+    "logger_name",
+    "name",
+    "component",
+    "module",
+    "target",
+];
+
+/// Keys commonly used for the trace id of a structured log line
+const TRACE_KEYS: [&str; 5] = ["trace_id", "traceId", "trace.id", "traceid", "dd.trace_id"];
+
+/// Keys of timestamps, hidden as the docker timestamp is already shown
+const TIME_KEYS: [&str; 4] = ["time", "ts", "timestamp", "@timestamp"];
+
+/// A log line which is either a JSON object, or logfmt key value pairs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StructuredLog {
+    Json(Map<String, Value>),
+    Logfmt(Vec<(String, String)>),
+}
+
+impl StructuredLog {
+    /// Parse a log line, which should have already had any ansi formatting removed, returns None if it isn't structured
+    pub fn parse(input: &str) -> Option<Self> {
+        let trimmed = input.trim();
+        if trimmed.starts_with('{') {
+            match serde_json::from_str::<Value>(trimmed) {
+                Ok(Value::Object(map)) => Some(Self::Json(map)),
+                _ => None,
+            }
+        } else {
+            Self::parse_logfmt(trimmed).map(Self::Logfmt)
+        }
+    }
+
+    /// Parse logfmt `key=value` pairs, values can be quoted, with `\"` escaping a quote
+    /// Any word without a value, or an unterminated quote, means the line isn't logfmt, and at least two pairs are required, to avoid matching plain text
+    fn parse_logfmt(input: &str) -> Option<Vec<(String, String)>> {
+        let mut fields = vec![];
+        let mut chars = input.chars().peekable();
+        loop {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            if chars.peek().is_none() {
+                break;
+            }
+            let mut key = String::new();
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace() && *c != '=') {
+                key.push(c);
+            }
+            if key.is_empty() || chars.next() != Some('=') {
+                return None;
+            }
+            let mut value = String::new();
+            if chars.next_if_eq(&'"').is_some() {
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => value.push(chars.next()?),
+                        c => value.push(c),
+                    }
+                }
+            } else {
+                while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                    value.push(c);
+                }
+            }
+            fields.push((key, value));
+        }
+        (fields.len() >= 2).then_some(fields)
+    }
+
+    /// Every field, in order, JSON string values are unquoted, and any other JSON values are shown as compact JSON
+    fn fields(&self) -> Vec<(&str, Cow<'_, str>)> {
+        match self {
+            Self::Json(map) => map
+                .iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Value::String(s) => Cow::Borrowed(s.as_str()),
+                        _ => Cow::Owned(value.to_string()),
+                    };
+                    (key.as_str(), value)
+                })
+                .collect(),
+            Self::Logfmt(pairs) => pairs
+                .iter()
+                .map(|(key, value)| (key.as_str(), Cow::Borrowed(value.as_str())))
+                .collect(),
+        }
+    }
+
+    /// Create a single line, with the level, logger, message, and trace id, shown first, and then every other field as `key=value`
+    /// Only the key used for each of the first fields is hidden from the `key=value` section, so a second logger, or message, key is still shown
+    /// The message has no color of its own, so takes the color of the line
+    pub fn to_line(
+        &self,
+        prefix: Option<String>,
+        level: Option<LogLevel>,
+        colors: &AppColors,
+    ) -> Line<'static> {
+        let fields = self.fields();
+        let mut shown = vec![];
+        let mut find = |keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| fields.iter().find(|(k, _)| k == key))
+                .map(|(key, value)| {
+                    shown.push(*key);
+                    value.to_string()
+                })
+        };
+        let colored = |text: String, color: Color| {
+            let style = if color == Color::Reset {
+                Style::default()
+            } else {
+                Style::default().fg(color)
+            };
+            Span::styled(text, style)
+        };
+
+        let mut spans = vec![];
+        if let Some(prefix) = prefix {
+            spans.push(Span::raw(format!("{prefix} ")));
+        }
+        let level_field = find(&LEVEL_KEYS);
+        let level_text = level
+            .map(|i| i.to_string())
+            .or(level_field)
+            .map(|i| i.to_uppercase());
+        if let Some(level_text) = level_text {
+            let color = level.map_or(Color::Reset, |i| i.color(colors));
+            spans.push(colored(format!("{level_text:<5} "), color).add_modifier(Modifier::BOLD));
+        }
+        if let Some(logger) = find(&LOGGER_KEYS) {
+            spans.push(colored(format!("{logger} "), colors.logs.structured_logger));
+        }
+        if let Some(message) = find(&MESSAGE_KEYS) {
+            spans.push(Span::styled(
+                message,
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(trace_id) = find(&TRACE_KEYS) {
+            spans.push(colored(
+                format!(" trace={trace_id}"),
+                colors.logs.structured_trace_id,
+            ));
+        }
+
+        for (key, value) in fields
+            .iter()
+            .filter(|(key, _)| !shown.contains(key) && !TIME_KEYS.contains(key))
+        {
+            let value = if value.contains(char::is_whitespace) || value.is_empty() {
+                format!("{value:?}")
+            } else {
+                value.to_string()
+            };
+            spans.push(colored(
+                format!(" {key}={value}"),
+                colors.logs.structured_fields,
+            ));
+        }
+        Line::from(spans)
+    }
+
+    /// Pretty print, JSON is indented, and logfmt pairs are placed on separate lines with the keys aligned
+    pub fn pretty(&self) -> Vec<String> {
+        match self {
+            Self::Json(map) => serde_json::to_string_pretty(map)
+                .unwrap_or_default()
+                .lines()
+                .map(ToOwned::to_owned)
+                .collect(),
+            Self::Logfmt(pairs) => {
+                let width = pairs
+                    .iter()
+                    .map(|(key, _)| key.chars().count())
+                    .max()
+                    .unwrap_or_default();
+                pairs
+                    .iter()
+                    .map(|(key, value)| format!("{key:<width$} = {value}"))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// A single log line, expanded into a popup, pretty printed if structured
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogDetail {
+    pub lines: Vec<String>,
+    pub timestamp: String,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{app_data::LogLevel, config::AppColors};
+
+    use super::StructuredLog;
+
+    #[test]
+    /// JSON objects & logfmt pairs are parsed, anything else isn't structured
+    fn test_structured_log_parse() {
+        assert!(matches!(
+            StructuredLog::parse(r#" {"msg":"a","level":"info"} "#),
+            Some(StructuredLog::Json(_))
+        ));
+        assert_eq!(
+            StructuredLog::parse(r#"level=info msg="hello \"world\"" empty="""#),
+            Some(StructuredLog::Logfmt(vec![
+                ("level".to_owned(), "info".to_owned()),
+                ("msg".to_owned(), "hello \"world\"".to_owned()),
+                ("empty".to_owned(), String::new()),
+            ]))
+        );
+        for input in [
+            "[1, 2]",
+            "{not json",
+            "plain text line",
+            "GET /health status=200",
+            "a=1",
+            "a=1 msg=\"unterminated",
+        ] {
+            assert!(StructuredLog::parse(input).is_none(), "{input}");
+        }
+    }
+
+    #[test]
+    /// Level, logger, message, and trace id, are shown first, the timestamp is hidden, and other fields are shown as key=value
+    fn test_structured_log_to_line() {
+        let colors = AppColors::new();
+        let log = StructuredLog::parse(
+            r#"{"time":"x","level":"error","logger":"db","msg":"query failed","trace_id":"abc","retry":2,"user":"a b"}"#,
+        )
+        .unwrap();
+        let line = log.to_line(Some("12:00:00".to_owned()), Some(LogLevel::Error), &colors);
+        assert_eq!(
+            line.to_string(),
+            r#"12:00:00 ERROR db query failed trace=abc retry=2 user="a b""#
+        );
+        assert_eq!(line.spans[1].style.fg, Some(Color::Red));
+        assert!(line.spans[1].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(line.spans[2].style.fg, Some(colors.logs.structured_logger));
+        assert_eq!(line.spans[3].style.fg, None);
+        assert!(line.spans[3].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(
+            line.spans[4].style.fg,
+            Some(colors.logs.structured_trace_id)
+        );
+        assert_eq!(line.spans[5].style.fg, Some(colors.logs.structured_fields));
+
+        let log = StructuredLog::parse("ts=1 lvl=debug msg=started port=80").unwrap();
+        assert_eq!(
+            log.to_line(None, Some(LogLevel::Debug), &colors)
+                .to_string(),
+            "DEBUG started port=80"
+        );
+    }
+
+    #[test]
+    /// Only the keys actually used for the level, logger, message, and trace id, are hidden, other keys of the same kind are shown as key=value
+    fn test_structured_log_to_line_unused_keys() {
+        let log = StructuredLog::parse(
+            "level=info logger=http name=worker_1 msg=done message=other trace_id=abc traceId=def",
+        )
+        .unwrap();
+        assert_eq!(
+            log.to_line(None, Some(LogLevel::Info), &AppColors::new())
+                .to_string(),
+            "INFO  http done trace=abc name=worker_1 message=other traceId=def"
+        );
+    }
+
+    #[test]
+    /// JSON is indented, logfmt keys are aligned
+    fn test_structured_log_pretty() {
+        let log = StructuredLog::parse(r#"{"a":1,"b":{"c":true}}"#).unwrap();
+        assert_eq!(
+            log.pretty(),
+            [
+                "{",
+                "  \"a\": 1,",
+                "  \"b\": {",
+                "    \"c\": true",
+                "  }",
+                "}"
+            ]
+        );
+        let log = StructuredLog::parse("level=info message=\"a b\"").unwrap();
+        assert_eq!(log.pretty(), ["level   = info", "message = a b"]);
+    }
+}
@@ -6,6 +6,7 @@ use std::fmt;
 pub enum AppError {
     DockerCommand(DockerCommand),
     DockerExec,
+    DockerExport,
     DockerLogs,
     DockerConnect,
     IO(String),
@@ -20,6 +21,7 @@ impl fmt::Display for AppError {
         match self {
             Self::DockerCommand(s) => write!(f, "Unable to {s} container"),
             Self::DockerExec => write!(f, "Unable to exec into container"),
+            Self::DockerExport => write!(f, "Unable to export container"),
             Self::DockerLogs => write!(f, "Unable to save logs"),
             Self::DockerConnect => write!(f, "Unable to access docker daemon"),
             Self::IO(msg) => write!(f, "IO error with: {msg}"),
@@ -111,6 +111,19 @@ impl From<Option<ConfigColors>> for AppColors {
                 Self::map_color(ip.text.as_deref(), &mut app_colors.popup_info.text);
             }
 
+            // Recreate Popup
+            if let Some(rp) = config_colors.popup_recreate {
+                Self::map_color(
+                    rp.background.as_deref(),
+                    &mut app_colors.popup_recreate.background,
+                );
+                Self::map_color(rp.text.as_deref(), &mut app_colors.popup_recreate.text);
+                Self::map_color(
+                    rp.text_highlight.as_deref(),
+                    &mut app_colors.popup_recreate.text_highlight,
+                );
+            }
+
             // Delete Popup
             if let Some(dp) = config_colors.popup_delete {
                 Self::map_color(
@@ -148,6 +161,50 @@ impl From<Option<ConfigColors>> for AppColors {
                 Self::map_color(cm.points.as_deref(), &mut app_colors.chart_memory.points);
                 Self::map_color(cm.title.as_deref(), &mut app_colors.chart_memory.title);
                 Self::map_color(cm.y_axis.as_deref(), &mut app_colors.chart_memory.y_axis);
+                Self::map_color(cm.limit.as_deref(), &mut app_colors.chart_memory.limit);
+            }
+
+            // Chart network
+            if let Some(cn) = config_colors.chart_net {
+                Self::map_color(
+                    cn.background.as_deref(),
+                    &mut app_colors.chart_net.background,
+                );
+                Self::map_color(cn.border.as_deref(), &mut app_colors.chart_net.border);
+                Self::map_color(cn.max.as_deref(), &mut app_colors.chart_net.max);
+                Self::map_color(cn.rx.as_deref(), &mut app_colors.chart_net.rx);
+                Self::map_color(cn.title.as_deref(), &mut app_colors.chart_net.title);
+                Self::map_color(cn.tx.as_deref(), &mut app_colors.chart_net.tx);
+                Self::map_color(cn.y_axis.as_deref(), &mut app_colors.chart_net.y_axis);
+            }
+
+            // Chart block I/O
+            if let Some(ci) = config_colors.chart_io {
+                Self::map_color(
+                    ci.background.as_deref(),
+                    &mut app_colors.chart_io.background,
+                );
+                Self::map_color(ci.border.as_deref(), &mut app_colors.chart_io.border);
+                Self::map_color(ci.max.as_deref(), &mut app_colors.chart_io.max);
+                Self::map_color(ci.read.as_deref(), &mut app_colors.chart_io.read);
+                Self::map_color(ci.title.as_deref(), &mut app_colors.chart_io.title);
+                Self::map_color(ci.write.as_deref(), &mut app_colors.chart_io.write);
+                Self::map_color(ci.y_axis.as_deref(), &mut app_colors.chart_io.y_axis);
+            }
+
+            // Chart networks
+            if let Some(cn) = config_colors.chart_networks {
+                Self::map_color(
+                    cn.background.as_deref(),
+                    &mut app_colors.chart_networks.background,
+                );
+                Self::map_color(cn.border.as_deref(), &mut app_colors.chart_networks.border);
+                Self::map_color(
+                    cn.headings.as_deref(),
+                    &mut app_colors.chart_networks.headings,
+                );
+                Self::map_color(cn.text.as_deref(), &mut app_colors.chart_networks.text);
+                Self::map_color(cn.title.as_deref(), &mut app_colors.chart_networks.title);
             }
 
             // Chart ports
@@ -172,6 +229,31 @@ impl From<Option<ConfigColors>> for AppColors {
                 Self::map_color(c.text.as_deref(), &mut app_colors.containers.text);
                 Self::map_color(c.text_rx.as_deref(), &mut app_colors.containers.text_rx);
                 Self::map_color(c.text_tx.as_deref(), &mut app_colors.containers.text_tx);
+                Self::map_color(
+                    c.text_block_read.as_deref(),
+                    &mut app_colors.containers.text_block_read,
+                );
+                Self::map_color(
+                    c.text_block_write.as_deref(),
+                    &mut app_colors.containers.text_block_write,
+                );
+                Self::map_color(c.text_pids.as_deref(), &mut app_colors.containers.text_pids);
+                Self::map_color(
+                    c.text_pids_warning.as_deref(),
+                    &mut app_colors.containers.text_pids_warning,
+                );
+                Self::map_color(
+                    c.text_pids_critical.as_deref(),
+                    &mut app_colors.containers.text_pids_critical,
+                );
+                Self::map_color(
+                    c.memory_warning.as_deref(),
+                    &mut app_colors.containers.memory_warning,
+                );
+                Self::map_color(
+                    c.memory_critical.as_deref(),
+                    &mut app_colors.containers.memory_critical,
+                );
             }
 
             // Commands
@@ -184,6 +266,7 @@ impl From<Option<ConfigColors>> for AppColors {
                 Self::map_color(cc.restart.as_deref(), &mut app_colors.commands.restart);
                 Self::map_color(cc.stop.as_deref(), &mut app_colors.commands.stop);
                 Self::map_color(cc.delete.as_deref(), &mut app_colors.commands.start);
+                Self::map_color(cc.recreate.as_deref(), &mut app_colors.commands.recreate);
                 Self::map_color(cc.resume.as_deref(), &mut app_colors.commands.resume);
                 Self::map_color(cc.start.as_deref(), &mut app_colors.commands.start);
             }
@@ -192,6 +275,31 @@ impl From<Option<ConfigColors>> for AppColors {
             if let Some(cl) = config_colors.logs {
                 Self::map_color(cl.background.as_deref(), &mut app_colors.logs.background);
                 Self::map_color(cl.text.as_deref(), &mut app_colors.logs.text);
+                Self::map_color(
+                    cl.search_match_background.as_deref(),
+                    &mut app_colors.logs.search_match_background,
+                );
+                Self::map_color(
+                    cl.search_match_text.as_deref(),
+                    &mut app_colors.logs.search_match_text,
+                );
+                Self::map_color(cl.level_error.as_deref(), &mut app_colors.logs.level_error);
+                Self::map_color(cl.level_warn.as_deref(), &mut app_colors.logs.level_warn);
+                Self::map_color(cl.level_info.as_deref(), &mut app_colors.logs.level_info);
+                Self::map_color(cl.level_debug.as_deref(), &mut app_colors.logs.level_debug);
+                Self::map_color(cl.level_trace.as_deref(), &mut app_colors.logs.level_trace);
+                Self::map_color(
+                    cl.structured_logger.as_deref(),
+                    &mut app_colors.logs.structured_logger,
+                );
+                Self::map_color(
+                    cl.structured_trace_id.as_deref(),
+                    &mut app_colors.logs.structured_trace_id,
+                );
+                Self::map_color(
+                    cl.structured_fields.as_deref(),
+                    &mut app_colors.logs.structured_fields,
+                );
             }
 
             // Container State
@@ -232,31 +340,38 @@ optional_config_struct!(
     ConfigBackgroundTextHighlight, background, text, text_highlight;
     ConfigBorders, selected, unselected;
     ConfigChartCpu, background, border, order, title, max, points,y_axis;
-    ConfigChartMemory, background, border, title, max, points, y_axis;
+    ConfigChartMemory, background, border, title, max, points, y_axis, limit;
+    ConfigChartIo, background, border, title, max, read, write, y_axis;
+    ConfigChartNet, background, border, title, max, rx, tx, y_axis;
     ConfigChartPorts, background, border, title, headings, text;
-    ConfigCommands, background, pause, restart, stop, delete, resume, start;
-    ConfigContainers, background, icon, text, text_rx, text_tx;
+    ConfigChartNetworks, background, border, title, headings, text;
+    ConfigCommands, background, pause, restart, stop, delete, recreate, resume, start;
+    ConfigContainers, background, icon, text, text_rx, text_tx, text_block_read, text_block_write, text_pids, text_pids_warning, text_pids_critical, memory_warning, memory_critical;
     ConfigContainerState, background, dead, exited, paused, removing, restarting, running_healthy, running_unhealthy, unknown;
     ConfigFilter, background, text, selected_filter_background, selected_filter_text, highlight;
     ConfigHeadersBar, background, loading_spinner, text, text_selected;
-    ConfigLogs, background, text
+    ConfigLogs, background, text, search_match_background, search_match_text, level_error, level_warn, level_info, level_debug, level_trace, structured_logger, structured_trace_id, structured_fields
 );
 
 config_struct!(
     Borders, selected, unselected;
     ChartCpu, background, border, title, max, points, y_axis;
-    ChartMemory, background, border, title, max, points, y_axis;
+    ChartMemory, background, border, title, max, points, y_axis, limit;
+    ChartIo, background, border, title, max, read, write, y_axis;
+    ChartNet, background, border, title, max, rx, tx, y_axis;
     ChartPorts, background, border, title, headings, text;
-    Commands, background, pause, restart, stop, delete, resume, start;
-    Containers, background, icon, text, text_rx, text_tx;
+    ChartNetworks, background, border, title, headings, text;
+    Commands, background, pause, restart, stop, delete, recreate, resume, start;
+    Containers, background, icon, text, text_rx, text_tx, text_block_read, text_block_write, text_pids, text_pids_warning, text_pids_critical, memory_warning, memory_critical;
     ContainerState, dead, exited, paused, removing, restarting, running_healthy, running_unhealthy, unknown;
     Filter, background, text, selected_filter_background, selected_filter_text, highlight;
     HeadersBar, background, text_selected, loading_spinner, text;
-    Logs, background, text;
+    Logs, background, text, search_match_background, search_match_text, level_error, level_warn, level_info, level_debug, level_trace, structured_logger, structured_trace_id, structured_fields;
     PopupDelete, background, text, text_highlight;
     PopupError, background, text;
     PopupHelp, background, text, text_highlight;
-    PopupInfo, background, text
+    PopupInfo, background, text;
+    PopupRecreate, background, text, text_highlight
 );
 
 #[derive(Debug, serde::Deserialize, Clone, PartialEq, Eq)]
@@ -264,7 +379,10 @@ pub struct ConfigColors {
     borders: Option<ConfigBorders>,
     chart_cpu: Option<ConfigChartCpu>,
     chart_memory: Option<ConfigChartMemory>,
+    chart_io: Option<ConfigChartIo>,
+    chart_net: Option<ConfigChartNet>,
     chart_ports: Option<ConfigChartPorts>,
+    chart_networks: Option<ConfigChartNetworks>,
     commands: Option<ConfigCommands>,
     container_state: Option<ConfigContainerState>,
     containers: Option<ConfigContainers>,
@@ -275,6 +393,7 @@ pub struct ConfigColors {
     popup_error: Option<ConfigBackgroundText>,
     popup_help: Option<ConfigBackgroundTextHighlight>,
     popup_info: Option<ConfigBackgroundText>,
+    popup_recreate: Option<ConfigBackgroundTextHighlight>,
 }
 
 /// Default colours for the header bar
@@ -308,6 +427,7 @@ impl Commands {
             restart: Color::Magenta,
             stop: Color::Red,
             delete: Color::Gray,
+            recreate: Color::Cyan,
             resume: Color::Blue,
             start: Color::Green,
         }
@@ -338,6 +458,37 @@ impl ChartMemory {
             max: ORANGE,
             points: Color::Cyan,
             y_axis: Color::White,
+            limit: Color::Red,
+        }
+    }
+}
+
+/// Default colours for the block I/O chart
+impl ChartIo {
+    const fn new() -> Self {
+        Self {
+            background: Color::Reset,
+            border: Color::White,
+            title: Color::Green,
+            max: ORANGE,
+            read: Color::Blue,
+            write: Color::LightRed,
+            y_axis: Color::White,
+        }
+    }
+}
+
+/// Default colours for the network chart
+impl ChartNet {
+    const fn new() -> Self {
+        Self {
+            background: Color::Reset,
+            border: Color::White,
+            title: Color::Green,
+            max: ORANGE,
+            rx: Color::Rgb(255, 233, 193),
+            tx: Color::Rgb(205, 140, 140),
+            y_axis: Color::White,
         }
     }
 }
@@ -355,6 +506,19 @@ impl ChartPorts {
     }
 }
 
+/// Default colours for the network interfaces panel
+impl ChartNetworks {
+    const fn new() -> Self {
+        Self {
+            background: Color::Reset,
+            border: Color::White,
+            title: Color::Green,
+            headings: Color::Yellow,
+            text: Color::White,
+        }
+    }
+}
+
 /// Default colours for the help popup
 impl Containers {
     const fn new() -> Self {
@@ -364,6 +528,13 @@ impl Containers {
             text: Color::Blue,
             text_rx: Color::Rgb(255, 233, 193),
             text_tx: Color::Rgb(205, 140, 140),
+            text_block_read: Color::Rgb(193, 220, 255),
+            text_block_write: Color::Rgb(205, 180, 219),
+            text_pids: Color::Rgb(206, 231, 176),
+            text_pids_warning: ORANGE,
+            text_pids_critical: Color::Red,
+            memory_warning: ORANGE,
+            memory_critical: Color::Red,
         }
     }
 }
@@ -397,11 +568,22 @@ impl Filter {
     }
 }
 
-/// Default colours for the logs panel, only applied if color_logs is false
+/// Default colours for the logs panel, only applied if color_logs is false, search matches are always highlighted
+/// Info lines are left in the text color, as they are usually the majority of lines
 impl Logs {
     const fn new() -> Self {
         Self {
             background: Color::Reset,
+            level_debug: Color::Blue,
+            level_error: Color::Red,
+            level_info: Color::Reset,
+            level_trace: Color::DarkGray,
+            level_warn: Color::Yellow,
+            search_match_background: Color::Yellow,
+            search_match_text: Color::Black,
+            structured_fields: Color::DarkGray,
+            structured_logger: Color::Cyan,
+            structured_trace_id: Color::Magenta,
             text: Color::Reset,
         }
     }
@@ -449,12 +631,26 @@ impl PopupDelete {
     }
 }
 
+/// Default colours for the recreate popup
+impl PopupRecreate {
+    const fn new() -> Self {
+        Self {
+            background: Color::Cyan,
+            text: Color::Black,
+            text_highlight: Color::White,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub struct AppColors {
     pub borders: Borders,
     pub chart_cpu: ChartCpu,
     pub chart_memory: ChartMemory,
+    pub chart_io: ChartIo,
+    pub chart_net: ChartNet,
     pub chart_ports: ChartPorts,
+    pub chart_networks: ChartNetworks,
     pub commands: Commands,
     pub container_state: ContainerState,
     pub containers: Containers,
@@ -465,6 +661,7 @@ pub struct AppColors {
     pub popup_error: PopupError,
     pub popup_help: PopupHelp,
     pub popup_info: PopupInfo,
+    pub popup_recreate: PopupRecreate,
 }
 
 impl AppColors {
@@ -473,7 +670,10 @@ impl AppColors {
             borders: Borders::new(),
             chart_cpu: ChartCpu::new(),
             chart_memory: ChartMemory::new(),
+            chart_io: ChartIo::new(),
+            chart_net: ChartNet::new(),
             chart_ports: ChartPorts::new(),
+            chart_networks: ChartNetworks::new(),
             commands: Commands::new(),
             container_state: ContainerState::new(),
             containers: Containers::new(),
@@ -484,6 +684,7 @@ impl AppColors {
             popup_error: PopupError::new(),
             popup_help: PopupHelp::new(),
             popup_info: PopupInfo::new(),
+            popup_recreate: PopupRecreate::new(),
         }
     }
 }
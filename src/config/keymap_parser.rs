@@ -38,6 +38,7 @@ optional_config_struct!(
     delete_deny,
     delete_confirm,
     exec,
+    export,
     filter_mode,
     log_section_height_increase,
     log_section_height_decrease,
@@ -61,9 +62,29 @@ optional_config_struct!(
     sort_by_image,
     sort_by_rx,
     sort_by_tx,
+    sort_by_block_read,
+    sort_by_block_write,
+    sort_by_pids,
     sort_reset,
     toggle_help,
-    toggle_mouse_capture
+    toggle_mouse_capture,
+    toggle_net_total,
+    toggle_cpu_detail,
+    cycle_cpu_mode,
+    toggle_mem_detail,
+    toggle_mem_percent,
+    chart_zoom_out,
+    chart_zoom_in,
+    toggle_chart_cursor,
+    toggle_chart_overview,
+    toggle_record,
+    toggle_merged_logs,
+    toggle_mark,
+    log_search,
+    log_search_next,
+    log_search_previous,
+    log_filter,
+    log_level
 );
 
 config_struct!(
@@ -72,6 +93,7 @@ config_struct!(
     delete_deny,
     delete_confirm,
     exec,
+    export,
     filter_mode,
     log_section_height_increase,
     log_section_height_decrease,
@@ -95,9 +117,29 @@ config_struct!(
     sort_by_image,
     sort_by_rx,
     sort_by_tx,
+    sort_by_block_read,
+    sort_by_block_write,
+    sort_by_pids,
     sort_reset,
     toggle_help,
-    toggle_mouse_capture
+    toggle_mouse_capture,
+    toggle_net_total,
+    toggle_cpu_detail,
+    cycle_cpu_mode,
+    toggle_mem_detail,
+    toggle_mem_percent,
+    chart_zoom_out,
+    chart_zoom_in,
+    toggle_chart_cursor,
+    toggle_chart_overview,
+    toggle_record,
+    toggle_merged_logs,
+    toggle_mark,
+    log_search,
+    log_search_next,
+    log_search_previous,
+    log_filter,
+    log_level
 );
 
 impl Keymap {
@@ -107,6 +149,7 @@ impl Keymap {
             delete_confirm: (KeyCode::Char('y'), None),
             delete_deny: (KeyCode::Char('n'), None),
             exec: (KeyCode::Char('e'), None),
+            export: (KeyCode::Char('x'), None),
             filter_mode: (KeyCode::Char('/'), Some(KeyCode::F(1))),
             log_section_height_decrease: (KeyCode::Char('-'), None),
             log_section_height_increase: (KeyCode::Char('='), None),
@@ -130,15 +173,36 @@ impl Keymap {
             sort_by_state: (KeyCode::Char('2'), None),
             sort_by_status: (KeyCode::Char('3'), None),
             sort_by_tx: (KeyCode::Char('9'), None),
+            sort_by_block_read: (KeyCode::Char('r'), None),
+            sort_by_block_write: (KeyCode::Char('w'), None),
+            sort_by_pids: (KeyCode::Char('p'), None),
             sort_reset: (KeyCode::Char('0'), None),
             toggle_help: (KeyCode::Char('h'), None),
             toggle_mouse_capture: (KeyCode::Char('m'), None),
+            toggle_net_total: (KeyCode::Char('t'), None),
+            toggle_cpu_detail: (KeyCode::Char('u'), None),
+            cycle_cpu_mode: (KeyCode::Char('o'), None),
+            toggle_mem_detail: (KeyCode::Char('v'), None),
+            toggle_mem_percent: (KeyCode::Char('l'), None),
+            chart_zoom_out: (KeyCode::Char('['), None),
+            chart_zoom_in: (KeyCode::Char(']'), None),
+            toggle_chart_cursor: (KeyCode::Char('i'), None),
+            toggle_chart_overview: (KeyCode::Char('a'), None),
+            toggle_record: (KeyCode::Char('z'), None),
+            toggle_merged_logs: (KeyCode::Char('g'), None),
+            toggle_mark: (KeyCode::Char('M'), None),
+            log_search: (KeyCode::Char('f'), None),
+            log_search_next: (KeyCode::Char('.'), None),
+            log_search_previous: (KeyCode::Char(','), None),
+            log_filter: (KeyCode::Char('b'), None),
+            log_level: (KeyCode::Char('d'), None),
         }
     }
 }
 
 impl From<Option<ConfigKeymap>> for Keymap {
     /// Probably a better way to do this, but for now it works
+    #[allow(clippy::too_many_lines)]
     fn from(value: Option<ConfigKeymap>) -> Self {
         let mut keymap = Self::new();
 
@@ -149,20 +213,20 @@ impl From<Option<ConfigKeymap>> for Keymap {
             |vec_str: Option<Vec<String>>,
              keymap_field: &mut (KeyCode, Option<KeyCode>),
              keymap_clash: &mut HashSet<KeyCode>| {
-                if let Some(vec_str) = vec_str {
-                    if let Some(vec_keycode) = Self::try_parse_keycode(&vec_str) {
-                        if let Some(first) = vec_keycode.first() {
-                            keymap_clash.insert(*first);
-                            counter += 1;
-                            keymap_field.0 = *first;
-                        }
-                        if let Some(second) = vec_keycode.get(1) {
-                            keymap_clash.insert(*second);
-                            counter += 1;
-                            keymap_field.1 = Some(*second);
-                        } else {
-                            keymap_field.1 = None;
-                        }
+                if let Some(vec_str) = vec_str
+                    && let Some(vec_keycode) = Self::try_parse_keycode(&vec_str)
+                {
+                    if let Some(first) = vec_keycode.first() {
+                        keymap_clash.insert(*first);
+                        counter += 1;
+                        keymap_field.0 = *first;
+                    }
+                    if let Some(second) = vec_keycode.get(1) {
+                        keymap_clash.insert(*second);
+                        counter += 1;
+                        keymap_field.1 = Some(*second);
+                    } else {
+                        keymap_field.1 = None;
                     }
                 }
             };
@@ -188,6 +252,7 @@ impl From<Option<ConfigKeymap>> for Keymap {
             );
 
             update_keymap(ck.exec, &mut keymap.exec, &mut clash);
+            update_keymap(ck.export, &mut keymap.export, &mut clash);
             update_keymap(ck.filter_mode, &mut keymap.filter_mode, &mut clash);
             update_keymap(ck.quit, &mut keymap.quit, &mut clash);
             update_keymap(ck.save_logs, &mut keymap.save_logs, &mut clash);
@@ -220,6 +285,17 @@ impl From<Option<ConfigKeymap>> for Keymap {
             update_keymap(ck.sort_by_image, &mut keymap.sort_by_image, &mut clash);
             update_keymap(ck.sort_by_rx, &mut keymap.sort_by_rx, &mut clash);
             update_keymap(ck.sort_by_tx, &mut keymap.sort_by_tx, &mut clash);
+            update_keymap(
+                ck.sort_by_block_read,
+                &mut keymap.sort_by_block_read,
+                &mut clash,
+            );
+            update_keymap(
+                ck.sort_by_block_write,
+                &mut keymap.sort_by_block_write,
+                &mut clash,
+            );
+            update_keymap(ck.sort_by_pids, &mut keymap.sort_by_pids, &mut clash);
             update_keymap(ck.sort_reset, &mut keymap.sort_reset, &mut clash);
             update_keymap(ck.toggle_help, &mut keymap.toggle_help, &mut clash);
             update_keymap(
@@ -227,6 +303,55 @@ impl From<Option<ConfigKeymap>> for Keymap {
                 &mut keymap.toggle_mouse_capture,
                 &mut clash,
             );
+            update_keymap(
+                ck.toggle_net_total,
+                &mut keymap.toggle_net_total,
+                &mut clash,
+            );
+            update_keymap(
+                ck.toggle_cpu_detail,
+                &mut keymap.toggle_cpu_detail,
+                &mut clash,
+            );
+            update_keymap(ck.cycle_cpu_mode, &mut keymap.cycle_cpu_mode, &mut clash);
+            update_keymap(
+                ck.toggle_mem_detail,
+                &mut keymap.toggle_mem_detail,
+                &mut clash,
+            );
+            update_keymap(
+                ck.toggle_mem_percent,
+                &mut keymap.toggle_mem_percent,
+                &mut clash,
+            );
+            update_keymap(ck.chart_zoom_out, &mut keymap.chart_zoom_out, &mut clash);
+            update_keymap(ck.chart_zoom_in, &mut keymap.chart_zoom_in, &mut clash);
+            update_keymap(
+                ck.toggle_chart_cursor,
+                &mut keymap.toggle_chart_cursor,
+                &mut clash,
+            );
+            update_keymap(
+                ck.toggle_chart_overview,
+                &mut keymap.toggle_chart_overview,
+                &mut clash,
+            );
+            update_keymap(ck.toggle_record, &mut keymap.toggle_record, &mut clash);
+            update_keymap(
+                ck.toggle_merged_logs,
+                &mut keymap.toggle_merged_logs,
+                &mut clash,
+            );
+            update_keymap(ck.toggle_mark, &mut keymap.toggle_mark, &mut clash);
+            update_keymap(ck.log_search, &mut keymap.log_search, &mut clash);
+            update_keymap(ck.log_search_next, &mut keymap.log_search_next, &mut clash);
+            update_keymap(
+                ck.log_search_previous,
+                &mut keymap.log_search_previous,
+                &mut clash,
+            );
+            update_keymap(ck.log_filter, &mut keymap.log_filter, &mut clash);
+            update_keymap(ck.log_level, &mut keymap.log_level, &mut clash);
         }
         // A very basic clash check, every key has been inserted into a hashset, and a counter has been increased
         // if the counter and hashet length don't match, then there's a clash, and we just return the default keymap
@@ -247,16 +372,16 @@ impl Keymap {
 
         for key in input.iter().take(2) {
             if key.chars().count() == 1 {
-                if let Some(first_char) = key.chars().next() {
-                    if let Some(first_char) = match first_char {
+                if let Some(first_char) = key.chars().next()
+                    && let Some(first_char) = match first_char {
                         x if x.is_ascii_alphabetic() || x.is_ascii_digit() => Some(first_char),
                         '/' | '\\' | ',' | '.' | '#' | '\'' | '[' | ']' | ';' | '=' | '-' => {
                             Some(first_char)
                         }
                         _ => None,
-                    } {
-                        output.push(KeyCode::Char(first_char));
                     }
+                {
+                    output.push(KeyCode::Char(first_char));
                 }
             } else {
                 let keycode = match key.to_lowercase().as_str() {
@@ -364,6 +489,7 @@ mod tests {
             delete_deny: Some(vec!["s".to_owned()]),
             delete_confirm: None,
             exec: None,
+            export: None,
             log_section_height_decrease: None,
             log_section_height_increase: None,
             filter_mode: None,
@@ -387,9 +513,29 @@ mod tests {
             sort_by_image: None,
             sort_by_rx: None,
             sort_by_tx: None,
+            sort_by_block_read: None,
+            sort_by_block_write: None,
+            sort_by_pids: None,
             sort_reset: None,
             toggle_help: None,
             toggle_mouse_capture: None,
+            toggle_net_total: None,
+            toggle_cpu_detail: None,
+            cycle_cpu_mode: None,
+            toggle_mem_detail: None,
+            toggle_mem_percent: None,
+            chart_zoom_out: None,
+            chart_zoom_in: None,
+            toggle_chart_cursor: None,
+            toggle_chart_overview: None,
+            toggle_record: None,
+            toggle_merged_logs: None,
+            toggle_mark: None,
+            log_search: None,
+            log_search_next: None,
+            log_search_previous: None,
+            log_filter: None,
+            log_level: None,
         };
 
         let result = Keymap::from(Some(input));
@@ -399,6 +545,7 @@ mod tests {
 
     #[test]
     /// Custom keymap definition creation
+    #[allow(clippy::too_many_lines)]
     fn test_valid_custom_keymap() {
         let gen_v = |a: (&str, &str)| Some(vec![a.0.to_owned(), a.1.to_owned()]);
 
@@ -407,6 +554,7 @@ mod tests {
             delete_confirm: gen_v(("e", "f")),
             delete_deny: gen_v(("c", "d")),
             exec: gen_v(("g", "h")),
+            export: gen_v(("E", "G")),
             filter_mode: gen_v(("i", "j")),
             log_section_height_decrease: gen_v(("-", "Z")),
             log_section_height_increase: gen_v(("=", "X")),
@@ -430,9 +578,29 @@ mod tests {
             sort_by_state: gen_v(("6", "7")),
             sort_by_status: gen_v(("8", "9")),
             sort_by_tx: gen_v(("insert", "TAB")),
+            sort_by_block_read: gen_v(("H", "I")),
+            sort_by_block_write: gen_v(("J", "K")),
+            sort_by_pids: gen_v(("N", "O")),
             sort_reset: gen_v(("up", "down")),
             toggle_help: gen_v(("home", "end")),
             toggle_mouse_capture: gen_v(("pagedown", "PAGEUP")),
+            toggle_net_total: gen_v(("L", "M")),
+            toggle_cpu_detail: gen_v(("P", "Q")),
+            cycle_cpu_mode: gen_v(("R", "S")),
+            toggle_mem_detail: gen_v(("T", "U")),
+            toggle_mem_percent: gen_v(("V", "F2")),
+            chart_zoom_out: gen_v(("F3", "F4")),
+            chart_zoom_in: gen_v(("F5", "F6")),
+            toggle_chart_cursor: gen_v(("F7", "F8")),
+            toggle_chart_overview: gen_v(("F9", "F10")),
+            toggle_record: gen_v(("F11", "F")),
+            toggle_merged_logs: gen_v((",", ".")),
+            toggle_mark: None,
+            log_search: gen_v((";", "#")),
+            log_search_next: gen_v(("'", "right")),
+            log_search_previous: gen_v(("delete", "left")),
+            log_filter: gen_v(("backspace", "backtab")),
+            log_level: Some(vec!["esc".to_owned()]),
         };
 
         let result = Keymap::from(Some(input));
@@ -445,6 +613,7 @@ mod tests {
             log_section_height_increase: (KeyCode::Char('='), Some(KeyCode::Char('X'))),
             log_section_toggle: (KeyCode::Char('Y'), Some(KeyCode::Char('W'))),
             exec: (KeyCode::Char('g'), Some(KeyCode::Char('h'))),
+            export: (KeyCode::Char('E'), Some(KeyCode::Char('G'))),
             filter_mode: (KeyCode::Char('i'), Some(KeyCode::Char('j'))),
             quit: (KeyCode::Char('k'), Some(KeyCode::Char('l'))),
             save_logs: (KeyCode::Char('m'), Some(KeyCode::Char('n'))),
@@ -465,9 +634,29 @@ mod tests {
             sort_by_image: (KeyCode::Char('A'), Some(KeyCode::Char('B'))),
             sort_by_rx: (KeyCode::Char('C'), Some(KeyCode::Char('D'))),
             sort_by_tx: (KeyCode::Insert, Some(KeyCode::Tab)),
+            sort_by_block_read: (KeyCode::Char('H'), Some(KeyCode::Char('I'))),
+            sort_by_block_write: (KeyCode::Char('J'), Some(KeyCode::Char('K'))),
+            sort_by_pids: (KeyCode::Char('N'), Some(KeyCode::Char('O'))),
             sort_reset: (KeyCode::Up, Some(KeyCode::Down)),
             toggle_help: (KeyCode::Home, Some(KeyCode::End)),
             toggle_mouse_capture: (KeyCode::PageDown, Some(KeyCode::PageUp)),
+            toggle_net_total: (KeyCode::Char('L'), Some(KeyCode::Char('M'))),
+            toggle_cpu_detail: (KeyCode::Char('P'), Some(KeyCode::Char('Q'))),
+            cycle_cpu_mode: (KeyCode::Char('R'), Some(KeyCode::Char('S'))),
+            toggle_mem_detail: (KeyCode::Char('T'), Some(KeyCode::Char('U'))),
+            toggle_mem_percent: (KeyCode::Char('V'), Some(KeyCode::F(2))),
+            chart_zoom_out: (KeyCode::F(3), Some(KeyCode::F(4))),
+            chart_zoom_in: (KeyCode::F(5), Some(KeyCode::F(6))),
+            toggle_chart_cursor: (KeyCode::F(7), Some(KeyCode::F(8))),
+            toggle_chart_overview: (KeyCode::F(9), Some(KeyCode::F(10))),
+            toggle_record: (KeyCode::F(11), Some(KeyCode::Char('F'))),
+            toggle_merged_logs: (KeyCode::Char(','), Some(KeyCode::Char('.'))),
+            toggle_mark: (KeyCode::Char('M'), None),
+            log_search: (KeyCode::Char(';'), Some(KeyCode::Char('#'))),
+            log_search_next: (KeyCode::Char('\''), Some(KeyCode::Right)),
+            log_search_previous: (KeyCode::Delete, Some(KeyCode::Left)),
+            log_filter: (KeyCode::Backspace, Some(KeyCode::BackTab)),
+            log_level: (KeyCode::Esc, None),
         };
 
         assert_eq!(expected, result);
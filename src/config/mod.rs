@@ -1,29 +1,227 @@
-use std::path::PathBuf;
+use std::{net::SocketAddr, path::PathBuf};
 
 use clap::Parser;
 use jiff::tz::TimeZone;
-use parse_args::Args;
+use parse_args::{Args, Command};
 use parse_config_file::ConfigFile;
 mod color_parser;
 mod keymap_parser;
 
-use crate::{ENV_KEY, ENV_VALUE};
+use crate::{ENV_KEY, ENV_VALUE, app_data::DockerCommand};
 pub use {color_parser::AppColors, keymap_parser::Keymap};
 
 mod parse_args;
 mod parse_config_file;
 
+/// How the cpu usage of a container is displayed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CpuMode {
+    /// Percentage of a single core, so a container using every core of a 32 core host will show 3200%
+    #[default]
+    PerCore,
+    /// Percentage of the total cpu capacity of the host
+    Host,
+    /// Percentage of the containers own cpu limit, if no limit is set, this is the same as Host
+    Quota,
+}
+
+/// How the memory usage of a container is calculated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MemoryFormula {
+    /// Total usage minus the inactive file cache, the same as the docker cli
+    #[default]
+    Docker,
+    /// Total usage, including all file cache
+    Usage,
+    /// Anonymous memory only
+    Rss,
+    /// Anonymous memory plus the active file cache
+    WorkingSet,
+}
+
+impl MemoryFormula {
+    /// Parse the config file value, default to Docker if missing or invalid
+    fn from_config(input: Option<&str>) -> Self {
+        match input.map(str::to_lowercase).as_deref() {
+            Some("usage") => Self::Usage,
+            Some("rss") => Self::Rss,
+            Some("working_set") => Self::WorkingSet,
+            _ => Self::Docker,
+        }
+    }
+
+    /// Short description of the formula, shown in the memory detail popup
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Docker => "usage - inactive file",
+            Self::Usage => "usage",
+            Self::Rss => "anon",
+            Self::WorkingSet => "anon + active file",
+        }
+    }
+}
+
+/// Format of the container snapshots printed when the gui is disabled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Aligned, human readable, columns
+    #[default]
+    Table,
+    /// A JSON array of every container, on a single line
+    Json,
+    /// Comma separated values, with a header row
+    Csv,
+}
+
+impl OutputFormat {
+    /// Parse the cli value, default to Table if missing or invalid
+    fn from_config(input: Option<&str>) -> Self {
+        match input.map(str::to_lowercase).as_deref() {
+            Some("json") => Self::Json,
+            Some("csv") => Self::Csv,
+            _ => Self::Table,
+        }
+    }
+}
+
+/// A docker command, run from the cli rather than the gui, against every container matched by the targets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControlCommand {
+    pub command: DockerCommand,
+    pub remove_volumes: bool,
+    pub targets: Vec<String>,
+}
+
+impl ControlCommand {
+    /// Every subcommand, other than logs, is a docker command
+    fn from_command(value: &Command) -> Option<Self> {
+        let (command, targets, remove_volumes) = match value {
+            Command::Delete { targets, volumes } => (DockerCommand::Delete, targets, *volumes),
+            Command::Pause(targets) => (DockerCommand::Pause, targets, false),
+            Command::Recreate(targets) => (DockerCommand::Recreate, targets, false),
+            Command::Restart(targets) => (DockerCommand::Restart, targets, false),
+            Command::Resume(targets) => (DockerCommand::Resume, targets, false),
+            Command::Start(targets) => (DockerCommand::Start, targets, false),
+            Command::Stop(targets) => (DockerCommand::Stop, targets, false),
+            Command::Logs { .. } => return None,
+        };
+        Some(Self {
+            command,
+            remove_volumes,
+            targets: targets.containers.clone(),
+        })
+    }
+}
+
+/// Follow the logs of every container matched by the targets, run from the cli rather than the gui
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogsCommand {
+    pub grep: Option<String>,
+    /// Unix seconds
+    pub since: Option<i64>,
+    pub tail: Option<u64>,
+    pub targets: Vec<String>,
+}
+
+impl LogsCommand {
+    fn from_command(value: &Command) -> Option<Self> {
+        if let Command::Logs {
+            targets,
+            since,
+            tail,
+            grep,
+        } = value
+        {
+            Some(Self {
+                grep: grep.clone(),
+                since: *since,
+                tail: *tail,
+                targets: targets.containers.clone(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Which stat history is drawn in the sparkline column of the containers panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparklineMetric {
+    Cpu,
+    Memory,
+}
+
+impl SparklineMetric {
+    /// Parse the config file value, the sparkline column is disabled if missing or invalid
+    fn from_config(input: Option<&str>) -> Option<Self> {
+        match input.map(str::to_lowercase).as_deref() {
+            Some("cpu") => Some(Self::Cpu),
+            Some("memory") => Some(Self::Memory),
+            _ => None,
+        }
+    }
+
+    /// Appended to the sparkline header, so that it's clear which stat is shown
+    pub const fn header_suffix(self) -> &'static str {
+        match self {
+            Self::Cpu => " cpu",
+            Self::Memory => " mem",
+        }
+    }
+}
+
+impl CpuMode {
+    /// Parse the config file value, default to PerCore if missing or invalid
+    fn from_config(input: Option<&str>) -> Self {
+        match input.map(str::to_lowercase).as_deref() {
+            Some("host") => Self::Host,
+            Some("quota") => Self::Quota,
+            _ => Self::PerCore,
+        }
+    }
+
+    /// Cycle through each mode, used by the cycle_cpu_mode key
+    pub const fn next(self) -> Self {
+        match self {
+            Self::PerCore => Self::Host,
+            Self::Host => Self::Quota,
+            Self::Quota => Self::PerCore,
+        }
+    }
+
+    /// Appended to the cpu header, so that it's clear which mode is active
+    pub const fn header_suffix(self) -> &'static str {
+        match self {
+            Self::PerCore => "",
+            Self::Host => " host",
+            Self::Quota => " quota",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Config {
     pub app_colors: AppColors,
+    pub chart_overview_count: u8,
     pub color_logs: bool,
+    pub control: Option<ControlCommand>,
+    pub cpu_mode: CpuMode,
     pub docker_interval_ms: u32,
     pub gui: bool,
     pub host: Option<String>,
     pub in_container: bool,
     pub keymap: Keymap,
+    pub logs: Option<LogsCommand>,
+    pub memory_critical_percentage: u8,
+    pub memory_formula: MemoryFormula,
+    pub memory_warning_percentage: u8,
+    pub metrics_listen: Option<SocketAddr>,
+    pub once: bool,
+    pub output_format: OutputFormat,
     pub raw_logs: bool,
+    pub record_max_mb: u32,
+    pub record_path: Option<PathBuf>,
     pub save_dir: Option<PathBuf>,
     pub show_self: bool,
     pub show_std_err: bool,
@@ -31,6 +229,13 @@ pub struct Config {
     pub timezone: Option<TimeZone>,
     pub timestamp_format: String,
     pub show_logs: bool,
+    pub show_io_chart: bool,
+    pub show_net_chart: bool,
+    pub show_host_summary: bool,
+    pub sparkline: Option<SparklineMetric>,
+    pub sparkline_width: u8,
+    pub stats_history_seconds: u32,
+    pub structured_logs: bool,
     pub use_cli: bool,
 }
 
@@ -38,13 +243,25 @@ impl From<&Args> for Config {
     fn from(args: &Args) -> Self {
         Self {
             app_colors: AppColors::new(),
+            chart_overview_count: 5,
             color_logs: args.color,
+            control: args.command.as_ref().and_then(ControlCommand::from_command),
+            cpu_mode: CpuMode::default(),
             docker_interval_ms: args.docker_interval,
-            gui: !args.gui,
+            gui: !args.gui && !args.once,
             host: args.host.clone(),
             in_container: Self::check_if_in_container(),
             keymap: Keymap::new(),
+            logs: args.command.as_ref().and_then(LogsCommand::from_command),
+            memory_critical_percentage: 95,
+            memory_formula: MemoryFormula::default(),
+            memory_warning_percentage: 80,
+            metrics_listen: args.metrics_listen,
+            once: args.once,
+            output_format: OutputFormat::from_config(Some(&args.format)),
             raw_logs: args.raw,
+            record_max_mb: 100,
+            record_path: args.record.as_ref().map(PathBuf::from),
             save_dir: Self::try_get_logs_dir(args.save_dir.as_ref()),
             show_self: !args.show_self,
             show_std_err: !args.no_std_err,
@@ -53,21 +270,48 @@ impl From<&Args> for Config {
             timestamp_format: Self::parse_timestamp_format(None),
             use_cli: args.use_cli,
             show_logs: true,
+            show_io_chart: false,
+            show_net_chart: false,
+            show_host_summary: false,
+            sparkline: None,
+            sparkline_width: 10,
+            stats_history_seconds: 60,
+            structured_logs: args.structured,
         }
     }
 }
 
 impl From<ConfigFile> for Config {
     fn from(config_file: ConfigFile) -> Self {
+        let (memory_warning, memory_critical) = Self::parse_memory_thresholds(
+            config_file.memory_warning_percentage,
+            config_file.memory_critical_percentage,
+        );
         Self {
             app_colors: AppColors::from(config_file.colors),
+            chart_overview_count: Self::parse_chart_overview_count(
+                config_file.chart_overview_count,
+            ),
             color_logs: config_file.color_logs.unwrap_or(false),
+            control: None,
+            cpu_mode: CpuMode::from_config(config_file.cpu_mode.as_deref()),
             docker_interval_ms: config_file.docker_interval.unwrap_or(1000),
             gui: config_file.gui.unwrap_or(true),
             host: config_file.host,
             in_container: Self::check_if_in_container(),
             keymap: Keymap::from(config_file.keymap),
+            logs: None,
+            memory_critical_percentage: memory_critical,
+            memory_formula: MemoryFormula::from_config(config_file.memory_formula.as_deref()),
+            memory_warning_percentage: memory_warning,
+            metrics_listen: config_file
+                .metrics_listen
+                .and_then(|i| i.parse::<SocketAddr>().ok()),
+            once: false,
+            output_format: OutputFormat::default(),
             raw_logs: config_file.raw_logs.unwrap_or(false),
+            record_max_mb: Self::parse_record_max_mb(config_file.record_max_mb),
+            record_path: config_file.record_path.map(PathBuf::from),
             save_dir: Self::try_get_logs_dir(config_file.save_dir.as_ref()),
             show_self: config_file.show_self.unwrap_or(false),
             show_std_err: config_file.show_std_err.unwrap_or(true),
@@ -76,6 +320,13 @@ impl From<ConfigFile> for Config {
             timestamp_format: Self::parse_timestamp_format(config_file.timestamp_format),
             use_cli: config_file.use_cli.unwrap_or(false),
             show_logs: config_file.show_logs.unwrap_or(true),
+            show_io_chart: config_file.show_io_chart.unwrap_or(false),
+            show_net_chart: config_file.show_net_chart.unwrap_or(false),
+            show_host_summary: config_file.show_host_summary.unwrap_or(false),
+            sparkline: SparklineMetric::from_config(config_file.sparkline.as_deref()),
+            sparkline_width: Self::parse_sparkline_width(config_file.sparkline_width),
+            stats_history_seconds: Self::parse_stats_history(config_file.stats_history_seconds),
+            structured_logs: config_file.structured_logs.unwrap_or(false),
         }
     }
 }
@@ -95,6 +346,35 @@ impl Config {
         })
     }
 
+    /// Memory warning & critical thresholds, as a percentage of the memory limit, default to 80 & 95
+    /// Each must be between 1 and 100, and the warning threshold can't be greater than the critical threshold
+    fn parse_memory_thresholds(warning: Option<u8>, critical: Option<u8>) -> (u8, u8) {
+        let valid =
+            |x: Option<u8>, default: u8| x.filter(|i| (1..=100).contains(i)).unwrap_or(default);
+        let critical = valid(critical, 95);
+        (valid(warning, 80).min(critical), critical)
+    }
+
+    /// Length of the cpu & memory history, in seconds, between 10 seconds and 24 hours, default to 60
+    fn parse_stats_history(input: Option<u32>) -> u32 {
+        input.map_or(60, |i| i.clamp(10, 86_400))
+    }
+
+    /// Number of containers plotted on the overview chart, between 1 and 8, default to 5
+    fn parse_chart_overview_count(input: Option<u8>) -> u8 {
+        input.map_or(5, |i| i.clamp(1, 8))
+    }
+
+    /// Number of samples drawn in the sparkline column, between 5 and 60, default to 10
+    fn parse_sparkline_width(input: Option<u8>) -> u8 {
+        input.map_or(10, |i| i.clamp(5, 60))
+    }
+
+    /// Size, in MB, at which the stats recording file is rotated, between 1 and 10,000, default to 100
+    fn parse_record_max_mb(input: Option<u32>) -> u32 {
+        input.map_or(100, |i| i.clamp(1, 10_000))
+    }
+
     /// Attempt to parse a timezone into a jiff::tz::TimeZone
     /// Also return a format to display the timesampt in
     fn parse_timezone(input: Option<String>) -> Option<TimeZone> {
@@ -147,6 +427,14 @@ impl Config {
             self.gui = config_from_cli.gui;
         }
 
+        if config_from_cli.once != default_args.once {
+            self.once = config_from_cli.once;
+        }
+
+        if config_from_cli.output_format != OutputFormat::from_config(Some(&default_args.format)) {
+            self.output_format = config_from_cli.output_format;
+        }
+
         if config_from_cli.docker_interval_ms != default_args.docker_interval {
             self.docker_interval_ms = config_from_cli.docker_interval_ms;
         }
@@ -171,6 +459,10 @@ impl Config {
             self.show_timestamp = config_from_cli.show_timestamp;
         }
 
+        if config_from_cli.structured_logs != default_args.structured {
+            self.structured_logs = config_from_cli.structured_logs;
+        }
+
         if config_from_cli.use_cli != default_args.use_cli {
             self.use_cli = config_from_cli.use_cli;
         }
@@ -183,6 +475,22 @@ impl Config {
             self.save_dir = Some(x);
         }
 
+        if let Some(x) = config_from_cli.record_path {
+            self.record_path = Some(x);
+        }
+
+        if let Some(x) = config_from_cli.control {
+            self.control = Some(x);
+        }
+
+        if let Some(x) = config_from_cli.logs {
+            self.logs = Some(x);
+        }
+
+        if let Some(x) = config_from_cli.metrics_listen {
+            self.metrics_listen = Some(x);
+        }
+
         if let Some(tz) = config_from_cli.timezone {
             self.timezone = Some(tz);
         }
@@ -205,12 +513,11 @@ impl Config {
         let args = Args::parse();
         let config_from_cli = Self::from(&args);
 
-        if let Some(config_file) = &args.config_file {
-            if let Some(config_file) =
+        if let Some(config_file) = &args.config_file
+            && let Some(config_file) =
                 parse_config_file::ConfigFile::try_parse_from_file(config_file)
-            {
-                return Self::from(config_file).merge_args(config_from_cli);
-            }
+        {
+            return Self::from(config_file).merge_args(config_from_cli);
         }
 
         if let Some(config_file) = parse_config_file::ConfigFile::try_parse(in_container) {
@@ -225,6 +532,8 @@ impl Config {
 mod tests {
     use jiff::tz::TimeZone;
 
+    use super::{CpuMode, MemoryFormula, OutputFormat};
+
     /// Test the basic timestamp_format parsing/checker function
     #[test]
     fn test_config_parse_timestamp_format() {
@@ -260,6 +569,125 @@ mod tests {
         assert_eq!(result, input);
     }
 
+    #[test]
+    /// Test the cpu_mode config value is parsed, case insensitive, with an invalid value being the default
+    fn test_config_parse_cpu_mode() {
+        for (input, expected) in [
+            (None, CpuMode::PerCore),
+            (Some("core"), CpuMode::PerCore),
+            (Some("invalid"), CpuMode::PerCore),
+            (Some("host"), CpuMode::Host),
+            (Some("HOST"), CpuMode::Host),
+            (Some("quota"), CpuMode::Quota),
+        ] {
+            assert_eq!(CpuMode::from_config(input), expected);
+        }
+    }
+
+    #[test]
+    /// Test the memory_formula config value is parsed, case insensitive, with an invalid value being the default
+    fn test_config_parse_memory_formula() {
+        for (input, expected) in [
+            (None, MemoryFormula::Docker),
+            (Some("docker"), MemoryFormula::Docker),
+            (Some("invalid"), MemoryFormula::Docker),
+            (Some("usage"), MemoryFormula::Usage),
+            (Some("RSS"), MemoryFormula::Rss),
+            (Some("working_set"), MemoryFormula::WorkingSet),
+        ] {
+            assert_eq!(MemoryFormula::from_config(input), expected);
+        }
+    }
+
+    #[test]
+    /// Test the format cli value is parsed, with an invalid value being the default
+    fn test_config_parse_output_format() {
+        for (input, expected) in [
+            (None, OutputFormat::Table),
+            (Some("table"), OutputFormat::Table),
+            (Some("invalid"), OutputFormat::Table),
+            (Some("json"), OutputFormat::Json),
+            (Some("CSV"), OutputFormat::Csv),
+        ] {
+            assert_eq!(OutputFormat::from_config(input), expected);
+        }
+    }
+
+    #[test]
+    /// Test the memory thresholds are parsed, with invalid values using the default
+    fn test_config_parse_memory_thresholds() {
+        for (input, expected) in [
+            ((None, None), (80, 95)),
+            ((Some(50), Some(75)), (50, 75)),
+            ((Some(0), Some(101)), (80, 95)),
+            ((Some(90), Some(85)), (85, 85)),
+            ((Some(99), None), (95, 95)),
+        ] {
+            assert_eq!(
+                super::Config::parse_memory_thresholds(input.0, input.1),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    /// Stats history defaults to 60 seconds, and is clamped between 10 seconds and 24 hours
+    fn test_config_parse_stats_history() {
+        for (input, expected) in [
+            (None, 60),
+            (Some(600), 600),
+            (Some(1), 10),
+            (Some(100_000), 86_400),
+        ] {
+            assert_eq!(super::Config::parse_stats_history(input), expected);
+        }
+    }
+
+    #[test]
+    /// Overview chart count defaults to 5, and is clamped between 1 and 8
+    fn test_config_parse_chart_overview_count() {
+        for (input, expected) in [(None, 5), (Some(3), 3), (Some(0), 1), (Some(20), 8)] {
+            assert_eq!(super::Config::parse_chart_overview_count(input), expected);
+        }
+    }
+
+    #[test]
+    /// Record file rotation size defaults to 100 MB, and is clamped between 1 MB and 10 GB
+    fn test_config_parse_record_max_mb() {
+        for (input, expected) in [
+            (None, 100),
+            (Some(5), 5),
+            (Some(0), 1),
+            (Some(50_000), 10_000),
+        ] {
+            assert_eq!(super::Config::parse_record_max_mb(input), expected);
+        }
+    }
+
+    #[test]
+    /// Sparkline column is disabled by default, and the width is clamped between 5 and 60
+    fn test_config_parse_sparkline() {
+        for (input, expected) in [
+            (None, None),
+            (Some("cpu"), Some(super::SparklineMetric::Cpu)),
+            (Some("Memory"), Some(super::SparklineMetric::Memory)),
+            (Some("off"), None),
+        ] {
+            assert_eq!(super::SparklineMetric::from_config(input), expected);
+        }
+        for (input, expected) in [(None, 10), (Some(20), 20), (Some(1), 5), (Some(100), 60)] {
+            assert_eq!(super::Config::parse_sparkline_width(input), expected);
+        }
+    }
+
+    #[test]
+    /// Test the cpu mode cycles through each variant
+    fn test_config_cpu_mode_next() {
+        assert_eq!(CpuMode::PerCore.next(), CpuMode::Host);
+        assert_eq!(CpuMode::Host.next(), CpuMode::Quota);
+        assert_eq!(CpuMode::Quota.next(), CpuMode::PerCore);
+    }
+
     #[test]
     /// Test various timezones get parsed correctly
     fn test_config_parse_timezone() {
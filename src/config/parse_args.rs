@@ -1,4 +1,6 @@
-use clap::Parser;
+use std::net::SocketAddr;
+
+use clap::{Parser, Subcommand};
 use serde::Deserialize;
 
 #[derive(Parser, Debug, Clone, Deserialize)]
@@ -21,14 +23,26 @@ pub struct Args {
     #[clap(short = 'r', conflicts_with = "color")]
     pub raw: bool,
 
+    /// Show JSON & logfmt log lines as the level, logger, message, and trace id, followed by any other fields
+    #[clap(long = "structured", short = None)]
+    pub structured: bool,
+
     /// Show self when running as a docker container
     #[clap(short = 's')]
     pub show_self: bool,
 
-    /// Don't draw gui - for debugging - mostly pointless
+    /// Don't draw gui, instead print a snapshot of every container at each docker update interval, like `top -b`
     #[clap(short = 'g')]
     pub gui: bool,
 
+    /// Don't draw gui, instead print a single snapshot of every container, then exit
+    #[clap(long = "once", short = None)]
+    pub once: bool,
+
+    /// Output format of the snapshots printed by `-g` & `--once`
+    #[clap(long = "format", short = None, value_name = "format", default_value = "table", value_parser = ["table", "json", "csv"])]
+    pub format: String,
+
     /// Docker host, defaults to `/var/run/docker.sock`
     #[clap(long, short = None)]
     pub host: Option<String>,
@@ -52,6 +66,88 @@ pub struct Args {
     /// Force use of docker cli when execing into containers
     #[clap(long="use-cli", short = None)]
     pub use_cli: bool,
+
+    /// Record every stats sample to a file, JSON Lines if the extension is `.jsonl`, `.ndjson`, or `.json`, else CSV
+    #[clap(long="record", short = None, value_name = "path")]
+    pub record: Option<String>,
+
+    /// Control containers from the command line, rather than the gui
+    #[command(subcommand)]
+    #[serde(skip)]
+    pub command: Option<Command>,
+
+    /// Run without a gui, serving container stats as Prometheus metrics at `http://[address]/metrics`
+    #[clap(long="metrics-listen", short = None, value_name = "address")]
+    pub metrics_listen: Option<SocketAddr>,
+}
+
+/// The containers to control, each is either an exact name, an id prefix, or a name glob
+#[derive(clap::Args, Debug, Clone)]
+pub struct Targets {
+    /// Container names, id prefixes, or name globs using `*` & `?`, e.g. `web_*`
+    #[clap(required = true, value_name = "name|id")]
+    pub containers: Vec<String>,
+}
+
+/// Run a single docker command against one or more containers, then exit
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Delete containers, running containers are stopped first
+    Delete {
+        #[command(flatten)]
+        targets: Targets,
+        /// Also remove the anonymous volumes of each container
+        #[clap(long = "volumes", short = 'v')]
+        volumes: bool,
+    },
+    /// Pause running containers
+    Pause(Targets),
+    /// Recreate containers, with their current configuration, pulling the image if it isn't available locally
+    Recreate(Targets),
+    /// Restart containers
+    Restart(Targets),
+    /// Resume paused containers
+    Resume(Targets),
+    /// Start stopped containers
+    Start(Targets),
+    /// Stop running containers
+    Stop(Targets),
+    /// Follow the logs of every matching container, interleaved by timestamp, each line prefixed with the container name
+    Logs {
+        #[command(flatten)]
+        targets: Targets,
+        /// Only show logs since a duration ago, e.g. `30s`, `10m`, `2h`, `1d`, or an RFC 3339 timestamp
+        #[clap(long = "since", value_name = "duration|timestamp", value_parser = parse_since)]
+        since: Option<i64>,
+        /// Number of existing lines to show from the end of each containers logs, defaults to all
+        #[clap(long = "tail", value_name = "lines")]
+        tail: Option<u64>,
+        /// Only show lines which contain the given text
+        #[clap(long = "grep", value_name = "text")]
+        grep: Option<String>,
+    },
+}
+
+/// Parse the `--since` value, into unix seconds, either a duration before now, or a timestamp
+fn parse_since(input: &str) -> Result<i64, String> {
+    let err = || format!("\"{input}\" isn't a duration, e.g. 10m, or an RFC 3339 timestamp");
+    if let Ok(timestamp) = input.parse::<jiff::Timestamp>() {
+        return Ok(timestamp.as_second());
+    }
+    let unit = match input.chars().last() {
+        Some('s') => 1,
+        Some('m') => 60,
+        Some('h') => 3600,
+        Some('d') => 86_400,
+        _ => return Err(err()),
+    };
+    input[..input.len() - 1]
+        .parse::<i64>()
+        .ok()
+        .filter(|i| *i >= 0)
+        .and_then(|i| i.checked_mul(unit))
+        .map(|i| jiff::Timestamp::now().as_second() - i)
+        .ok_or_else(err)
 }
 
 impl Default for Args {
@@ -61,14 +157,43 @@ impl Default for Args {
             timestamp: true,
             color: false,
             raw: false,
+            structured: false,
             show_self: false,
             gui: true,
+            once: false,
+            format: "table".to_owned(),
             host: None,
             no_std_err: true,
             timezone: None,
             save_dir: None,
             config_file: None,
             use_cli: false,
+            record: None,
+            metrics_listen: None,
+            command: None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::parse_since;
+
+    #[test]
+    /// Durations are converted to unix seconds before now, timestamps are used as is, anything else is an error
+    fn test_parse_args_since() {
+        let now = jiff::Timestamp::now().as_second();
+        for (input, expected) in [("30s", 30), ("10m", 600), ("2h", 7200), ("1d", 86_400)] {
+            let result = parse_since(input).unwrap();
+            assert!(
+                (now - expected..=now - expected + 2).contains(&result),
+                "{input}"
+            );
+        }
+        assert_eq!(parse_since("2025-01-01T00:00:00Z"), Ok(1_735_689_600));
+        for input in ["", "m", "10", "10w", "-5m", "ten minutes"] {
+            assert!(parse_since(input).is_err(), "{input}");
         }
     }
 }
@@ -63,19 +63,34 @@ impl ConfigFileFormat {
 pub struct ConfigFile {
     pub color_logs: Option<bool>,
     pub colors: Option<ConfigColors>,
+    pub cpu_mode: Option<String>,
     pub docker_interval: Option<u32>,
     pub gui: Option<bool>,
     pub host: Option<String>,
     pub keymap: Option<ConfigKeymap>,
+    pub memory_critical_percentage: Option<u8>,
+    pub memory_formula: Option<String>,
+    pub memory_warning_percentage: Option<u8>,
+    pub metrics_listen: Option<String>,
     pub raw_logs: Option<bool>,
+    pub record_max_mb: Option<u32>,
+    pub record_path: Option<String>,
     pub save_dir: Option<String>,
     pub show_self: Option<bool>,
     pub show_std_err: Option<bool>,
+    pub structured_logs: Option<bool>,
+    pub stats_history_seconds: Option<u32>,
+    pub chart_overview_count: Option<u8>,
+    pub sparkline: Option<String>,
+    pub sparkline_width: Option<u8>,
     pub show_timestamp: Option<bool>,
     pub timestamp_format: Option<String>,
     pub timezone: Option<String>,
     pub use_cli: Option<bool>,
     pub show_logs: Option<bool>,
+    pub show_io_chart: Option<bool>,
+    pub show_net_chart: Option<bool>,
+    pub show_host_summary: Option<bool>,
 }
 
 impl ConfigFile {
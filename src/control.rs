@@ -0,0 +1,274 @@
+use bollard::{Docker, container::ListContainersOptions};
+
+use crate::{
+    app_data::{ContainerId, DockerCommand},
+    config::ControlCommand,
+    docker_data::{DockerData, RecreateForm},
+};
+
+/// Every container was resolved, and every command succeeded
+pub const EXIT_SUCCESS: i32 = 0;
+
+/// Unable to connect to docker, or a docker command failed
+pub const EXIT_FAILED: i32 = 1;
+
+/// A target didn't match any container, or an id prefix matched more than one container
+pub const EXIT_UNRESOLVED: i32 = 2;
+
+/// A container id & name, as used to resolve the cli targets
+type Container = (ContainerId, String);
+
+/// Simple glob matching, `*` matches any number of characters, and `?` matches a single character
+fn glob_match(pattern: &str, input: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let input = input.chars().collect::<Vec<_>>();
+    let (mut p, mut i) = (0, 0);
+    let mut backtrack = None;
+    while i < input.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == input[i]) {
+            p += 1;
+            i += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, i));
+            p += 1;
+        } else if let Some((star_p, star_i)) = backtrack {
+            p = star_p + 1;
+            i = star_i + 1;
+            backtrack = Some((star_p, star_i + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Resolve a single target, an exact id or name match takes priority, then a name glob, then an id prefix
+fn resolve_target<'a>(
+    target: &str,
+    containers: &'a [Container],
+) -> Result<Vec<&'a Container>, String> {
+    if let Some(container) = containers
+        .iter()
+        .find(|(id, name)| id.get() == target || name == target)
+    {
+        return Ok(vec![container]);
+    }
+
+    let matches = if target.contains(['*', '?']) {
+        containers
+            .iter()
+            .filter(|(_, name)| glob_match(target, name))
+            .collect::<Vec<_>>()
+    } else {
+        let matches = containers
+            .iter()
+            .filter(|(id, _)| id.get().starts_with(target))
+            .collect::<Vec<_>>();
+        if matches.len() > 1 {
+            return Err(format!(
+                "\"{target}\" matches more than one container id: {}",
+                matches
+                    .iter()
+                    .map(|(_, name)| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        matches
+    };
+
+    if matches.is_empty() {
+        Err(format!("\"{target}\" doesn't match any container"))
+    } else {
+        Ok(matches)
+    }
+}
+
+/// Resolve every target, each container is only included once, in the order in which it was first matched
+fn resolve<'a>(
+    targets: &[String],
+    containers: &'a [Container],
+) -> Result<Vec<&'a Container>, Vec<String>> {
+    let mut resolved: Vec<&Container> = vec![];
+    let mut errors = vec![];
+    for target in targets {
+        match resolve_target(target, containers) {
+            Ok(matches) => {
+                for container in matches {
+                    if !resolved.iter().any(|i| i.0 == container.0) {
+                        resolved.push(container);
+                    }
+                }
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+    if errors.is_empty() {
+        Ok(resolved)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Get the id & name of every container, names are stored by docker with a leading `/`
+async fn list_containers(docker: &Docker) -> Result<Vec<Container>, bollard::errors::Error> {
+    Ok(docker
+        .list_containers(Some(ListContainersOptions::<String> {
+            all: true,
+            ..Default::default()
+        }))
+        .await?
+        .into_iter()
+        .filter_map(|i| {
+            let name = i
+                .names
+                .and_then(|names| names.first().cloned())
+                .unwrap_or_default()
+                .trim_start_matches('/')
+                .to_owned();
+            i.id.map(|id| (ContainerId::from(id.as_str()), name))
+        })
+        .collect())
+}
+
+/// Run a single command against a single container, the recreate form is created from an inspect of the container, and its image, so it's recreated unchanged
+async fn run_single(
+    docker: &Docker,
+    control: &ControlCommand,
+    id: &ContainerId,
+) -> Result<(), bollard::errors::Error> {
+    let recreate_form = if control.command == DockerCommand::Recreate {
+        let inspect = docker.inspect_container(id.get(), None).await?;
+        let image = DockerData::image_config(docker, &inspect).await;
+        Some(RecreateForm::new(id.clone(), inspect, image.as_ref()))
+    } else {
+        None
+    };
+    DockerData::run_command(
+        docker,
+        control.command,
+        id,
+        recreate_form,
+        control.remove_volumes,
+    )
+    .await
+}
+
+/// Resolve the targets against the current containers, any errors are printed to stderr, and the exit code returned
+pub async fn resolve_targets(
+    docker: &Docker,
+    targets: &[String],
+) -> Result<Vec<(ContainerId, String)>, i32> {
+    let containers = match list_containers(docker).await {
+        Ok(containers) => containers,
+        Err(e) => {
+            eprintln!("unable to list containers: {e}");
+            return Err(EXIT_FAILED);
+        }
+    };
+
+    match resolve(targets, &containers) {
+        Ok(resolved) => Ok(resolved.into_iter().cloned().collect()),
+        Err(errors) => {
+            for error in errors {
+                eprintln!("{error}");
+            }
+            Err(EXIT_UNRESOLVED)
+        }
+    }
+}
+
+/// Resolve the targets against the current containers, then run the command against each, returning the process exit code
+/// No command is run unless every target is resolved
+pub async fn run(docker: &Docker, control: &ControlCommand) -> i32 {
+    let resolved = match resolve_targets(docker, &control.targets).await {
+        Ok(resolved) => resolved,
+        Err(code) => return code,
+    };
+
+    let mut code = EXIT_SUCCESS;
+    for (id, name) in &resolved {
+        match run_single(docker, control, id).await {
+            Ok(()) => println!("{} {name} {}", control.command, id.get_short()),
+            Err(e) => {
+                eprintln!("unable to {} {name}: {e}", control.command);
+                code = EXIT_FAILED;
+            }
+        }
+    }
+    code
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::app_data::ContainerId;
+
+    use super::{Container, glob_match, resolve};
+
+    fn gen_containers() -> Vec<Container> {
+        [
+            ("abc123", "web_1"),
+            ("abc456", "web_2"),
+            ("def789", "db"),
+            ("fed000", "worker"),
+        ]
+        .into_iter()
+        .map(|(id, name)| (ContainerId::from(id), name.to_owned()))
+        .collect()
+    }
+
+    /// Just the names of the resolved containers
+    fn names(targets: &[&str]) -> Result<Vec<String>, Vec<String>> {
+        let containers = gen_containers();
+        let targets = targets.iter().map(|i| (*i).to_owned()).collect::<Vec<_>>();
+        resolve(&targets, &containers).map(|i| i.iter().map(|(_, name)| name.clone()).collect())
+    }
+
+    #[test]
+    /// `*` matches any number of characters, `?` matches a single character
+    fn test_control_glob_match() {
+        for (pattern, input, expected) in [
+            ("web_*", "web_1", true),
+            ("web_*", "web_", true),
+            ("web_*", "db", false),
+            ("*", "anything", true),
+            ("w?b_1", "web_1", true),
+            ("w?b_1", "wb_1", false),
+            ("*_1", "web_1", true),
+            ("*e*_*", "web_2", true),
+            ("web", "web_1", false),
+            ("a*b*c", "axxbyyc", true),
+            ("a*b*c", "axxbyy", false),
+        ] {
+            assert_eq!(glob_match(pattern, input), expected, "{pattern} {input}");
+        }
+    }
+
+    #[test]
+    /// Names, full ids, unique id prefixes, and globs are all resolved, each container is only included once
+    fn test_control_resolve() {
+        assert_eq!(names(&["db"]).unwrap(), ["db"]);
+        assert_eq!(names(&["abc123"]).unwrap(), ["web_1"]);
+        assert_eq!(names(&["fe"]).unwrap(), ["worker"]);
+        assert_eq!(names(&["web_*"]).unwrap(), ["web_1", "web_2"]);
+        assert_eq!(
+            names(&["web_2", "web_*", "d*"]).unwrap(),
+            ["web_2", "web_1", "db"]
+        );
+    }
+
+    #[test]
+    /// Ambiguous id prefixes, and targets that don't match, are errors, and every error is returned
+    fn test_control_resolve_errors() {
+        let result = names(&["abc", "missing", "db", "x*"]).unwrap_err();
+        assert_eq!(
+            result,
+            [
+                "\"abc\" matches more than one container id: web_1, web_2",
+                "\"missing\" doesn't match any container",
+                "\"x*\" doesn't match any container",
+            ]
+        );
+    }
+}
@@ -0,0 +1,655 @@
+use std::collections::BTreeSet;
+
+use bollard::service::{
+    ContainerConfig, ContainerInspectResponse, ImageConfig, RestartPolicyNameEnum,
+};
+
+use super::recreate::{mount_lines, port_lines};
+
+/// Labels with these prefixes are added by docker compose, and so shouldn't be exported
+const COMPOSE_LABEL: &str = "com.docker.compose.";
+
+/// Network modes which don't need to be exported
+const DEFAULT_NETWORKS: [&str; 2] = ["default", "bridge"];
+
+/// Only `host`, `none`, and `container:<name|id>` are compose network modes, any other network is user-defined
+fn is_network_mode(input: &str) -> bool {
+    matches!(input, "host" | "none") || input.starts_with("container:")
+}
+
+/// Quote a value so that it can be safely used as a single shell argument
+fn shell_quote(input: &str) -> String {
+    let safe = !input.is_empty()
+        && input
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_-./:=@%+,".contains(c));
+    if safe {
+        input.to_owned()
+    } else {
+        format!("'{}'", input.replace('\'', r"'\''"))
+    }
+}
+
+/// Quote a value for use in yaml, json strings are valid yaml strings
+fn yaml_quote(input: &str) -> String {
+    serde_json::to_string(input).unwrap_or_else(|_| format!("\"{input}\""))
+}
+
+/// Remove the values of a container config that are inherited from its image, env, labels, command, entrypoint, user, and working dir
+/// Without the image config, nothing can be removed
+pub fn strip_image_config(config: ContainerConfig, image: Option<&ImageConfig>) -> ContainerConfig {
+    let Some(image) = image else {
+        return config;
+    };
+    let image_env = image.env.clone().unwrap_or_default();
+    let image_labels = image.labels.clone().unwrap_or_default();
+    let differs = |value: Option<String>, image_value: Option<&String>| {
+        value.filter(|i| !i.is_empty() && Some(i) != image_value)
+    };
+
+    let entrypoint = config.entrypoint.unwrap_or_default();
+    let entrypoint_changed = entrypoint != image.entrypoint.clone().unwrap_or_default();
+    let cmd = config.cmd.unwrap_or_default();
+    let cmd_changed = cmd != image.cmd.clone().unwrap_or_default();
+
+    ContainerConfig {
+        cmd: (entrypoint_changed || cmd_changed).then_some(cmd),
+        entrypoint: entrypoint_changed.then_some(entrypoint),
+        env: config
+            .env
+            .map(|env| env.into_iter().filter(|i| !image_env.contains(i)).collect()),
+        labels: config.labels.map(|labels| {
+            labels
+                .into_iter()
+                .filter(|(k, v)| image_labels.get(k).is_none_or(|i| i != v))
+                .collect()
+        }),
+        user: differs(config.user, image.user.as_ref()),
+        working_dir: differs(config.working_dir, image.working_dir.as_ref()),
+        ..config
+    }
+}
+
+/// The parts of a containers config that differ from the defaults, either the docker defaults, or those set by the image
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+struct ContainerSpec {
+    cap_add: Vec<String>,
+    cap_drop: Vec<String>,
+    command: Vec<String>,
+    cpus: Option<String>,
+    devices: Vec<String>,
+    dns: Vec<String>,
+    entrypoint: Vec<String>,
+    env: Vec<String>,
+    extra_hosts: Vec<String>,
+    hostname: Option<String>,
+    image: String,
+    init: bool,
+    labels: Vec<(String, String)>,
+    memory: Option<i64>,
+    name: String,
+    network_mode: Option<String>,
+    networks: Vec<String>,
+    ports: Vec<String>,
+    privileged: bool,
+    read_only: bool,
+    restart: Option<String>,
+    stdin_open: bool,
+    tty: bool,
+    user: Option<String>,
+    volumes: Vec<String>,
+    working_dir: Option<String>,
+}
+
+impl ContainerSpec {
+    /// Generate the spec, using the image config to remove any values that are inherited from the image
+    #[allow(clippy::too_many_lines)]
+    fn new(inspect: &ContainerInspectResponse, image: Option<&ImageConfig>) -> Self {
+        let config = strip_image_config(inspect.config.clone().unwrap_or_default(), image);
+        let host_config = inspect.host_config.clone().unwrap_or_default();
+        let id = inspect.id.as_deref().unwrap_or_default();
+
+        let mut labels = config
+            .labels
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(k, _)| !k.starts_with(COMPOSE_LABEL))
+            .collect::<Vec<_>>();
+        labels.sort();
+
+        let network_mode = host_config
+            .network_mode
+            .clone()
+            .filter(|i| !DEFAULT_NETWORKS.contains(&i.as_str()));
+
+        let mut networks = inspect
+            .network_settings
+            .as_ref()
+            .and_then(|i| i.networks.as_ref())
+            .map(|i| {
+                i.keys()
+                    .filter(|k| {
+                        !DEFAULT_NETWORKS.contains(&k.as_str()) && network_mode.as_ref() != Some(*k)
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        networks.sort();
+
+        Self {
+            cap_add: host_config.cap_add.unwrap_or_default(),
+            cap_drop: host_config.cap_drop.unwrap_or_default(),
+            command: config.cmd.unwrap_or_default(),
+            cpus: host_config.nano_cpus.filter(|i| *i > 0).map(|i| {
+                format!("{}.{:09}", i / 1_000_000_000, i % 1_000_000_000)
+                    .trim_end_matches('0')
+                    .trim_end_matches('.')
+                    .to_owned()
+            }),
+            devices: host_config
+                .devices
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|i| {
+                    let host = i.path_on_host?;
+                    let container = i.path_in_container.unwrap_or_else(|| host.clone());
+                    Some(format!("{host}:{container}"))
+                })
+                .collect(),
+            dns: host_config.dns.unwrap_or_default(),
+            entrypoint: config.entrypoint.unwrap_or_default(),
+            env: config.env.unwrap_or_default(),
+            extra_hosts: host_config.extra_hosts.unwrap_or_default(),
+            hostname: config
+                .hostname
+                .filter(|i| !i.is_empty() && !id.starts_with(i.as_str())),
+            image: config.image.unwrap_or_default(),
+            init: host_config.init.unwrap_or_default(),
+            labels,
+            memory: host_config.memory.filter(|i| *i > 0),
+            name: inspect
+                .name
+                .as_deref()
+                .unwrap_or_default()
+                .trim_start_matches('/')
+                .to_owned(),
+            network_mode,
+            networks,
+            ports: port_lines(inspect.host_config.as_ref()),
+            privileged: host_config.privileged.unwrap_or_default(),
+            read_only: host_config.readonly_rootfs.unwrap_or_default(),
+            restart: host_config.restart_policy.and_then(|i| match i.name {
+                Some(RestartPolicyNameEnum::ON_FAILURE) => Some(
+                    i.maximum_retry_count
+                        .filter(|i| *i > 0)
+                        .map_or_else(|| "on-failure".to_owned(), |i| format!("on-failure:{i}")),
+                ),
+                Some(RestartPolicyNameEnum::ALWAYS | RestartPolicyNameEnum::UNLESS_STOPPED) => {
+                    i.name.map(|i| i.to_string())
+                }
+                _ => None,
+            }),
+            stdin_open: config.open_stdin.unwrap_or_default(),
+            tty: config.tty.unwrap_or_default(),
+            user: config.user.filter(|i| !i.is_empty()),
+            volumes: mount_lines(inspect.mounts.as_deref()),
+            working_dir: config.working_dir.filter(|i| !i.is_empty()),
+        }
+    }
+
+    /// Named volumes, rather than bind mounts, need to be declared in a compose file
+    fn named_volumes(&self) -> BTreeSet<&str> {
+        self.volumes
+            .iter()
+            .filter_map(|i| i.split_once(':').map(|(source, _)| source))
+            .filter(|i| !i.starts_with(['/', '.', '~']))
+            .collect()
+    }
+
+    /// Generate a `docker run` command, one flag per line
+    fn docker_run(&self) -> String {
+        let mut args = vec![format!("docker run -d --name {}", shell_quote(&self.name))];
+        let mut push =
+            |flag: &str, value: &str| args.push(format!("{flag} {}", shell_quote(value)));
+
+        if let Some(restart) = self.restart.as_ref() {
+            push("--restart", restart);
+        }
+        if let Some(hostname) = self.hostname.as_ref() {
+            push("--hostname", hostname);
+        }
+        if let Some(user) = self.user.as_ref() {
+            push("--user", user);
+        }
+        if let Some(working_dir) = self.working_dir.as_ref() {
+            push("--workdir", working_dir);
+        }
+        if let Some(network_mode) = self.network_mode.as_ref() {
+            push("--network", network_mode);
+        }
+        for (flag, values) in [
+            ("-e", &self.env),
+            ("-p", &self.ports),
+            ("-v", &self.volumes),
+            ("--device", &self.devices),
+            ("--cap-add", &self.cap_add),
+            ("--cap-drop", &self.cap_drop),
+            ("--dns", &self.dns),
+            ("--add-host", &self.extra_hosts),
+        ] {
+            for value in values {
+                push(flag, value);
+            }
+        }
+        for (key, value) in &self.labels {
+            push("--label", &format!("{key}={value}"));
+        }
+        if let Some(memory) = self.memory {
+            push("--memory", &memory.to_string());
+        }
+        if let Some(cpus) = self.cpus.as_ref() {
+            push("--cpus", cpus);
+        }
+        if let Some(entrypoint) = self.entrypoint.first() {
+            push("--entrypoint", entrypoint);
+        }
+        for (flag, enabled) in [
+            ("--init", self.init),
+            ("--privileged", self.privileged),
+            ("--read-only", self.read_only),
+            ("-i", self.stdin_open),
+            ("-t", self.tty),
+        ] {
+            if enabled {
+                args.push(flag.to_owned());
+            }
+        }
+
+        let mut last = vec![shell_quote(&self.image)];
+        last.extend(
+            self.entrypoint
+                .iter()
+                .skip(1)
+                .chain(self.command.iter())
+                .map(|i| shell_quote(i)),
+        );
+        args.push(last.join(" "));
+
+        // Networks other than the first can only be connected after the container has been created
+        let mut output = vec![args.join(" \\\n  ")];
+        output.extend(self.networks.iter().map(|network| {
+            format!(
+                "docker network connect {} {}",
+                shell_quote(network),
+                shell_quote(&self.name)
+            )
+        }));
+        output.join("\n")
+    }
+
+    /// Generate a docker-compose.yml containing a single service
+    fn compose(&self) -> String {
+        // A user-defined primary network is listed alongside any other networks, rather than used as the network_mode
+        let networks = self
+            .network_mode
+            .iter()
+            .filter(|i| !is_network_mode(i))
+            .chain(self.networks.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut lines = vec![
+            "services:".to_owned(),
+            format!("  {}:", self.name),
+            format!("    image: {}", yaml_quote(&self.image)),
+            format!("    container_name: {}", yaml_quote(&self.name)),
+        ];
+
+        let mut scalar = |key: &str, value: Option<String>| {
+            if let Some(value) = value {
+                lines.push(format!("    {key}: {value}"));
+            }
+        };
+        scalar("restart", self.restart.as_deref().map(yaml_quote));
+        scalar("hostname", self.hostname.as_deref().map(yaml_quote));
+        scalar("user", self.user.as_deref().map(yaml_quote));
+        scalar("working_dir", self.working_dir.as_deref().map(yaml_quote));
+        scalar(
+            "network_mode",
+            self.network_mode
+                .as_deref()
+                .filter(|i| is_network_mode(i))
+                .map(yaml_quote),
+        );
+        scalar("mem_limit", self.memory.map(|i| i.to_string()));
+        scalar("cpus", self.cpus.as_deref().map(yaml_quote));
+        let flow = |values: &[String]| {
+            (!values.is_empty()).then(|| {
+                format!(
+                    "[{}]",
+                    values
+                        .iter()
+                        .map(|i| yaml_quote(i))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+        };
+        scalar("entrypoint", flow(&self.entrypoint));
+        scalar("command", flow(&self.command));
+        for (key, enabled) in [
+            ("init", self.init),
+            ("privileged", self.privileged),
+            ("read_only", self.read_only),
+            ("stdin_open", self.stdin_open),
+            ("tty", self.tty),
+        ] {
+            if enabled {
+                lines.push(format!("    {key}: true"));
+            }
+        }
+
+        let labels = self
+            .labels
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>();
+        for (key, values) in [
+            ("environment", &self.env),
+            ("ports", &self.ports),
+            ("volumes", &self.volumes),
+            ("devices", &self.devices),
+            ("cap_add", &self.cap_add),
+            ("cap_drop", &self.cap_drop),
+            ("dns", &self.dns),
+            ("extra_hosts", &self.extra_hosts),
+            ("labels", &labels),
+            ("networks", &networks),
+        ] {
+            if !values.is_empty() {
+                lines.push(format!("    {key}:"));
+                lines.extend(values.iter().map(|i| format!("      - {}", yaml_quote(i))));
+            }
+        }
+
+        // Existing volumes & networks are declared as external, so compose won't try to create new ones
+        for (key, values) in [
+            ("volumes", self.named_volumes()),
+            ("networks", networks.iter().map(String::as_str).collect()),
+        ] {
+            if !values.is_empty() {
+                lines.push(format!("{key}:"));
+                for value in values {
+                    lines.push(format!("  {value}:"));
+                    lines.push("    external: true".to_owned());
+                }
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// The `docker run` command and compose file for a single container, generated from its inspect data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerExport {
+    pub name: String,
+    pub run: String,
+    pub compose: String,
+}
+
+impl ContainerExport {
+    pub fn new(inspect: &ContainerInspectResponse, image: Option<&ImageConfig>) -> Self {
+        let spec = ContainerSpec::new(inspect, image);
+        Self {
+            run: spec.docker_run(),
+            compose: spec.compose(),
+            name: spec.name,
+        }
+    }
+
+    /// All the lines to be displayed in the export popup
+    pub fn lines(&self) -> Vec<String> {
+        let mut output = vec!["# docker run".to_owned()];
+        output.extend(self.run.lines().map(ToOwned::to_owned));
+        output.push(String::new());
+        output.push("# docker-compose.yml".to_owned());
+        output.extend(self.compose.lines().map(ToOwned::to_owned));
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerConfig, ContainerInspectResponse, EndpointSettings, HostConfig, ImageConfig,
+        MountPoint, MountPointTypeEnum, NetworkSettings, PortBinding, RestartPolicy,
+        RestartPolicyNameEnum,
+    };
+
+    use super::{ContainerExport, shell_quote};
+
+    fn gen_inspect() -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            id: Some("1234567890abcdef".to_owned()),
+            name: Some("/container_1".to_owned()),
+            config: Some(ContainerConfig {
+                hostname: Some("1234567890ab".to_owned()),
+                image: Some("image_1:1.0".to_owned()),
+                env: Some(vec!["PATH=/usr/bin".to_owned(), "A=hello world".to_owned()]),
+                cmd: Some(vec![
+                    "serve".to_owned(),
+                    "--port".to_owned(),
+                    "80".to_owned(),
+                ]),
+                labels: Some(HashMap::from([
+                    ("com.docker.compose.project".to_owned(), "test".to_owned()),
+                    ("maintainer".to_owned(), "image".to_owned()),
+                    ("custom".to_owned(), "label".to_owned()),
+                ])),
+                ..Default::default()
+            }),
+            host_config: Some(HostConfig {
+                network_mode: Some("bridge".to_owned()),
+                restart_policy: Some(RestartPolicy {
+                    name: Some(RestartPolicyNameEnum::UNLESS_STOPPED),
+                    maximum_retry_count: Some(0),
+                }),
+                port_bindings: Some(HashMap::from([(
+                    "80/tcp".to_owned(),
+                    Some(vec![PortBinding {
+                        host_ip: Some(String::new()),
+                        host_port: Some("8080".to_owned()),
+                    }]),
+                )])),
+                ..Default::default()
+            }),
+            mounts: Some(vec![
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::VOLUME),
+                    name: Some("volume_1".to_owned()),
+                    destination: Some("/data".to_owned()),
+                    rw: Some(true),
+                    ..Default::default()
+                },
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::BIND),
+                    source: Some("/etc/config".to_owned()),
+                    destination: Some("/config".to_owned()),
+                    rw: Some(false),
+                    ..Default::default()
+                },
+            ]),
+            network_settings: Some(NetworkSettings {
+                networks: Some(HashMap::from([
+                    ("bridge".to_owned(), EndpointSettings::default()),
+                    ("network_1".to_owned(), EndpointSettings::default()),
+                ])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn gen_image() -> ImageConfig {
+        ImageConfig {
+            env: Some(vec!["PATH=/usr/bin".to_owned()]),
+            cmd: Some(vec!["serve".to_owned()]),
+            labels: Some(HashMap::from([(
+                "maintainer".to_owned(),
+                "image".to_owned(),
+            )])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Only unsafe values are quoted
+    fn test_export_shell_quote() {
+        assert_eq!(shell_quote("image_1:1.0"), "image_1:1.0");
+        assert_eq!(shell_quote("A=hello world"), "'A=hello world'");
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+        assert_eq!(shell_quote(""), "''");
+    }
+
+    #[test]
+    /// docker run command only contains values that differ from the image
+    fn test_export_docker_run() {
+        let result = ContainerExport::new(&gen_inspect(), Some(&gen_image()));
+        assert_eq!(result.name, "container_1");
+        assert_eq!(
+            result.run,
+            [
+                "docker run -d --name container_1 \\",
+                "  --restart unless-stopped \\",
+                "  -e 'A=hello world' \\",
+                "  -p 8080:80 \\",
+                "  -v volume_1:/data \\",
+                "  -v /etc/config:/config:ro \\",
+                "  --label custom=label \\",
+                "  image_1:1.0 serve --port 80",
+                "docker network connect network_1 container_1",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    /// Without the image config, all env and labels are exported
+    fn test_export_docker_run_no_image() {
+        let result = ContainerExport::new(&gen_inspect(), None);
+        assert!(result.run.contains("-e PATH=/usr/bin"));
+        assert!(result.run.contains("--label maintainer=image"));
+        assert!(!result.run.contains("com.docker.compose"));
+    }
+
+    #[test]
+    /// Compose snippet contains a single service, and declares existing volumes & networks as external
+    fn test_export_compose() {
+        let result = ContainerExport::new(&gen_inspect(), Some(&gen_image()));
+        assert_eq!(
+            result.compose,
+            [
+                "services:",
+                "  container_1:",
+                "    image: \"image_1:1.0\"",
+                "    container_name: \"container_1\"",
+                "    restart: \"unless-stopped\"",
+                "    command: [\"serve\", \"--port\", \"80\"]",
+                "    environment:",
+                "      - \"A=hello world\"",
+                "    ports:",
+                "      - \"8080:80\"",
+                "    volumes:",
+                "      - \"volume_1:/data\"",
+                "      - \"/etc/config:/config:ro\"",
+                "    labels:",
+                "      - \"custom=label\"",
+                "    networks:",
+                "      - \"network_1\"",
+                "volumes:",
+                "  volume_1:",
+                "    external: true",
+                "networks:",
+                "  network_1:",
+                "    external: true",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    /// A user-defined primary network is listed under networks, rather than used as the network_mode
+    fn test_export_compose_user_network() {
+        let mut inspect = gen_inspect();
+        if let Some(host_config) = inspect.host_config.as_mut() {
+            host_config.network_mode = Some("network_2".to_owned());
+        }
+        if let Some(networks) = inspect
+            .network_settings
+            .as_mut()
+            .and_then(|i| i.networks.as_mut())
+        {
+            networks.remove("bridge");
+            networks.insert("network_2".to_owned(), EndpointSettings::default());
+        }
+        let result = ContainerExport::new(&inspect, Some(&gen_image()));
+        assert!(!result.compose.contains("network_mode"));
+        assert!(
+            result.compose.ends_with(
+                &[
+                    "    networks:",
+                    "      - \"network_2\"",
+                    "      - \"network_1\"",
+                    "volumes:",
+                    "  volume_1:",
+                    "    external: true",
+                    "networks:",
+                    "  network_1:",
+                    "    external: true",
+                    "  network_2:",
+                    "    external: true",
+                ]
+                .join("\n")
+            )
+        );
+        assert!(result.run.contains("--network network_2"));
+        assert!(
+            result
+                .run
+                .ends_with("docker network connect network_1 container_1")
+        );
+    }
+
+    #[test]
+    /// host, none, and container network modes are kept as the network_mode
+    fn test_export_compose_network_mode() {
+        for mode in ["host", "none", "container:container_2"] {
+            let mut inspect = gen_inspect();
+            if let Some(host_config) = inspect.host_config.as_mut() {
+                host_config.network_mode = Some(mode.to_owned());
+            }
+            inspect.network_settings = None;
+            let result = ContainerExport::new(&inspect, Some(&gen_image()));
+            assert!(
+                result
+                    .compose
+                    .contains(&format!("    network_mode: \"{mode}\""))
+            );
+            assert!(!result.compose.contains("networks:"));
+        }
+    }
+
+    #[test]
+    /// Popup lines contain both the run command and the compose file
+    fn test_export_lines() {
+        let result = ContainerExport::new(&gen_inspect(), Some(&gen_image())).lines();
+        assert_eq!(result[0], "# docker run");
+        assert_eq!(result[10], "");
+        assert_eq!(result[11], "# docker-compose.yml");
+        assert_eq!(result.len(), 35);
+    }
+}
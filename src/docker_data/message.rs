@@ -7,7 +7,9 @@ use tokio::sync::oneshot::Sender;
 #[derive(Debug)]
 pub enum DockerMessage {
     ConfirmDelete(ContainerId),
+    ConfirmRecreate(ContainerId),
     Control((DockerCommand, ContainerId)),
     Exec(Sender<Arc<Docker>>),
+    ToggleRecord,
     Update,
 }
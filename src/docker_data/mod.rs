@@ -1,10 +1,12 @@
 use bollard::{
     Docker,
     container::{
-        ListContainersOptions, LogsOptions, MemoryStatsStats, RemoveContainerOptions,
-        StartContainerOptions, Stats, StatsOptions,
+        CreateContainerOptions, ListContainersOptions, LogsOptions, MemoryStats, MemoryStatsStats,
+        RemoveContainerOptions, RenameContainerOptions, StartContainerOptions, Stats, StatsOptions,
     },
-    service::ContainerSummary,
+    image::CreateImageOptions,
+    network::ConnectNetworkOptions,
+    service::{ContainerInspectResponse, ContainerSummary, HostConfig, ImageConfig},
 };
 use futures_util::StreamExt;
 use parking_lot::Mutex;
@@ -20,13 +22,23 @@ use uuid::Uuid;
 
 use crate::{
     ENTRY_POINT,
-    app_data::{AppData, ContainerId, DockerCommand, State},
+    app_data::{
+        AppData, ContainerId, ContainerPids, DockerCommand, HostSummary, MemoryDetail,
+        NetworkInterface, State,
+    },
     app_error::AppError,
     config::Config,
     ui::{GuiState, Status},
 };
+mod export;
 mod message;
+mod record;
+mod recreate;
+pub use export::ContainerExport;
 pub use message::DockerMessage;
+pub use record::csv_quote;
+use record::{StatsRecorder, StatsSample};
+pub use recreate::{RecreateField, RecreateForm};
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 enum SpawnId {
@@ -62,13 +74,18 @@ impl Binate {
     }
 }
 
+/// How often the host summary is refreshed, if enabled
+const HOST_SUMMARY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
 pub struct DockerData {
     app_data: Arc<Mutex<AppData>>,
     binate: Binate,
     config: Config,
     docker: Arc<Docker>,
     gui_state: Arc<Mutex<GuiState>>,
+    host_summary_updated: Option<std::time::Instant>,
     receiver: Receiver<DockerMessage>,
+    recorder: Arc<Mutex<Option<StatsRecorder>>>,
     spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
 }
 
@@ -89,17 +106,7 @@ impl DockerData {
             stats.precpu_stats.system_cpu_usage,
         ) {
             let system_delta = cpu_stats_usage.saturating_sub(precpu_stats_usage) as f64;
-            let online_cpus = stats.cpu_stats.online_cpus.unwrap_or_else(|| {
-                u64::try_from(
-                    stats
-                        .cpu_stats
-                        .cpu_usage
-                        .percpu_usage
-                        .as_ref()
-                        .map_or(0, std::vec::Vec::len),
-                )
-                .unwrap_or_default()
-            }) as f64;
+            let online_cpus = Self::online_cpus(stats) as f64;
             if system_delta > 0.0 && cpu_delta > 0.0 {
                 cpu_percentage = (cpu_delta / system_delta) * online_cpus * 100.0;
             }
@@ -107,15 +114,164 @@ impl DockerData {
         cpu_percentage
     }
 
-    /// Get a single docker stat in order to update mem and cpu usage
-    /// don't take &self, so that can tokio::spawn into it's own thread
-    /// remove if from spawns hashmap when complete
+    /// Breakdown of the memory usage, None if docker doesn't report the detailed memory stats, i.e. on Windows
+    fn calculate_memory_detail(memory_stats: &MemoryStats) -> Option<MemoryDetail> {
+        let usage = memory_stats.usage.unwrap_or_default();
+        memory_stats.stats.map(|i| match i {
+            MemoryStatsStats::V1(x) => MemoryDetail {
+                usage,
+                anon: x.rss,
+                file: x.cache,
+                active_file: x.active_file,
+                inactive_file: x.inactive_file,
+                kernel: None,
+                shmem: x.shmem,
+            },
+            MemoryStatsStats::V2(x) => MemoryDetail {
+                usage,
+                anon: x.anon,
+                file: x.file,
+                active_file: x.active_file,
+                inactive_file: x.inactive_file,
+                kernel: Some(x.kernel_stack.saturating_add(x.slab)),
+                shmem: Some(x.shmem),
+            },
+        })
+    }
+
+    /// Number of cpus on the host, if not reported, use the number of per core usage entries instead
+    fn online_cpus(stats: &Stats) -> u64 {
+        stats.cpu_stats.online_cpus.unwrap_or_else(|| {
+            u64::try_from(
+                stats
+                    .cpu_stats
+                    .cpu_usage
+                    .percpu_usage
+                    .as_ref()
+                    .map_or(0, std::vec::Vec::len),
+            )
+            .unwrap_or_default()
+        })
+    }
+
+    /// The cpu limit of a container, in units of 10^-9 cpus, set either via `--cpus`, or `--cpu-quota` & `--cpu-period`
+    /// None if the container has no limit
+    fn calculate_cpu_quota(host_config: &HostConfig) -> Option<u64> {
+        let positive = |x: Option<i64>| x.and_then(|i| u64::try_from(i).ok()).filter(|i| *i > 0);
+        positive(host_config.nano_cpus).or_else(|| {
+            let quota = positive(host_config.cpu_quota)?;
+            let period = positive(host_config.cpu_period).unwrap_or(100_000);
+            Some(quota.saturating_mul(1_000_000_000) / period)
+        })
+    }
+
+    /// Percentage of cpu periods in which the container was throttled, the cumulative throttled time in ns, and the usage of each core
+    /// Per core usage is only reported by cgroup v1, so will be empty on cgroup v2 hosts
+    #[allow(clippy::cast_precision_loss)]
+    fn calculate_cpu_detail(stats: &Stats) -> (f64, u64, Vec<f64>) {
+        let (throttling, pre_throttling) = (
+            &stats.cpu_stats.throttling_data,
+            &stats.precpu_stats.throttling_data,
+        );
+        let periods = throttling.periods.saturating_sub(pre_throttling.periods);
+        let throttled_periods = throttling
+            .throttled_periods
+            .saturating_sub(pre_throttling.throttled_periods);
+        let throttled = if periods > 0 {
+            throttled_periods as f64 / periods as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        let system_delta = stats
+            .cpu_stats
+            .system_cpu_usage
+            .zip(stats.precpu_stats.system_cpu_usage)
+            .map_or(0, |(current, previous)| current.saturating_sub(previous))
+            as f64;
+
+        let per_core = stats
+            .cpu_stats
+            .cpu_usage
+            .percpu_usage
+            .as_ref()
+            .filter(|_| system_delta > 0.0)
+            .map_or_else(Vec::new, |per_core| {
+                let previous = stats.precpu_stats.cpu_usage.percpu_usage.as_ref();
+                let cores = per_core.len() as f64;
+                per_core
+                    .iter()
+                    .enumerate()
+                    .map(|(index, usage)| {
+                        let previous = previous
+                            .and_then(|i| i.get(index))
+                            .copied()
+                            .unwrap_or_default();
+                        usage.saturating_sub(previous) as f64 / system_delta * cores * 100.0
+                    })
+                    .collect()
+            });
+
+        (throttled, throttling.throttled_time, per_core)
+    }
+
+    /// Sum the cumulative bytes read & written across every block device
+    /// cgroup v1 reports a Read, Write, Sync, Async, & Total entry for each device, whereas cgroup v2 only reports read & write entries, so match the op case insensitively and ignore the rest
+    fn calculate_block_io(stats: &Stats) -> (u64, u64) {
+        stats
+            .blkio_stats
+            .io_service_bytes_recursive
+            .iter()
+            .flatten()
+            .fold((0, 0), |(read, write), entry| {
+                if entry.op.eq_ignore_ascii_case("read") {
+                    (read.saturating_add(entry.value), write)
+                } else if entry.op.eq_ignore_ascii_case("write") {
+                    (read, write.saturating_add(entry.value))
+                } else {
+                    (read, write)
+                }
+            })
+    }
+
+    /// Collect the cumulative totals of every network interface, sorted by interface name
+    fn calculate_networks(stats: &Stats) -> Vec<NetworkInterface> {
+        let mut networks = stats
+            .networks
+            .iter()
+            .flatten()
+            .map(|(name, network)| NetworkInterface {
+                name: name.clone(),
+                rx_bytes: network.rx_bytes,
+                tx_bytes: network.tx_bytes,
+                rx_errors: network.rx_errors,
+                tx_errors: network.tx_errors,
+                rx_dropped: network.rx_dropped,
+                tx_dropped: network.tx_dropped,
+            })
+            .collect::<Vec<_>>();
+        networks.sort_by(|a, b| a.name.cmp(&b.name));
+        networks
+    }
+
+    /// The time, in ms, at which docker read the stats, used to calculate per second rates
+    /// If it can't be parsed, use the current time instead
+    fn stats_timestamp_ms(stats: &Stats) -> i64 {
+        stats
+            .read
+            .parse::<jiff::Timestamp>()
+            .unwrap_or_else(|_| jiff::Timestamp::now())
+            .as_millisecond()
+    }
+
     /// Get a single docker stat in order to update mem and cpu usage
     /// don't take &self, so that can tokio::spawn into it's own thread
     /// remove if from spawns hashmap when complete
     async fn update_container_stat(
         app_data: Arc<Mutex<AppData>>,
         docker: Arc<Docker>,
+        gui_state: Arc<Mutex<GuiState>>,
+        recorder: Arc<Mutex<Option<StatsRecorder>>>,
         state: State,
         spawn_id: SpawnId,
         spawns: Arc<Mutex<HashMap<SpawnId, JoinHandle<()>>>>,
@@ -131,54 +287,161 @@ impl DockerData {
             )
             .take(1);
 
+        let memory_formula = app_data.lock().config.memory_formula;
         while let Some(Ok(stats)) = stream.next().await {
             // Memory stats are only collected if the container is alive - is this the behaviour we want?
+            let mem_detail = Self::calculate_memory_detail(&stats.memory_stats);
             let (mem_stat, cpu_stats) = if state.is_alive() {
-                let mem_cache = stats.memory_stats.stats.map_or(0, |i| match i {
-                    MemoryStatsStats::V1(x) => x.inactive_file,
-                    MemoryStatsStats::V2(x) => x.inactive_file,
-                });
                 (
-                    Some(
-                        stats
-                            .memory_stats
-                            .usage
-                            .unwrap_or_default()
-                            .saturating_sub(mem_cache),
-                    ),
+                    Some(mem_detail.map_or_else(
+                        || stats.memory_stats.usage.unwrap_or_default(),
+                        |i| i.calculate(memory_formula),
+                    )),
                     Some(Self::calculate_usage(&stats)),
                 )
             } else {
                 (None, None)
             };
 
-            let op_key = stats
-                .networks
-                .as_ref()
-                .and_then(|networks| networks.keys().next().cloned());
+            let cpu_detail = state.is_alive().then(|| Self::calculate_cpu_detail(&stats));
+            let block = Self::calculate_block_io(&stats);
+            let timestamp_ms = Self::stats_timestamp_ms(&stats);
 
-            let (rx, tx) = if let Some(key) = op_key {
-                stats
-                    .networks
-                    .unwrap_or_default()
-                    .get(&key)
-                    .map_or((0, 0), |f| (f.rx_bytes, f.tx_bytes))
-            } else {
-                (0, 0)
-            };
+            let networks = Self::calculate_networks(&stats);
+
+            // The cpu limit is only found via an inspect, so only do this once per container
+            if app_data.lock().cpu_limit_uninspected(id) {
+                let nano_cpus = docker
+                    .inspect_container(id.get(), None)
+                    .await
+                    .ok()
+                    .and_then(|i| i.host_config)
+                    .and_then(|i| Self::calculate_cpu_quota(&i));
+                app_data.lock().update_cpu_quota_by_id(id, nano_cpus);
+            }
 
-            app_data.lock().update_stats_by_id(
+            let mut app_data_lock = app_data.lock();
+            app_data_lock.update_mem_detail_by_id(id, mem_detail);
+            if let Some((throttled, throttled_time, per_core)) = cpu_detail {
+                app_data_lock.update_cpu_detail_by_id(
+                    id,
+                    throttled,
+                    throttled_time,
+                    per_core,
+                    Self::online_cpus(&stats),
+                );
+            }
+            let mem_limit = stats.memory_stats.limit.unwrap_or_default();
+            let pids = ContainerPids::new(stats.pids_stats.current, stats.pids_stats.limit);
+            let sample = recorder
+                .lock()
+                .as_ref()
+                .is_some_and(StatsRecorder::is_recording)
+                .then(|| StatsSample {
+                    timestamp: jiff::Timestamp::from_millisecond(timestamp_ms)
+                        .unwrap_or_else(|_| jiff::Timestamp::now())
+                        .to_string(),
+                    id: id.get().to_owned(),
+                    name: app_data_lock
+                        .get_any_container_name_by_id(id)
+                        .unwrap_or_default(),
+                    cpu: cpu_stats,
+                    memory: mem_stat,
+                    memory_limit: mem_limit,
+                    rx: networks.iter().map(|i| i.rx_bytes).sum(),
+                    tx: networks.iter().map(|i| i.tx_bytes).sum(),
+                    block_read: block.0,
+                    block_write: block.1,
+                    pids: pids.current,
+                });
+            app_data_lock.update_stats_by_id(
                 id,
                 cpu_stats,
                 mem_stat,
-                stats.memory_stats.limit.unwrap_or_default(),
-                rx,
-                tx,
+                mem_limit,
+                networks,
+                block,
+                pids,
+                timestamp_ms,
             );
+            drop(app_data_lock);
+            if let Some(sample) = sample {
+                Self::record_sample(&app_data, &gui_state, &recorder, &sample);
+            }
         }
         spawns.lock().remove(&spawn_id);
     }
 
+    /// Append a stats sample to the record file, recording is stopped if the sample can't be written
+    fn record_sample(
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        recorder: &Arc<Mutex<Option<StatsRecorder>>>,
+        sample: &StatsSample,
+    ) {
+        let mut recorder = recorder.lock();
+        if let Some(recorder) = recorder.as_mut()
+            && recorder.write(sample).is_err()
+        {
+            recorder.stop();
+            app_data.lock().set_error(
+                AppError::IO(format!("recording stats to {}", recorder.path().display())),
+                gui_state,
+                Status::Error,
+            );
+        }
+    }
+
+    /// Start, or stop, recording every stats sample to a file
+    /// If no record path is set, a new CSV file is created in the save_dir
+    fn toggle_record(&self) {
+        let mut recorder = self.recorder.lock();
+        if let Some(recorder) = recorder.as_mut().filter(|i| i.is_recording()) {
+            recorder.stop();
+            self.gui_state.lock().set_info_box(&format!(
+                "stopped recording to {}",
+                recorder.path().display()
+            ));
+            return;
+        }
+        if recorder.is_none() {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::SystemTime::UNIX_EPOCH)
+                .map_or(0, |i| i.as_secs());
+            *recorder = self
+                .config
+                .record_path
+                .clone()
+                .or_else(|| {
+                    self.config
+                        .save_dir
+                        .as_ref()
+                        .map(|dir| dir.join(format!("oxker_stats_{now}.csv")))
+                })
+                .map(|path| StatsRecorder::new(path, self.config.record_max_mb));
+        }
+        if let Some(recorder) = recorder.as_mut() {
+            let path = recorder.path().display().to_string();
+            if recorder.start().is_ok() {
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("recording to {path}"));
+            } else {
+                self.app_data.lock().set_error(
+                    AppError::IO(format!("recording stats to {path}")),
+                    &self.gui_state,
+                    Status::Error,
+                );
+            }
+        } else {
+            self.app_data.lock().set_error(
+                AppError::IO("recording stats, no save directory".to_owned()),
+                &self.gui_state,
+                Status::Error,
+            );
+        }
+    }
+
     /// Update all stats, spawn each container into own tokio::spawn thread
     fn update_all_container_stats(&mut self) {
         let all_ids = self.app_data.lock().get_all_id_state();
@@ -191,6 +454,8 @@ impl DockerData {
                 spawns.insert(tokio::spawn(Self::update_container_stat(
                     Arc::clone(&self.app_data),
                     Arc::clone(&self.docker),
+                    Arc::clone(&self.gui_state),
+                    Arc::clone(&self.recorder),
                     state,
                     spawn_id,
                     Arc::clone(&self.spawns),
@@ -292,7 +557,12 @@ impl DockerData {
         let loading_uuid = Uuid::new_v4();
         GuiState::start_loading_animation(&self.gui_state, loading_uuid);
         self.update_all_containers().await;
-        let all_ids = self.app_data.lock().get_all_id_state();
+        // Logs are only shown in the gui, so aren't downloaded when running headless, i.e. serving metrics, or printing snapshots
+        let all_ids = if self.config.gui {
+            self.app_data.lock().get_all_id_state()
+        } else {
+            vec![]
+        };
         let all_ids_len = all_ids.len();
         let init = self.init_all_logs(all_ids);
         self.update_all_container_stats();
@@ -305,12 +575,38 @@ impl DockerData {
         self.gui_state.lock().status_del(Status::Init);
     }
 
-    /// Update all cpu_mem, and selected container log (if a log update join_handle isn't currently being executed)
+    /// Spawn a docker info & docker version request, if the host summary is enabled and hasn't been refreshed within the interval
+    fn update_host_summary(&mut self) {
+        if !self.config.show_host_summary
+            || self
+                .host_summary_updated
+                .is_some_and(|i| i.elapsed() < HOST_SUMMARY_INTERVAL)
+        {
+            return;
+        }
+        self.host_summary_updated = Some(std::time::Instant::now());
+        let app_data = Arc::clone(&self.app_data);
+        let docker = Arc::clone(&self.docker);
+        tokio::spawn(async move {
+            if let (Ok(info), Ok(version)) = tokio::join!(docker.info(), docker.version()) {
+                app_data
+                    .lock()
+                    .update_host_summary(HostSummary::from((info, version)));
+            }
+        });
+    }
+
+    /// Update all cpu_mem, and the logs of the selected container, or every merged container (if a log update join_handle isn't currently being executed)
+    /// Logs are only updated when the gui is enabled
     async fn update_everything(&mut self) {
         self.update_all_containers().await;
-        if let Some(container) = self.app_data.lock().get_selected_container() {
-            let last_updated = container.last_updated;
-            let spawn_id = SpawnId::Log(container.id.clone());
+        let log_targets = if self.config.gui {
+            self.app_data.lock().get_log_update_targets()
+        } else {
+            vec![]
+        };
+        for (id, last_updated) in log_targets {
+            let spawn_id = SpawnId::Log(id.clone());
             // Only spawn if not already spawned with a given id/binate pair
             if let std::collections::hash_map::Entry::Vacant(spawns) =
                 self.spawns.lock().entry(spawn_id)
@@ -318,7 +614,7 @@ impl DockerData {
                 spawns.insert(tokio::spawn(Self::update_log(
                     Arc::clone(&self.app_data),
                     Arc::clone(&self.docker),
-                    container.id.clone(),
+                    id,
                     last_updated,
                     Arc::clone(&self.spawns),
                     self.config.show_std_err,
@@ -326,6 +622,7 @@ impl DockerData {
             }
         }
         self.update_all_container_stats();
+        self.update_host_summary();
         self.app_data.lock().sort_containers();
     }
 
@@ -340,6 +637,192 @@ impl DockerData {
             .set_error(AppError::DockerCommand(error), gui_state, Status::Error);
     }
 
+    /// Inspect the image of a container, the config is used to tell which container values are inherited from the image
+    pub async fn image_config(
+        docker: &Docker,
+        inspect: &ContainerInspectResponse,
+    ) -> Option<ImageConfig> {
+        docker
+            .inspect_image(inspect.image.as_deref()?)
+            .await
+            .ok()
+            .and_then(|i| i.config)
+    }
+
+    /// Inspect the given container, and its image, and use the responses to open the recreate form
+    async fn confirm_recreate(&self, id: ContainerId) {
+        match self.docker.inspect_container(id.get(), None).await {
+            Ok(inspect) => {
+                let image = Self::image_config(&self.docker, &inspect).await;
+                self.gui_state
+                    .lock()
+                    .set_recreate_form(Some(RecreateForm::new(id, inspect, image.as_ref())));
+            }
+            Err(_) => Self::set_error(&self.app_data, DockerCommand::Recreate, &self.gui_state),
+        }
+    }
+
+    /// Pull the image, but only if it isn't already available locally
+    async fn pull_image(docker: &Docker, image: &str) -> Result<(), bollard::errors::Error> {
+        if docker.inspect_image(image).await.is_err() {
+            let mut stream = docker.create_image(
+                Some(CreateImageOptions {
+                    from_image: image,
+                    ..Default::default()
+                }),
+                None,
+                None,
+            );
+            while let Some(message) = stream.next().await {
+                message?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Undo a partially completed recreate, by force removing the replacement container, and restoring the name of the original
+    async fn recreate_rollback(docker: &Docker, new_id: &str, id: &str, name: &str) {
+        let options = RemoveContainerOptions {
+            force: true,
+            ..Default::default()
+        };
+        docker.remove_container(new_id, Some(options)).await.ok();
+        docker
+            .rename_container(id, RenameContainerOptions { name })
+            .await
+            .ok();
+    }
+
+    /// Replace a container with a new one created from the recreate form, using the same name
+    /// The original container is renamed, rather than removed, until the replacement has been successfully started,
+    /// if any step fails, the original container is restored
+    async fn recreate(
+        docker: &Docker,
+        form: Option<RecreateForm>,
+    ) -> Result<(), bollard::errors::Error> {
+        let Some(form) = form else {
+            return Ok(());
+        };
+        let (id, name, old_name) = (form.id().get(), form.name(), form.old_name());
+
+        if form.image_changed() {
+            Self::pull_image(docker, &form.image()).await?;
+        }
+
+        docker
+            .rename_container(id, RenameContainerOptions { name: &old_name })
+            .await?;
+
+        let new_id = match docker
+            .create_container(
+                Some(CreateContainerOptions {
+                    name,
+                    platform: None,
+                }),
+                form.to_config(),
+            )
+            .await
+        {
+            Ok(response) => response.id,
+            Err(e) => {
+                docker
+                    .rename_container(id, RenameContainerOptions { name })
+                    .await
+                    .ok();
+                return Err(e);
+            }
+        };
+
+        for (network, endpoint_config) in form.extra_networks() {
+            if let Err(e) = docker
+                .connect_network(
+                    &network,
+                    ConnectNetworkOptions {
+                        container: new_id.as_str(),
+                        endpoint_config,
+                    },
+                )
+                .await
+            {
+                Self::recreate_rollback(docker, &new_id, id, name).await;
+                return Err(e);
+            }
+        }
+
+        let was_running = docker
+            .inspect_container(id, None)
+            .await
+            .ok()
+            .and_then(|i| i.state)
+            .and_then(|i| i.running)
+            .unwrap_or_default();
+
+        if was_running && let Err(e) = docker.stop_container(id, None).await {
+            Self::recreate_rollback(docker, &new_id, id, name).await;
+            return Err(e);
+        }
+
+        if let Err(e) = docker
+            .start_container(&new_id, None::<StartContainerOptions<String>>)
+            .await
+        {
+            Self::recreate_rollback(docker, &new_id, id, name).await;
+            if was_running {
+                docker
+                    .start_container(id, None::<StartContainerOptions<String>>)
+                    .await
+                    .ok();
+            }
+            return Err(e);
+        }
+
+        docker
+            .remove_container(
+                id,
+                Some(RemoveContainerOptions {
+                    v: false,
+                    force: true,
+                    link: false,
+                }),
+            )
+            .await
+    }
+
+    /// Run a single docker command against a container, used by both the gui & the cli subcommands
+    /// The recreate form is only used by the recreate command, and remove_volumes by the delete command
+    pub async fn run_command(
+        docker: &Docker,
+        control: DockerCommand,
+        id: &ContainerId,
+        recreate_form: Option<RecreateForm>,
+        remove_volumes: bool,
+    ) -> Result<(), bollard::errors::Error> {
+        match control {
+            DockerCommand::Delete => {
+                docker
+                    .remove_container(
+                        id.get(),
+                        Some(RemoveContainerOptions {
+                            v: remove_volumes,
+                            force: true,
+                            link: false,
+                        }),
+                    )
+                    .await
+            }
+            DockerCommand::Pause => docker.pause_container(id.get()).await,
+            DockerCommand::Recreate => Self::recreate(docker, recreate_form).await,
+            DockerCommand::Restart => docker.restart_container(id.get(), None).await,
+            DockerCommand::Resume => docker.unpause_container(id.get()).await,
+            DockerCommand::Start => {
+                docker
+                    .start_container(id.get(), None::<StartContainerOptions<String>>)
+                    .await
+            }
+            DockerCommand::Stop => docker.stop_container(id.get(), None).await,
+        }
+    }
+
     /// Execute docker commands (start, stop etc) on it's own tokio thread
     async fn execute_command(&mut self, control: DockerCommand, id: ContainerId) {
         let (app_data, docker, gui_state) = (
@@ -347,34 +830,20 @@ impl DockerData {
             Arc::clone(&self.docker),
             Arc::clone(&self.gui_state),
         );
+        let recreate_form = if control == DockerCommand::Recreate {
+            self.gui_state.lock().take_recreate_form()
+        } else {
+            None
+        };
         tokio::spawn(async move {
             let uuid = Uuid::new_v4();
             GuiState::start_loading_animation(&gui_state, uuid);
-            if match control {
-                DockerCommand::Delete => {
-                    gui_state.lock().set_delete_container(None);
-                    docker
-                        .remove_container(
-                            id.get(),
-                            Some(RemoveContainerOptions {
-                                v: false,
-                                force: true,
-                                link: false,
-                            }),
-                        )
-                        .await
-                }
-                DockerCommand::Pause => docker.pause_container(id.get()).await,
-                DockerCommand::Restart => docker.restart_container(id.get(), None).await,
-                DockerCommand::Resume => docker.unpause_container(id.get()).await,
-                DockerCommand::Start => {
-                    docker
-                        .start_container(id.get(), None::<StartContainerOptions<String>>)
-                        .await
-                }
-                DockerCommand::Stop => docker.stop_container(id.get(), None).await,
+            if control == DockerCommand::Delete {
+                gui_state.lock().set_delete_container(None);
             }
-            .is_err()
+            if Self::run_command(&docker, control, &id, recreate_form, false)
+                .await
+                .is_err()
             {
                 Self::set_error(&app_data, control, &gui_state);
             }
@@ -392,10 +861,12 @@ impl DockerData {
                 DockerMessage::ConfirmDelete(id) => {
                     self.gui_state.lock().set_delete_container(Some(id));
                 }
+                DockerMessage::ConfirmRecreate(id) => self.confirm_recreate(id).await,
                 DockerMessage::Control((command, id)) => self.execute_command(command, id).await,
                 DockerMessage::Exec(docker_tx) => {
                     docker_tx.send(Arc::clone(&self.docker)).ok();
                 }
+                DockerMessage::ToggleRecord => self.toggle_record(),
                 DockerMessage::Update => self.update_everything().await,
             }
         }
@@ -427,15 +898,24 @@ impl DockerData {
     ) {
         let args = app_data.lock().config.clone();
         if app_data.lock().get_error().is_none() {
+            let recorder = args
+                .record_path
+                .clone()
+                .map(|path| StatsRecorder::new(path, args.record_max_mb));
             let mut inner = Self {
                 app_data,
                 config: args,
                 binate: Binate::One,
                 docker: Arc::new(docker),
                 gui_state,
+                host_summary_updated: None,
                 receiver: docker_rx,
+                recorder: Arc::new(Mutex::new(recorder)),
                 spawns: Arc::new(Mutex::new(HashMap::new())),
             };
+            if inner.recorder.lock().is_some() {
+                inner.toggle_record();
+            }
             inner.initialise_container_data().await;
             Self::heartbeat(&inner.config, docker_tx);
             inner.message_handler().await;
@@ -449,7 +929,8 @@ impl DockerData {
 mod tests {
 
     use bollard::container::{
-        BlkioStats, CPUStats, CPUUsage, MemoryStats, PidsStats, Stats, StorageStats, ThrottlingData,
+        BlkioStats, BlkioStatsEntry, CPUStats, CPUUsage, MemoryStats, NetworkStats, PidsStats,
+        Stats, StorageStats, ThrottlingData,
     };
 
     use super::*;
@@ -528,6 +1009,169 @@ mod tests {
         }
     }
 
+    /// Generate a BlkioStatsEntry
+    fn gen_blkio(major: u64, op: &str, value: u64) -> BlkioStatsEntry {
+        BlkioStatsEntry {
+            major,
+            minor: 0,
+            op: op.to_owned(),
+            value,
+        }
+    }
+
+    #[test]
+    /// cgroup v1 block I/O, summed across devices, with the Sync/Async/Total entries ignored
+    fn test_calculate_block_io_cgroup_v1() {
+        let mut stats = gen_stats();
+        stats.read = "2025-01-01T00:00:01.5Z".to_owned();
+        stats.blkio_stats.io_service_bytes_recursive = Some(vec![
+            gen_blkio(8, "Read", 100),
+            gen_blkio(8, "Write", 200),
+            gen_blkio(8, "Sync", 300),
+            gen_blkio(8, "Async", 0),
+            gen_blkio(8, "Total", 300),
+            gen_blkio(9, "Read", 1000),
+            gen_blkio(9, "Write", 2000),
+            gen_blkio(9, "Total", 3000),
+        ]);
+        let result = DockerData::calculate_block_io(&stats);
+        assert_eq!(result, (1100, 2200));
+        assert_eq!(DockerData::stats_timestamp_ms(&stats), 1_735_689_601_500);
+    }
+
+    #[test]
+    /// cgroup v2 block I/O, summed across devices
+    fn test_calculate_block_io_cgroup_v2() {
+        let mut stats = gen_stats();
+        stats.blkio_stats.io_service_bytes_recursive = Some(vec![
+            gen_blkio(8, "read", 100),
+            gen_blkio(8, "write", 200),
+            gen_blkio(9, "read", 50),
+            gen_blkio(9, "write", 25),
+        ]);
+        let result = DockerData::calculate_block_io(&stats);
+        assert_eq!(result, (150, 225));
+
+        stats.blkio_stats.io_service_bytes_recursive = None;
+        let result = DockerData::calculate_block_io(&stats);
+        assert_eq!(result, (0, 0));
+    }
+
+    #[test]
+    /// Throttled percentage calculated from the change in periods, per core usage from the change in each core, relative to the system
+    fn test_calculate_cpu_detail() {
+        let mut stats = gen_stats();
+        stats.precpu_stats.throttling_data = ThrottlingData {
+            periods: 100,
+            throttled_periods: 10,
+            throttled_time: 1000,
+        };
+        stats.cpu_stats.throttling_data = ThrottlingData {
+            periods: 200,
+            throttled_periods: 35,
+            throttled_time: 5000,
+        };
+        stats.precpu_stats.cpu_usage.percpu_usage = Some(vec![100, 100]);
+        stats.cpu_stats.cpu_usage.percpu_usage = Some(vec![200, 150]);
+        stats.precpu_stats.system_cpu_usage = Some(1000);
+        stats.cpu_stats.system_cpu_usage = Some(1400);
+
+        let (throttled, throttled_time, per_core) = DockerData::calculate_cpu_detail(&stats);
+        assert_eq!(throttled, 25.0);
+        assert_eq!(throttled_time, 5000);
+        assert_eq!(per_core, vec![50.0, 25.0]);
+
+        // cgroup v2, no per core stats, and no periods
+        stats.cpu_stats.cpu_usage.percpu_usage = None;
+        stats.cpu_stats.throttling_data.periods = 100;
+        let (throttled, _, per_core) = DockerData::calculate_cpu_detail(&stats);
+        assert_eq!(throttled, 0.0);
+        assert!(per_core.is_empty());
+    }
+
+    #[test]
+    /// No memory detail when docker doesn't report the detailed memory stats
+    fn test_calculate_memory_detail_none() {
+        let stats = gen_stats();
+        assert!(DockerData::calculate_memory_detail(&stats.memory_stats).is_none());
+    }
+
+    #[test]
+    /// Cpu limit is taken from nano_cpus first, then from the quota & period, with no limit being None
+    fn test_calculate_cpu_quota() {
+        let gen_host_config = |nano_cpus, cpu_quota, cpu_period| HostConfig {
+            nano_cpus,
+            cpu_quota,
+            cpu_period,
+            ..HostConfig::default()
+        };
+        for (input, expected) in [
+            (gen_host_config(None, None, None), None),
+            (gen_host_config(Some(0), Some(-1), Some(0)), None),
+            (
+                gen_host_config(Some(1_500_000_000), None, None),
+                Some(1_500_000_000),
+            ),
+            (
+                gen_host_config(None, Some(50_000), Some(100_000)),
+                Some(500_000_000),
+            ),
+            (
+                gen_host_config(None, Some(200_000), None),
+                Some(2_000_000_000),
+            ),
+            (
+                gen_host_config(Some(2_000_000_000), Some(50_000), Some(100_000)),
+                Some(2_000_000_000),
+            ),
+        ] {
+            assert_eq!(DockerData::calculate_cpu_quota(&input), expected);
+        }
+    }
+
+    #[test]
+    /// Every network interface is collected, sorted by name, and summed for the rx & tx totals
+    fn test_calculate_networks() {
+        let mut stats = gen_stats();
+        assert!(DockerData::calculate_networks(&stats).is_empty());
+
+        let gen_network = |rx_bytes, tx_bytes, rx_errors, tx_dropped| NetworkStats {
+            rx_dropped: 0,
+            rx_bytes,
+            rx_errors,
+            tx_packets: 0,
+            tx_dropped,
+            rx_packets: 0,
+            tx_errors: 0,
+            tx_bytes,
+        };
+        stats.networks = Some(HashMap::from([
+            ("eth1".to_owned(), gen_network(300, 400, 0, 2)),
+            ("eth0".to_owned(), gen_network(100, 200, 1, 0)),
+        ]));
+        let result = DockerData::calculate_networks(&stats);
+        assert_eq!(
+            result,
+            vec![
+                NetworkInterface {
+                    name: "eth0".to_owned(),
+                    rx_bytes: 100,
+                    tx_bytes: 200,
+                    rx_errors: 1,
+                    ..NetworkInterface::default()
+                },
+                NetworkInterface {
+                    name: "eth1".to_owned(),
+                    rx_bytes: 300,
+                    tx_bytes: 400,
+                    tx_dropped: 2,
+                    ..NetworkInterface::default()
+                },
+            ]
+        );
+        assert_eq!(NetworkInterface::sum(&result), (400, 600));
+    }
+
     #[test]
     fn test_calculate_usage_50() {
         let mut stats = gen_stats();
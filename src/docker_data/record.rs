@@ -0,0 +1,315 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+
+/// Number of rotated record files that are kept, older files are overwritten
+const MAX_ROTATED: u8 = 5;
+
+/// The CSV header, the field order matches StatsSample
+const CSV_HEADER: &str =
+    "timestamp,id,name,cpu,memory,memory_limit,rx,tx,block_read,block_write,pids\n";
+
+/// The record file format, based on the file extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RecordFormat {
+    Csv,
+    JsonLines,
+}
+
+impl RecordFormat {
+    /// JSON Lines if the extension is jsonl, ndjson, or json, else CSV
+    fn from_path(path: &Path) -> Self {
+        match path
+            .extension()
+            .and_then(|i| i.to_str())
+            .map(str::to_lowercase)
+            .as_deref()
+        {
+            Some("jsonl" | "ndjson" | "json") => Self::JsonLines,
+            _ => Self::Csv,
+        }
+    }
+}
+
+/// A single stats sample of a single container
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatsSample {
+    pub timestamp: String,
+    pub id: String,
+    pub name: String,
+    pub cpu: Option<f64>,
+    pub memory: Option<u64>,
+    pub memory_limit: u64,
+    pub rx: u64,
+    pub tx: u64,
+    pub block_read: u64,
+    pub block_write: u64,
+    pub pids: u64,
+}
+
+/// Quote a CSV field if it contains a comma, quote, or newline
+pub fn csv_quote(input: &str) -> String {
+    if input.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", input.replace('"', "\"\""))
+    } else {
+        input.to_owned()
+    }
+}
+
+impl StatsSample {
+    /// Format as a single line, including the trailing newline
+    fn to_line(&self, format: RecordFormat) -> String {
+        match format {
+            RecordFormat::Csv => {
+                let option = |x: Option<String>| x.unwrap_or_default();
+                format!(
+                    "{},{},{},{},{},{},{},{},{},{},{}\n",
+                    self.timestamp,
+                    csv_quote(&self.id),
+                    csv_quote(&self.name),
+                    option(self.cpu.map(|i| format!("{i:.2}"))),
+                    option(self.memory.map(|i| i.to_string())),
+                    self.memory_limit,
+                    self.rx,
+                    self.tx,
+                    self.block_read,
+                    self.block_write,
+                    self.pids
+                )
+            }
+            RecordFormat::JsonLines => serde_json::to_string(self)
+                .map(|i| format!("{i}\n"))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Append stats samples to a file, the file is rotated once it reaches the max size
+#[derive(Debug)]
+pub struct StatsRecorder {
+    file: Option<File>,
+    format: RecordFormat,
+    max_bytes: u64,
+    path: PathBuf,
+    written: u64,
+}
+
+impl StatsRecorder {
+    pub fn new(path: PathBuf, max_mb: u32) -> Self {
+        Self {
+            file: None,
+            format: RecordFormat::from_path(&path),
+            max_bytes: u64::from(max_mb) * 1_000_000,
+            path,
+            written: 0,
+        }
+    }
+
+    pub const fn is_recording(&self) -> bool {
+        self.file.is_some()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Open the record file, appending to it if it already exists, a CSV header is written to new files
+    pub fn start(&mut self) -> std::io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.written = file.metadata()?.len();
+        if self.written == 0 && self.format == RecordFormat::Csv {
+            file.write_all(CSV_HEADER.as_bytes())?;
+            self.written = CSV_HEADER.len() as u64;
+        }
+        self.file = Some(file);
+        Ok(())
+    }
+
+    pub fn stop(&mut self) {
+        self.file = None;
+    }
+
+    /// The path of a rotated file, `stats.csv` becomes `stats.1.csv`
+    fn rotated_path(&self, index: u8) -> PathBuf {
+        let stem = self
+            .path
+            .file_stem()
+            .map_or_else(String::new, |i| i.to_string_lossy().to_string());
+        let name = self.path.extension().map_or_else(
+            || format!("{stem}.{index}"),
+            |ext| format!("{stem}.{index}.{}", ext.to_string_lossy()),
+        );
+        self.path.with_file_name(name)
+    }
+
+    /// Shift each rotated file up by one, move the current file to the first rotated file, then start a new file
+    fn rotate(&mut self) -> std::io::Result<()> {
+        self.stop();
+        for index in (1..MAX_ROTATED).rev() {
+            let from = self.rotated_path(index);
+            if from.exists() {
+                std::fs::rename(from, self.rotated_path(index + 1))?;
+            }
+        }
+        std::fs::rename(&self.path, self.rotated_path(1))?;
+        self.start()
+    }
+
+    /// Append a sample, does nothing if not currently recording
+    pub fn write(&mut self, sample: &StatsSample) -> std::io::Result<()> {
+        if !self.is_recording() {
+            return Ok(());
+        }
+        let line = sample.to_line(self.format);
+        if self.written + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        if let Some(file) = self.file.as_mut() {
+            file.write_all(line.as_bytes())?;
+            self.written += line.len() as u64;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{CSV_HEADER, RecordFormat, StatsRecorder, StatsSample};
+
+    fn gen_sample(name: &str) -> StatsSample {
+        StatsSample {
+            timestamp: "2025-01-01T00:00:00Z".to_owned(),
+            id: "abc".to_owned(),
+            name: name.to_owned(),
+            cpu: Some(12.345),
+            memory: Some(1000),
+            memory_limit: 2000,
+            rx: 10,
+            tx: 20,
+            block_read: 30,
+            block_write: 40,
+            pids: 5,
+        }
+    }
+
+    /// A unique, empty, directory for each test
+    fn gen_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("oxker_record_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    /// The format is chosen by the file extension
+    fn test_record_format_from_path() {
+        for (path, expected) in [
+            ("stats.csv", RecordFormat::Csv),
+            ("stats", RecordFormat::Csv),
+            ("stats.jsonl", RecordFormat::JsonLines),
+            ("stats.NDJSON", RecordFormat::JsonLines),
+            ("stats.json", RecordFormat::JsonLines),
+        ] {
+            assert_eq!(RecordFormat::from_path(&PathBuf::from(path)), expected);
+        }
+    }
+
+    #[test]
+    /// Samples are formatted as a single line, csv fields are quoted when needed, missing values are empty or null
+    fn test_record_sample_to_line() {
+        let mut sample = gen_sample("name, with \"quotes\"");
+        assert_eq!(
+            sample.to_line(RecordFormat::Csv),
+            "2025-01-01T00:00:00Z,abc,\"name, with \"\"quotes\"\"\",12.35,1000,2000,10,20,30,40,5\n"
+        );
+
+        sample.name = "name".to_owned();
+        sample.cpu = None;
+        sample.memory = None;
+        assert_eq!(
+            sample.to_line(RecordFormat::Csv),
+            "2025-01-01T00:00:00Z,abc,name,,,2000,10,20,30,40,5\n"
+        );
+        assert_eq!(
+            sample.to_line(RecordFormat::JsonLines),
+            "{\"timestamp\":\"2025-01-01T00:00:00Z\",\"id\":\"abc\",\"name\":\"name\",\"cpu\":null,\"memory\":null,\"memory_limit\":2000,\"rx\":10,\"tx\":20,\"block_read\":30,\"block_write\":40,\"pids\":5}\n"
+        );
+    }
+
+    #[test]
+    /// Samples are only written whilst recording, a csv header is written to a new file, and restarting appends to the existing file
+    fn test_record_start_stop() {
+        let dir = gen_dir();
+        let path = dir.join("stats.csv");
+        let mut recorder = StatsRecorder::new(path.clone(), 1);
+
+        recorder.write(&gen_sample("one")).unwrap();
+        assert!(!path.exists());
+
+        recorder.start().unwrap();
+        assert!(recorder.is_recording());
+        recorder.write(&gen_sample("one")).unwrap();
+        recorder.stop();
+        recorder.write(&gen_sample("two")).unwrap();
+        recorder.start().unwrap();
+        recorder.write(&gen_sample("three")).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(format!("{}\n", lines[0]), CSV_HEADER);
+        assert!(lines[1].contains(",one,"));
+        assert!(lines[2].contains(",three,"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    /// Once the max size is reached, the file is rotated, and only the set number of rotated files are kept
+    fn test_record_rotate() {
+        let dir = gen_dir();
+        let path = dir.join("stats.jsonl");
+        let mut recorder = StatsRecorder::new(path.clone(), 1);
+        recorder.max_bytes = 500;
+        recorder.start().unwrap();
+
+        for i in 0..40 {
+            recorder.write(&gen_sample(&format!("{i:02}"))).unwrap();
+        }
+
+        let mut files = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|i| i.unwrap().file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(
+            files,
+            [
+                "stats.1.jsonl",
+                "stats.2.jsonl",
+                "stats.3.jsonl",
+                "stats.4.jsonl",
+                "stats.5.jsonl",
+                "stats.jsonl"
+            ]
+        );
+        for file in &files {
+            assert!(std::fs::metadata(dir.join(file)).unwrap().len() <= 500);
+        }
+        // The newest sample is in the current file
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .contains("\"name\":\"39\"")
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,652 @@
+use std::{collections::HashMap, fmt, sync::Arc};
+
+use bollard::{
+    container::{Config, NetworkingConfig},
+    service::{
+        ContainerInspectResponse, EndpointSettings, HostConfig, ImageConfig, MountPoint,
+        MountPointTypeEnum, PortBinding, PortMap,
+    },
+};
+
+use super::export::strip_image_config;
+use crate::app_data::{ContainerId, StatefulList};
+
+/// Suffix applied to the original container whilst the replacement is being created
+pub const OLD_SUFFIX: &str = "_oxker_old";
+
+/// The editable sections of the recreate form
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum RecreateField {
+    Image,
+    Env,
+    Port,
+    Mount,
+}
+
+impl RecreateField {
+    /// Image is a single value, every other field can have any number of lines
+    const fn is_multi(self) -> bool {
+        !matches!(self, Self::Image)
+    }
+}
+
+impl fmt::Display for RecreateField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let disp = match self {
+            Self::Image => "image",
+            Self::Env => "env",
+            Self::Port => "port",
+            Self::Mount => "mount",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// A single parsed port line, `[ip:]host:container[/protocol]`, or just `container[/protocol]` to only expose the port
+#[derive(Debug, Clone)]
+struct PortLine {
+    key: String,
+    binding: Option<PortBinding>,
+}
+
+impl PortLine {
+    fn parse(input: &str) -> Option<Self> {
+        let (ports, protocol) = input.split_once('/').unwrap_or((input, "tcp"));
+        if !["tcp", "udp", "sctp"].contains(&protocol) {
+            return None;
+        }
+        let mut split = ports.rsplitn(3, ':');
+        let container = split.next()?.parse::<u16>().ok()?;
+        let key = format!("{container}/{protocol}");
+
+        let binding = match (split.next(), split.next()) {
+            (None, _) => None,
+            (Some(host), ip) => {
+                if !host.is_empty() {
+                    host.parse::<u16>().ok()?;
+                }
+                Some(PortBinding {
+                    host_ip: ip.map(|i| i.trim_matches(['[', ']']).to_owned()),
+                    host_port: Some(host.to_owned()),
+                })
+            }
+        };
+        Some(Self { key, binding })
+    }
+}
+
+/// Convert the port bindings of a host config into `[ip:]host:container[/protocol]` lines, sorted
+pub fn port_lines(host_config: Option<&HostConfig>) -> Vec<String> {
+    let mut ports = host_config
+        .and_then(|i| i.port_bindings.as_ref())
+        .into_iter()
+        .flatten()
+        .flat_map(|(key, bindings)| {
+            let (port, protocol) = key.split_once('/').unwrap_or((key, "tcp"));
+            let suffix = if protocol == "tcp" {
+                port.to_owned()
+            } else {
+                format!("{port}/{protocol}")
+            };
+            bindings
+                .iter()
+                .flatten()
+                .map(move |binding| {
+                    let host = binding.host_port.as_deref().unwrap_or_default();
+                    match binding.host_ip.as_deref() {
+                        Some(ip) if !ip.is_empty() => {
+                            if ip.contains(':') {
+                                format!("[{ip}]:{host}:{suffix}")
+                            } else {
+                                format!("{ip}:{host}:{suffix}")
+                            }
+                        }
+                        _ => format!("{host}:{suffix}"),
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    ports.sort();
+    ports
+}
+
+/// Convert volume and bind mounts into `source:destination[:ro]` lines, all other mount types are ignored
+pub fn mount_lines(mounts: Option<&[MountPoint]>) -> Vec<String> {
+    mounts
+        .into_iter()
+        .flatten()
+        .filter_map(|mount| {
+            let source = match mount.typ {
+                Some(MountPointTypeEnum::VOLUME) => mount.name.as_ref(),
+                Some(MountPointTypeEnum::BIND) => mount.source.as_ref(),
+                _ => None,
+            }?;
+            let mut line = format!("{source}:{}", mount.destination.as_ref()?);
+            if mount.rw == Some(false) {
+                line.push_str(":ro");
+            }
+            Some(line)
+        })
+        .collect()
+}
+
+/// The editable config of a container, generated from `inspect_container`, which is used to create a replacement container
+/// Values inherited from the original image are removed, so that a replacement using a different image gets that image's values
+#[derive(Debug, Clone)]
+pub struct RecreateForm {
+    id: ContainerId,
+    name: String,
+    inspect: Arc<ContainerInspectResponse>,
+    pub lines: StatefulList<(RecreateField, String)>,
+}
+
+impl RecreateForm {
+    /// Generate the editable lines from the inspect response, each multi-line field gets an empty line appended, so that new values can be added
+    pub fn new(
+        id: ContainerId,
+        mut inspect: ContainerInspectResponse,
+        image: Option<&ImageConfig>,
+    ) -> Self {
+        inspect.config = inspect
+            .config
+            .map(|config| strip_image_config(config, image));
+        let name = inspect
+            .name
+            .as_deref()
+            .unwrap_or_else(|| id.get())
+            .trim_start_matches('/')
+            .to_owned();
+
+        let mut lines = vec![(
+            RecreateField::Image,
+            inspect
+                .config
+                .as_ref()
+                .and_then(|i| i.image.clone())
+                .unwrap_or_default(),
+        )];
+
+        for env in inspect
+            .config
+            .as_ref()
+            .and_then(|i| i.env.as_ref())
+            .into_iter()
+            .flatten()
+        {
+            lines.push((RecreateField::Env, env.clone()));
+        }
+        lines.push((RecreateField::Env, String::new()));
+
+        for port in port_lines(inspect.host_config.as_ref()) {
+            lines.push((RecreateField::Port, port));
+        }
+        lines.push((RecreateField::Port, String::new()));
+
+        for mount in mount_lines(inspect.mounts.as_deref()) {
+            lines.push((RecreateField::Mount, mount));
+        }
+        lines.push((RecreateField::Mount, String::new()));
+
+        let mut lines = StatefulList::new(lines);
+        lines.start();
+        Self {
+            id,
+            name,
+            inspect: Arc::new(inspect),
+            lines,
+        }
+    }
+
+    pub const fn id(&self) -> &ContainerId {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The name the original container is given whilst the replacement is created
+    pub fn old_name(&self) -> String {
+        format!("{}{OLD_SUFFIX}", self.name)
+    }
+
+    /// Get all non-empty values for a given field
+    fn values(&self, field: RecreateField) -> impl Iterator<Item = &str> {
+        self.lines
+            .items
+            .iter()
+            .filter(move |(f, value)| *f == field && !value.trim().is_empty())
+            .map(|(_, value)| value.trim())
+    }
+
+    /// The image the replacement container should be created from
+    pub fn image(&self) -> String {
+        self.values(RecreateField::Image)
+            .next()
+            .unwrap_or_default()
+            .to_owned()
+    }
+
+    /// Check if the image has been edited
+    pub fn image_changed(&self) -> bool {
+        self.inspect
+            .config
+            .as_ref()
+            .and_then(|i| i.image.as_deref())
+            != Some(self.image().as_str())
+    }
+
+    /// Check if a given line can be parsed into the docker config
+    pub fn line_valid(field: RecreateField, value: &str) -> bool {
+        let value = value.trim();
+        match field {
+            RecreateField::Image => !value.is_empty(),
+            RecreateField::Env => value.is_empty() || value.split_once('=').is_some(),
+            RecreateField::Port => value.is_empty() || PortLine::parse(value).is_some(),
+            RecreateField::Mount => {
+                value.is_empty()
+                    || value
+                        .split_once(':')
+                        .is_some_and(|(source, dest)| !source.is_empty() && !dest.is_empty())
+            }
+        }
+    }
+
+    /// Only valid forms can be used to create a replacement container
+    pub fn is_valid(&self) -> bool {
+        self.lines
+            .items
+            .iter()
+            .all(|(field, value)| Self::line_valid(*field, value))
+    }
+
+    /// Get the index of the currently selected line, and the field
+    fn selected(&self) -> Option<(usize, RecreateField)> {
+        self.lines
+            .state
+            .selected()
+            .and_then(|i| self.lines.items.get(i).map(|(field, _)| (i, *field)))
+    }
+
+    /// Add a char to the selected line, if the line is the last of its field, then insert a new empty line below it
+    pub fn push(&mut self, c: char) {
+        if let Some((index, field)) = self.selected() {
+            let was_empty = self.lines.items[index].1.is_empty();
+            self.lines.items[index].1.push(c);
+            let is_last = self
+                .lines
+                .items
+                .get(index + 1)
+                .is_none_or(|(next, _)| *next != field);
+            if field.is_multi() && was_empty && is_last {
+                self.lines.items.insert(index + 1, (field, String::new()));
+            }
+        }
+    }
+
+    /// Remove the last char of the selected line
+    pub fn pop(&mut self) {
+        if let Some((index, _)) = self.selected() {
+            self.lines.items[index].1.pop();
+        }
+    }
+
+    /// Convert the form into a config that can be used to create a new container
+    /// Mounts are all converted into binds, and every edited field replaces the original value
+    pub fn to_config(&self) -> Config<String> {
+        let mut config = self
+            .inspect
+            .config
+            .clone()
+            .map(Config::from)
+            .unwrap_or_default();
+
+        // The default hostname is the short id of the original container, so don't reuse it
+        if config
+            .hostname
+            .as_ref()
+            .is_some_and(|hostname| self.id.get().starts_with(hostname.as_str()))
+        {
+            config.hostname = None;
+        }
+
+        config.image = Some(self.image());
+        config.env = Some(
+            self.values(RecreateField::Env)
+                .map(ToOwned::to_owned)
+                .collect(),
+        );
+
+        let mut port_bindings = PortMap::new();
+        let mut exposed_ports = config.exposed_ports.take().unwrap_or_default();
+        for port in self.values(RecreateField::Port).filter_map(PortLine::parse) {
+            // Docker uses a map with an empty object as the value, as a set
+            #[allow(clippy::zero_sized_map_values)]
+            exposed_ports.insert(port.key.clone(), HashMap::new());
+            let entry = port_bindings.entry(port.key).or_insert(None);
+            if let Some(binding) = port.binding {
+                entry.get_or_insert_with(Vec::new).push(binding);
+            }
+        }
+        config.exposed_ports = Some(exposed_ports);
+
+        let mut host_config = self.inspect.host_config.clone().unwrap_or_default();
+        host_config.port_bindings = Some(port_bindings);
+        host_config.binds = Some(
+            self.values(RecreateField::Mount)
+                .map(ToOwned::to_owned)
+                .collect(),
+        );
+        host_config.mounts = None;
+
+        config.networking_config =
+            self.primary_network()
+                .map(|(name, endpoint)| NetworkingConfig {
+                    endpoints_config: HashMap::from([(name, endpoint)]),
+                });
+        config.host_config = Some(host_config);
+        config
+    }
+
+    /// Strip the runtime information out of the endpoint settings, so that they can be reused on a new container
+    fn endpoint(&self, endpoint: &EndpointSettings) -> EndpointSettings {
+        EndpointSettings {
+            ipam_config: endpoint.ipam_config.clone(),
+            links: endpoint.links.clone(),
+            aliases: endpoint.aliases.as_ref().map(|aliases| {
+                aliases
+                    .iter()
+                    .filter(|alias| !self.id.get().starts_with(alias.as_str()))
+                    .cloned()
+                    .collect()
+            }),
+            driver_opts: endpoint.driver_opts.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Get the network name from the host_config network mode, "default" is the bridge network
+    fn network_mode(&self) -> Option<&str> {
+        self.inspect
+            .host_config
+            .as_ref()
+            .and_then(|i| i.network_mode.as_deref())
+            .map(|mode| if mode == "default" { "bridge" } else { mode })
+    }
+
+    /// Get all networks that the original container was connected to
+    fn networks(&self) -> impl Iterator<Item = (&String, &EndpointSettings)> {
+        self.inspect
+            .network_settings
+            .as_ref()
+            .and_then(|i| i.networks.as_ref())
+            .into_iter()
+            .flatten()
+    }
+
+    /// The network that is used when creating the container
+    fn primary_network(&self) -> Option<(String, EndpointSettings)> {
+        let mode = self.network_mode()?;
+        self.networks()
+            .find(|(name, _)| name.as_str() == mode)
+            .map(|(name, endpoint)| (name.clone(), self.endpoint(endpoint)))
+    }
+
+    /// Networks that need to be connected to the new container after it has been created
+    pub fn extra_networks(&self) -> Vec<(String, EndpointSettings)> {
+        let mode = self.network_mode();
+        let mut output = self
+            .networks()
+            .filter(|(name, _)| Some(name.as_str()) != mode)
+            .map(|(name, endpoint)| (name.clone(), self.endpoint(endpoint)))
+            .collect::<Vec<_>>();
+        output.sort_by(|a, b| a.0.cmp(&b.0));
+        output
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{
+        ContainerConfig, ContainerInspectResponse, EndpointSettings, HostConfig, ImageConfig,
+        MountPoint, MountPointTypeEnum, NetworkSettings, PortBinding,
+    };
+
+    use super::{PortLine, RecreateField, RecreateForm};
+    use crate::app_data::ContainerId;
+
+    fn gen_inspect() -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            name: Some("/container_1".to_owned()),
+            config: Some(ContainerConfig {
+                hostname: Some("1234567890ab".to_owned()),
+                image: Some("image_1:1.0".to_owned()),
+                env: Some(vec!["A=1".to_owned(), "B=2".to_owned()]),
+                ..Default::default()
+            }),
+            host_config: Some(HostConfig {
+                network_mode: Some("network_1".to_owned()),
+                port_bindings: Some(HashMap::from([(
+                    "80/tcp".to_owned(),
+                    Some(vec![PortBinding {
+                        host_ip: Some(String::new()),
+                        host_port: Some("8080".to_owned()),
+                    }]),
+                )])),
+                ..Default::default()
+            }),
+            mounts: Some(vec![
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::VOLUME),
+                    name: Some("volume_1".to_owned()),
+                    source: Some("/var/lib/docker/volumes/volume_1/_data".to_owned()),
+                    destination: Some("/data".to_owned()),
+                    rw: Some(true),
+                    ..Default::default()
+                },
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::BIND),
+                    source: Some("/etc/config".to_owned()),
+                    destination: Some("/config".to_owned()),
+                    rw: Some(false),
+                    ..Default::default()
+                },
+            ]),
+            network_settings: Some(NetworkSettings {
+                networks: Some(HashMap::from([
+                    (
+                        "network_1".to_owned(),
+                        EndpointSettings {
+                            aliases: Some(vec!["1234567890ab".to_owned(), "alias".to_owned()]),
+                            ip_address: Some("172.17.0.2".to_owned()),
+                            ..Default::default()
+                        },
+                    ),
+                    ("network_2".to_owned(), EndpointSettings::default()),
+                ])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn gen_form() -> RecreateForm {
+        RecreateForm::new(ContainerId::from("1234567890abcdef"), gen_inspect(), None)
+    }
+
+    #[test]
+    /// Inspect response is converted into editable lines, with an empty line at the end of each multi-line field
+    fn test_recreate_form_new() {
+        let form = gen_form();
+        assert_eq!(form.name(), "container_1");
+        assert_eq!(form.old_name(), "container_1_oxker_old");
+        assert_eq!(
+            form.lines.items,
+            vec![
+                (RecreateField::Image, "image_1:1.0".to_owned()),
+                (RecreateField::Env, "A=1".to_owned()),
+                (RecreateField::Env, "B=2".to_owned()),
+                (RecreateField::Env, String::new()),
+                (RecreateField::Port, "8080:80".to_owned()),
+                (RecreateField::Port, String::new()),
+                (RecreateField::Mount, "volume_1:/data".to_owned()),
+                (RecreateField::Mount, "/etc/config:/config:ro".to_owned()),
+                (RecreateField::Mount, String::new()),
+            ]
+        );
+        assert_eq!(form.lines.state.selected(), Some(0));
+        assert!(form.is_valid());
+        assert!(!form.image_changed());
+    }
+
+    #[test]
+    /// Typing into the last line of a field inserts a new empty line
+    fn test_recreate_form_push_pop() {
+        let mut form = gen_form();
+        form.lines.state.select(Some(3));
+        form.push('C');
+        assert_eq!(form.lines.items[3], (RecreateField::Env, "C".to_owned()));
+        assert_eq!(form.lines.items[4], (RecreateField::Env, String::new()));
+        assert!(!form.is_valid());
+        form.push('=');
+        form.push('3');
+        assert!(form.is_valid());
+        form.pop();
+        assert_eq!(form.lines.items[3], (RecreateField::Env, "C=".to_owned()));
+        assert_eq!(form.lines.items.len(), 10);
+
+        // Image line never gets a second line
+        form.lines.start();
+        form.pop();
+        form.pop();
+        form.pop();
+        form.push('2');
+        assert_eq!(
+            form.lines.items[0],
+            (RecreateField::Image, "image_1:2".to_owned())
+        );
+        assert_eq!(form.lines.items[1].0, RecreateField::Env);
+        assert!(form.image_changed());
+    }
+
+    #[test]
+    /// Port lines are parsed into a key and an optional binding
+    fn test_recreate_port_parse() {
+        let result = PortLine::parse("80").unwrap();
+        assert_eq!(result.key, "80/tcp");
+        assert!(result.binding.is_none());
+
+        let result = PortLine::parse("127.0.0.1:8080:80/udp").unwrap();
+        assert_eq!(result.key, "80/udp");
+        let binding = result.binding.unwrap();
+        assert_eq!(binding.host_ip, Some("127.0.0.1".to_owned()));
+        assert_eq!(binding.host_port, Some("8080".to_owned()));
+
+        let result = PortLine::parse("[::1]:8080:80").unwrap();
+        assert_eq!(result.binding.unwrap().host_ip, Some("::1".to_owned()));
+
+        assert!(PortLine::parse("8080:abc").is_none());
+        assert!(PortLine::parse("abc:80").is_none());
+        assert!(PortLine::parse("80/xyz").is_none());
+    }
+
+    #[test]
+    /// Edited lines replace original config, hostname is removed, aliases are cleaned, and extra networks are returned
+    fn test_recreate_form_to_config() {
+        let mut form = gen_form();
+        form.lines.items[1].1 = String::new();
+        form.lines.items[4].1 = "9090:90".to_owned();
+        let config = form.to_config();
+
+        assert_eq!(config.image, Some("image_1:1.0".to_owned()));
+        assert!(config.hostname.is_none());
+        assert_eq!(config.env, Some(vec!["B=2".to_owned()]));
+        assert!(config.exposed_ports.unwrap().contains_key("90/tcp"));
+
+        let host_config = config.host_config.unwrap();
+        let bindings = host_config.port_bindings.unwrap();
+        assert_eq!(bindings.len(), 1);
+        assert_eq!(
+            bindings.get("90/tcp").unwrap().as_ref().unwrap()[0].host_port,
+            Some("9090".to_owned())
+        );
+        assert_eq!(
+            host_config.binds,
+            Some(vec![
+                "volume_1:/data".to_owned(),
+                "/etc/config:/config:ro".to_owned()
+            ])
+        );
+        assert!(host_config.mounts.is_none());
+
+        let endpoints = config.networking_config.unwrap().endpoints_config;
+        let endpoint = endpoints.get("network_1").unwrap();
+        assert_eq!(endpoint.aliases, Some(vec!["alias".to_owned()]));
+        assert!(endpoint.ip_address.is_none());
+
+        let extra = form.extra_networks();
+        assert_eq!(extra.len(), 1);
+        assert_eq!(extra[0].0, "network_2");
+    }
+
+    #[test]
+    /// Values inherited from the original image aren't shown in the form, or used in the config, so a new image tag gets its own values
+    fn test_recreate_form_image_values_removed() {
+        let mut inspect = gen_inspect();
+        inspect.config = Some(ContainerConfig {
+            image: Some("image_1:1.0".to_owned()),
+            env: Some(vec![
+                "PATH=/usr/local/bin".to_owned(),
+                "IMAGE_VERSION=1.0".to_owned(),
+                "A=1".to_owned(),
+            ]),
+            cmd: Some(vec!["serve".to_owned()]),
+            entrypoint: Some(vec!["/entrypoint.sh".to_owned()]),
+            working_dir: Some("/app".to_owned()),
+            labels: Some(HashMap::from([
+                ("version".to_owned(), "1.0".to_owned()),
+                ("custom".to_owned(), "label".to_owned()),
+            ])),
+            ..Default::default()
+        });
+        let image = ImageConfig {
+            env: Some(vec![
+                "PATH=/usr/local/bin".to_owned(),
+                "IMAGE_VERSION=1.0".to_owned(),
+            ]),
+            cmd: Some(vec!["serve".to_owned()]),
+            entrypoint: Some(vec!["/entrypoint.sh".to_owned()]),
+            working_dir: Some("/app".to_owned()),
+            labels: Some(HashMap::from([("version".to_owned(), "1.0".to_owned())])),
+            ..Default::default()
+        };
+
+        let mut form =
+            RecreateForm::new(ContainerId::from("1234567890abcdef"), inspect, Some(&image));
+        assert_eq!(
+            form.lines.items[..3],
+            [
+                (RecreateField::Image, "image_1:1.0".to_owned()),
+                (RecreateField::Env, "A=1".to_owned()),
+                (RecreateField::Env, String::new()),
+            ]
+        );
+
+        form.lines.items[0].1 = "image_1:2.0".to_owned();
+        assert!(form.image_changed());
+        let config = form.to_config();
+        assert_eq!(config.image, Some("image_1:2.0".to_owned()));
+        assert_eq!(config.env, Some(vec!["A=1".to_owned()]));
+        assert!(config.cmd.is_none());
+        assert!(config.entrypoint.is_none());
+        assert!(config.working_dir.is_none());
+        assert_eq!(
+            config.labels,
+            Some(HashMap::from([("custom".to_owned(), "label".to_owned())]))
+        );
+    }
+}
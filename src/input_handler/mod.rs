@@ -20,10 +20,10 @@ use uuid::Uuid;
 
 mod message;
 use crate::{
-    app_data::{AppData, DockerCommand, Header},
+    app_data::{AppData, DockerCommand, Header, LogFilter},
     app_error::AppError,
     config,
-    docker_data::DockerMessage,
+    docker_data::{ContainerExport, DockerData, DockerMessage, RecreateForm},
     exec::{ExecMode, tty_readable},
     ui::{DeleteButton, GuiState, SelectablePanel, Status, Ui},
 };
@@ -74,10 +74,17 @@ impl InputHandler {
 
                     if contains(Status::DeleteConfirm) {
                         self.button_intersect(mouse_event).await;
-                    } else if !contains(Status::Error)
-                        | !contains(Status::Help)
-                        | !contains(Status::DeleteConfirm)
-                        | !contains(Status::Filter)
+                    } else if !contains(Status::Recreate)
+                        && !contains(Status::Export)
+                        && !contains(Status::LogDetail)
+                        && !contains(Status::CpuDetail)
+                        && !contains(Status::MemDetail)
+                        && (!contains(Status::Error)
+                            | !contains(Status::Help)
+                            | !contains(Status::DeleteConfirm)
+                            | !contains(Status::Filter)
+                            | !contains(Status::LogFilter)
+                            | !contains(Status::LogSearch))
                     {
                         self.mouse_press(mouse_event);
                     }
@@ -145,6 +152,22 @@ impl InputHandler {
         }
     }
 
+    /// Zoom the cpu & memory charts, and show the new time window in the info box
+    fn chart_zoom_key(&self, zoom_in: bool) {
+        let label = {
+            let mut app_data = self.app_data.lock();
+            if zoom_in {
+                app_data.chart_zoom_in();
+            } else {
+                app_data.chart_zoom_out();
+            }
+            app_data.get_chart_window_label()
+        };
+        self.gui_state
+            .lock()
+            .set_info_box(&format!("chart window {label}"));
+    }
+
     /// Toggle the mouse capture (via input of the 'm' key)
     fn mouse_capture_key(&mut self) {
         let err = || {
@@ -177,58 +200,110 @@ impl InputHandler {
     async fn save_logs(&self) -> Result<(), Box<dyn std::error::Error>> {
         let args = self.app_data.lock().config.clone();
         let container = self.app_data.lock().get_selected_container_id_state_name();
-        if let Some((id, _, name)) = container {
-            if let Some(log_path) = args.save_dir {
-                let (sx, rx) = tokio::sync::oneshot::channel();
-                self.docker_tx.send(DockerMessage::Exec(sx)).await?;
-
-                let now = SystemTime::now()
-                    .duration_since(SystemTime::UNIX_EPOCH)
-                    .map_or(0, |i| i.as_secs());
-
-                let path = log_path.join(format!("{name}_{now}.log"));
-
-                let options = Some(LogsOptions::<String> {
-                    stderr: true,
-                    stdout: true,
-                    timestamps: args.show_timestamp,
-                    since: 0,
-                    ..Default::default()
-                });
-                let mut logs = rx.await?.logs(id.get(), options);
-                let mut output = vec![];
-
-                while let Some(Ok(value)) = logs.next().await {
-                    let data = value.to_string();
-                    if !data.trim().is_empty() {
-                        output.push(
-                            categorise_text(&data)
-                                .into_iter()
-                                .map(|i| i.text)
-                                .collect::<String>(),
-                        );
-                    }
-                }
-                if !output.is_empty() {
-                    let mut stream = BufWriter::new(
-                        OpenOptions::new()
-                            .read(true)
-                            .write(true)
-                            .create(true)
-                            .truncate(true)
-                            .open(&path)?,
+        if let Some((id, _, name)) = container
+            && let Some(log_path) = args.save_dir
+        {
+            let (sx, rx) = tokio::sync::oneshot::channel();
+            self.docker_tx.send(DockerMessage::Exec(sx)).await?;
+
+            let now = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |i| i.as_secs());
+
+            let path = log_path.join(format!("{name}_{now}.log"));
+
+            let options = Some(LogsOptions::<String> {
+                stderr: true,
+                stdout: true,
+                timestamps: args.show_timestamp,
+                since: 0,
+                ..Default::default()
+            });
+            let mut logs = rx.await?.logs(id.get(), options);
+            let mut output = vec![];
+
+            while let Some(Ok(value)) = logs.next().await {
+                let data = value.to_string();
+                if !data.trim().is_empty() {
+                    output.push(
+                        categorise_text(&data)
+                            .into_iter()
+                            .map(|i| i.text)
+                            .collect::<String>(),
                     );
+                }
+            }
+            if !output.is_empty() {
+                let mut stream = BufWriter::new(
+                    OpenOptions::new()
+                        .read(true)
+                        .write(true)
+                        .create(true)
+                        .truncate(true)
+                        .open(&path)?,
+                );
 
-                    for line in &output {
-                        stream.write_all(line.as_bytes())?;
-                    }
-                    stream.flush()?;
+                for line in &output {
+                    stream.write_all(line.as_bytes())?;
+                }
+                stream.flush()?;
+
+                self.gui_state
+                    .lock()
+                    .set_info_box(&format!("saved to {}", path.display()));
+            }
+        }
+        Ok(())
+    }
 
-                    self.gui_state
-                        .lock()
-                        .set_info_box(&format!("saved to {}", path.display()));
+    /// Inspect the selected container, and its image, to generate a docker run command & compose file, which are then shown in a popup
+    async fn export_key(&self) {
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, _, _)) = container {
+            let uuid = Uuid::new_v4();
+            GuiState::start_loading_animation(&self.gui_state, uuid);
+            let (sx, rx) = tokio::sync::oneshot::channel();
+            self.docker_tx.send(DockerMessage::Exec(sx)).await.ok();
+
+            if let Ok(docker) = rx.await {
+                if let Ok(inspect) = docker.inspect_container(id.get(), None).await {
+                    let image = DockerData::image_config(&docker, &inspect).await;
+                    let export = ContainerExport::new(&inspect, image.as_ref());
+                    self.gui_state.lock().set_export(Some(export));
+                } else {
+                    self.app_data.lock().set_error(
+                        AppError::DockerExport,
+                        &self.gui_state,
+                        Status::Error,
+                    );
                 }
             }
+            self.gui_state.lock().stop_loading_animation(uuid);
+        }
+    }
+
+    /// Save the docker run command, and the compose file, of the current export into `[container_name]_[timestamp].sh` and `[container_name]_[timestamp].compose.yml` files
+    fn save_export(&self) -> Result<(), std::io::Error> {
+        let save_dir = self.app_data.lock().config.save_dir.clone();
+        let export = self.gui_state.lock().get_export();
+        if let Some(save_dir) = save_dir
+            && let Some((export, _)) = export
+        {
+            let now = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |i| i.as_secs());
+            let name = format!("{}_{now}", export.name);
+            std::fs::write(
+                save_dir.join(format!("{name}.sh")),
+                format!("#!/bin/sh\n{}\n", export.run),
+            )?;
+            std::fs::write(
+                save_dir.join(format!("{name}.compose.yml")),
+                format!("{}\n", export.compose),
+            )?;
+            self.gui_state
+                .lock()
+                .set_info_box(&format!("saved to {}", save_dir.join(name).display()));
         }
         Ok(())
     }
@@ -274,6 +349,11 @@ impl InputHandler {
                             .send(DockerMessage::ConfirmDelete(id))
                             .await
                             .ok(),
+                        DockerCommand::Recreate => self
+                            .docker_tx
+                            .send(DockerMessage::ConfirmRecreate(id))
+                            .await
+                            .ok(),
 
                         _ => self
                             .docker_tx
@@ -283,6 +363,9 @@ impl InputHandler {
                     };
                 }
             }
+        } else if panel == SelectablePanel::Logs {
+            let log_detail = self.app_data.lock().get_log_detail();
+            self.gui_state.lock().set_log_detail(log_detail);
         }
     }
 
@@ -336,6 +419,59 @@ impl InputHandler {
         }
     }
 
+    /// Actions to take when CpuDetail status active, only close the popup
+    fn handle_cpu_detail(&self, key_code: KeyCode) {
+        if self.keymap.clear.0 == key_code
+            || self.keymap.clear.1 == Some(key_code)
+            || self.keymap.toggle_cpu_detail.0 == key_code
+            || self.keymap.toggle_cpu_detail.1 == Some(key_code)
+        {
+            self.gui_state.lock().status_del(Status::CpuDetail);
+        }
+    }
+
+    /// Actions to take when MemDetail status active, only close the popup
+    fn handle_mem_detail(&self, key_code: KeyCode) {
+        if self.keymap.clear.0 == key_code
+            || self.keymap.clear.1 == Some(key_code)
+            || self.keymap.toggle_mem_detail.0 == key_code
+            || self.keymap.toggle_mem_detail.1 == Some(key_code)
+        {
+            self.gui_state.lock().status_del(Status::MemDetail);
+        }
+    }
+
+    /// Actions to take when ChartCursor status active, move the cursor, zoom the charts, or exit
+    fn handle_chart_cursor(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Left => {
+                let points = self.app_data.lock().get_chart_times().len();
+                self.gui_state.lock().chart_cursor_left(points);
+            }
+            KeyCode::Right => {
+                self.gui_state.lock().chart_cursor_right();
+            }
+            _ if self.keymap.chart_zoom_out.0 == key_code
+                || self.keymap.chart_zoom_out.1 == Some(key_code) =>
+            {
+                self.chart_zoom_key(false);
+            }
+            _ if self.keymap.chart_zoom_in.0 == key_code
+                || self.keymap.chart_zoom_in.1 == Some(key_code) =>
+            {
+                self.chart_zoom_key(true);
+            }
+            _ if self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code)
+                || self.keymap.toggle_chart_cursor.0 == key_code
+                || self.keymap.toggle_chart_cursor.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_del(Status::ChartCursor);
+            }
+            _ => (),
+        }
+    }
+
     /// Actions to take when Error status active
     fn handle_error(&self, key_code: KeyCode) {
         if self.keymap.clear.0 == key_code || self.keymap.clear.1 == Some(key_code) {
@@ -359,6 +495,85 @@ impl InputHandler {
         }
     }
 
+    /// Actions to take when Export status active
+    fn handle_export(&self, key_code: KeyCode) {
+        let is = |key: (KeyCode, Option<KeyCode>)| key.0 == key_code || key.1 == Some(key_code);
+        if is(self.keymap.clear) {
+            self.gui_state.lock().set_export(None);
+        } else if is(self.keymap.save_logs) {
+            if self.save_export().is_err() {
+                self.app_data.lock().set_error(
+                    AppError::DockerExport,
+                    &self.gui_state,
+                    Status::Error,
+                );
+            }
+        } else if is(self.keymap.scroll_up_one) {
+            self.gui_state.lock().export_scroll(-1);
+        } else if is(self.keymap.scroll_down_one) {
+            self.gui_state.lock().export_scroll(1);
+        } else if is(self.keymap.scroll_up_many) {
+            self.gui_state.lock().export_scroll(-10);
+        } else if is(self.keymap.scroll_down_many) {
+            self.gui_state.lock().export_scroll(10);
+        } else if is(self.keymap.scroll_start) {
+            self.gui_state.lock().export_scroll(isize::MIN);
+        } else if is(self.keymap.scroll_end) {
+            self.gui_state.lock().export_scroll(isize::MAX);
+        }
+    }
+
+    /// Actions to take when LogDetail status active, Enter also closes the popup, as it is used to open it
+    fn handle_log_detail(&self, key_code: KeyCode) {
+        let is = |key: (KeyCode, Option<KeyCode>)| key.0 == key_code || key.1 == Some(key_code);
+        if is(self.keymap.clear) || key_code == KeyCode::Enter {
+            self.gui_state.lock().set_log_detail(None);
+        } else if is(self.keymap.scroll_up_one) {
+            self.gui_state.lock().log_detail_scroll(-1);
+        } else if is(self.keymap.scroll_down_one) {
+            self.gui_state.lock().log_detail_scroll(1);
+        } else if is(self.keymap.scroll_up_many) {
+            self.gui_state.lock().log_detail_scroll(-10);
+        } else if is(self.keymap.scroll_down_many) {
+            self.gui_state.lock().log_detail_scroll(10);
+        } else if is(self.keymap.scroll_start) {
+            self.gui_state.lock().log_detail_scroll(isize::MIN);
+        } else if is(self.keymap.scroll_end) {
+            self.gui_state.lock().log_detail_scroll(isize::MAX);
+        }
+    }
+
+    /// Actions to take when Recreate status active, all keys other than Esc, Enter, Tab, and the arrow keys, are used to edit the form
+    async fn handle_recreate(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => self.gui_state.lock().set_recreate_form(None),
+            KeyCode::Enter => {
+                let id = self
+                    .gui_state
+                    .lock()
+                    .get_recreate_form()
+                    .filter(RecreateForm::is_valid)
+                    .map(|form| form.id().clone());
+                if let Some(id) = id {
+                    self.docker_tx
+                        .send(DockerMessage::Control((DockerCommand::Recreate, id)))
+                        .await
+                        .ok();
+                }
+            }
+            KeyCode::Up => self
+                .gui_state
+                .lock()
+                .edit_recreate_form(|f| f.lines.previous()),
+            KeyCode::Down | KeyCode::Tab => {
+                self.gui_state.lock().edit_recreate_form(|f| f.lines.next());
+            }
+            KeyCode::Backspace => self.gui_state.lock().edit_recreate_form(RecreateForm::pop),
+            KeyCode::Char(c) => self.gui_state.lock().edit_recreate_form(|f| f.push(c)),
+            _ => (),
+        }
+    }
+
     /// Actions to take when Filter status active
     fn handle_filter(&self, key_code: KeyCode) {
         match key_code {
@@ -388,6 +603,75 @@ impl InputHandler {
         }
     }
 
+    /// Actions to take when LogFilter status active, the filter remains on the currently shown logs once confirmed
+    fn handle_log_filter(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => {
+                self.app_data.lock().log_filter_clear();
+                self.gui_state.lock().status_del(Status::LogFilter);
+            }
+            _ if KeyCode::Enter == key_code
+                || self.keymap.log_filter.0 == key_code
+                || self.keymap.log_filter.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_filter_confirm();
+                self.gui_state.lock().status_del(Status::LogFilter);
+            }
+            KeyCode::Backspace => {
+                self.app_data.lock().log_filter_edit(LogFilter::pop);
+            }
+            KeyCode::Char(x) => {
+                self.app_data.lock().log_filter_edit(|f| f.push(x));
+            }
+            KeyCode::Tab => {
+                self.app_data.lock().log_filter_edit(LogFilter::mode_next);
+            }
+            KeyCode::Left | KeyCode::Right => {
+                self.app_data
+                    .lock()
+                    .log_filter_edit(LogFilter::toggle_editing);
+            }
+            KeyCode::Up | KeyCode::Down => {
+                self.app_data
+                    .lock()
+                    .log_filter_edit(LogFilter::toggle_case_sensitive);
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when LogSearch status active, the search, and its highlights, remain once the term is confirmed
+    fn handle_log_search(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => {
+                self.app_data.lock().log_search_clear();
+                self.gui_state.lock().status_del(Status::LogSearch);
+            }
+            _ if KeyCode::Enter == key_code
+                || self.keymap.log_search.0 == key_code
+                || self.keymap.log_search.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_del(Status::LogSearch);
+            }
+            KeyCode::Backspace => {
+                self.app_data.lock().log_search_pop();
+            }
+            KeyCode::Char(x) => {
+                self.app_data.lock().log_search_push(x);
+            }
+            KeyCode::Tab => {
+                self.app_data.lock().log_search_mode_next();
+            }
+            KeyCode::Down => {
+                self.app_data.lock().log_search_next();
+            }
+            KeyCode::Up => {
+                self.app_data.lock().log_search_previous();
+            }
+            _ => (),
+        }
+    }
+
     /// Handle input that refers to the sorting of columns
     fn handle_sort(&self, key_code: KeyCode) {
         match key_code {
@@ -447,6 +731,21 @@ impl InputHandler {
             {
                 self.sort(Header::Tx);
             }
+            _ if self.keymap.sort_by_block_read.0 == key_code
+                || self.keymap.sort_by_block_read.1 == Some(key_code) =>
+            {
+                self.sort(Header::BlockRead);
+            }
+            _ if self.keymap.sort_by_block_write.0 == key_code
+                || self.keymap.sort_by_block_write.1 == Some(key_code) =>
+            {
+                self.sort(Header::BlockWrite);
+            }
+            _ if self.keymap.sort_by_pids.0 == key_code
+                || self.keymap.sort_by_pids.1 == Some(key_code) =>
+            {
+                self.sort(Header::Pids);
+            }
             _ => (),
         }
     }
@@ -466,7 +765,29 @@ impl InputHandler {
         self.gui_state.lock().toggle_show_logs();
     }
 
+    /// Show the cpu detail popup, only if a container is selected
+    fn cpu_detail_key(&self) {
+        if self.app_data.lock().get_selected_container_id().is_some() {
+            self.gui_state.lock().status_push(Status::CpuDetail);
+        }
+    }
+
+    /// Start inspecting the chart history, only if a container is selected
+    fn chart_cursor_key(&self) {
+        if self.app_data.lock().get_selected_container_id().is_some() {
+            self.gui_state.lock().status_push(Status::ChartCursor);
+        }
+    }
+
+    /// Show the memory detail popup, only if a container is selected
+    fn mem_detail_key(&self) {
+        if self.app_data.lock().get_selected_container_id().is_some() {
+            self.gui_state.lock().status_push(Status::MemDetail);
+        }
+    }
+
     /// Handle button presses in all other scenarios
+    #[allow(clippy::too_many_lines)]
     async fn handle_others(&mut self, key_code: KeyCode) {
         self.handle_sort(key_code);
         // shift key plus arrows
@@ -475,6 +796,10 @@ impl InputHandler {
                 self.exec_key().await;
             }
 
+            _ if self.keymap.export.0 == key_code || self.keymap.export.1 == Some(key_code) => {
+                self.export_key().await;
+            }
+
             _ if self.keymap.toggle_help.0 == key_code
                 || self.keymap.toggle_help.1 == Some(key_code) =>
             {
@@ -486,6 +811,111 @@ impl InputHandler {
             {
                 self.mouse_capture_key();
             }
+
+            _ if self.keymap.toggle_cpu_detail.0 == key_code
+                || self.keymap.toggle_cpu_detail.1 == Some(key_code) =>
+            {
+                self.cpu_detail_key();
+            }
+
+            _ if self.keymap.toggle_mem_detail.0 == key_code
+                || self.keymap.toggle_mem_detail.1 == Some(key_code) =>
+            {
+                self.mem_detail_key();
+            }
+
+            _ if self.keymap.toggle_net_total.0 == key_code
+                || self.keymap.toggle_net_total.1 == Some(key_code) =>
+            {
+                self.app_data.lock().toggle_show_net_total();
+            }
+
+            _ if self.keymap.cycle_cpu_mode.0 == key_code
+                || self.keymap.cycle_cpu_mode.1 == Some(key_code) =>
+            {
+                self.app_data.lock().cycle_cpu_mode();
+            }
+
+            _ if self.keymap.toggle_mem_percent.0 == key_code
+                || self.keymap.toggle_mem_percent.1 == Some(key_code) =>
+            {
+                self.app_data.lock().toggle_show_mem_percent();
+            }
+
+            _ if self.keymap.chart_zoom_out.0 == key_code
+                || self.keymap.chart_zoom_out.1 == Some(key_code) =>
+            {
+                self.chart_zoom_key(false);
+            }
+
+            _ if self.keymap.chart_zoom_in.0 == key_code
+                || self.keymap.chart_zoom_in.1 == Some(key_code) =>
+            {
+                self.chart_zoom_key(true);
+            }
+
+            _ if self.keymap.toggle_chart_cursor.0 == key_code
+                || self.keymap.toggle_chart_cursor.1 == Some(key_code) =>
+            {
+                self.chart_cursor_key();
+            }
+
+            _ if self.keymap.toggle_chart_overview.0 == key_code
+                || self.keymap.toggle_chart_overview.1 == Some(key_code) =>
+            {
+                self.app_data.lock().cycle_chart_overview();
+            }
+
+            _ if self.keymap.toggle_record.0 == key_code
+                || self.keymap.toggle_record.1 == Some(key_code) =>
+            {
+                self.docker_tx.send(DockerMessage::ToggleRecord).await.ok();
+            }
+
+            _ if self.keymap.toggle_merged_logs.0 == key_code
+                || self.keymap.toggle_merged_logs.1 == Some(key_code) =>
+            {
+                self.app_data.lock().toggle_merged_logs();
+            }
+
+            _ if self.keymap.toggle_mark.0 == key_code
+                || self.keymap.toggle_mark.1 == Some(key_code) =>
+            {
+                self.app_data.lock().toggle_mark();
+            }
+
+            _ if self.keymap.log_filter.0 == key_code
+                || self.keymap.log_filter.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_filter_edit(|_| ());
+                self.gui_state.lock().status_push(Status::LogFilter);
+            }
+
+            _ if self.keymap.log_level.0 == key_code
+                || self.keymap.log_level.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_level_next();
+            }
+
+            _ if self.keymap.log_search.0 == key_code
+                || self.keymap.log_search.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_search_start();
+                self.gui_state.lock().status_push(Status::LogSearch);
+            }
+
+            _ if self.keymap.log_search_next.0 == key_code
+                || self.keymap.log_search_next.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_search_next();
+            }
+
+            _ if self.keymap.log_search_previous.0 == key_code
+                || self.keymap.log_search_previous.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_search_previous();
+            }
+
             _ if self.keymap.log_section_height_decrease.0 == key_code
                 || self.keymap.log_section_height_decrease.1 == Some(key_code) =>
             {
@@ -583,14 +1013,26 @@ impl InputHandler {
         let contains_help = contains(Status::Help);
         let contains_exec = contains(Status::Exec);
         let contains_filter = contains(Status::Filter);
+        let contains_log_filter = contains(Status::LogFilter);
+        let contains_log_search = contains(Status::LogSearch);
         let contains_delete = contains(Status::DeleteConfirm);
+        let contains_recreate = contains(Status::Recreate);
+        let contains_export = contains(Status::Export);
+        let contains_log_detail = contains(Status::LogDetail);
+        let contains_cpu_detail = contains(Status::CpuDetail);
+        let contains_mem_detail = contains(Status::MemDetail);
+        let contains_chart_cursor = contains(Status::ChartCursor);
 
         if !contains_exec {
             let is_q = || key_code == self.keymap.quit.0 || Some(key_code) == self.keymap.quit.1;
             if key_modifier == KeyModifiers::CONTROL && key_code == KeyCode::Char('c')
-                || is_q() && !contains_filter
+                || is_q()
+                    && !contains_filter
+                    && !contains_log_filter
+                    && !contains_log_search
+                    && !contains_recreate
             {
-                // Always just quit on Ctrl + c/C or q/Q, unless in Filter status active
+                // Always just quit on Ctrl + c/C or q/Q, unless in Filter, LogFilter, LogSearch, or Recreate status active
                 self.quit();
             }
 
@@ -600,8 +1042,24 @@ impl InputHandler {
                 self.handle_help(key_code);
             } else if contains_filter {
                 self.handle_filter(key_code);
+            } else if contains_log_filter {
+                self.handle_log_filter(key_code);
+            } else if contains_log_search {
+                self.handle_log_search(key_code);
             } else if contains_delete {
                 self.handle_delete(key_code).await;
+            } else if contains_recreate {
+                self.handle_recreate(key_code).await;
+            } else if contains_export {
+                self.handle_export(key_code);
+            } else if contains_log_detail {
+                self.handle_log_detail(key_code);
+            } else if contains_cpu_detail {
+                self.handle_cpu_detail(key_code);
+            } else if contains_mem_detail {
+                self.handle_mem_detail(key_code);
+            } else if contains_chart_cursor {
+                self.handle_chart_cursor(key_code);
             } else {
                 self.handle_others(key_code).await;
             }
@@ -0,0 +1,282 @@
+use std::{
+    io::{IsTerminal, Write},
+    time::Duration,
+};
+
+use bollard::{Docker, container::LogsOptions};
+use futures_util::StreamExt;
+use jiff::Timestamp;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    app_data::{ContainerId, LogsTz},
+    config::{Config, LogsCommand},
+    control::{self, EXIT_FAILED, EXIT_SUCCESS},
+    ui::log_sanitizer,
+};
+
+/// Lines received within this window are sorted by timestamp before being printed, so that the output of each container is interleaved
+const INTERLEAVE_WINDOW: Duration = Duration::from_millis(250);
+
+/// ANSI foreground colours used for the container name prefix, cyan, yellow, green, magenta, blue, red, and then the bright variants
+const PREFIX_COLORS: [u8; 12] = [36, 33, 32, 35, 34, 31, 96, 93, 92, 95, 94, 91];
+
+/// A single log line, received from docker, and the index of the container it came from
+type RawLine = (usize, String);
+
+/// The container name, padded so that every prefix is the same width, and optionally coloured
+fn prefix(name: &str, width: usize, index: usize, color: bool) -> String {
+    if color {
+        let code = PREFIX_COLORS[index % PREFIX_COLORS.len()];
+        format!("\x1b[{code}m{name:<width$}\x1b[0m | ")
+    } else {
+        format!("{name:<width$} | ")
+    }
+}
+
+/// Apply the same log_sanitizer mode as the gui, color logs are left unchanged, as the terminal will render the ansi formatting
+fn sanitize(input: &str, config: &Config) -> String {
+    let lines = if config.color_logs {
+        return input.trim_end().to_owned();
+    } else if config.raw_logs {
+        log_sanitizer::raw(input)
+    } else {
+        log_sanitizer::remove_ansi(input)
+    };
+    lines
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format a single docker log line, with the timestamp displayed in the same way as the gui
+/// Every line of a multi-line entry gets the prefix & timestamp, so that the lines of different containers can still be told apart
+/// Returns None if the line doesn't contain the grep text
+fn format_line(line: &str, prefix: &str, config: &Config, grep: Option<&str>) -> Option<String> {
+    let (log_tz, content) = LogsTz::splitter(line);
+    let content = sanitize(&content, config);
+    if grep.is_some_and(|grep| !content.contains(grep)) {
+        return None;
+    }
+    let prefix = if config.show_timestamp {
+        let timestamp = log_tz
+            .display_with_formatter(config.timezone.as_ref(), &config.timestamp_format)
+            .unwrap_or_else(|| log_tz.to_string());
+        format!("{prefix}{timestamp} ")
+    } else {
+        prefix.to_owned()
+    };
+    Some(
+        content
+            .split('\n')
+            .map(|line| format!("{prefix}{line}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// The docker timestamp at the start of each log line, lines without a valid timestamp are sorted first
+fn line_timestamp(line: &str) -> Option<Timestamp> {
+    line.split_once(' ')
+        .and_then(|(timestamp, _)| timestamp.parse::<Timestamp>().ok())
+}
+
+/// Stream the logs of a single container, sending each line to the printer, until the container stops
+async fn stream_logs(
+    docker: Docker,
+    id: ContainerId,
+    index: usize,
+    options: LogsOptions<String>,
+    sender: UnboundedSender<RawLine>,
+) {
+    let mut logs = docker.logs(id.get(), Some(options));
+    while let Some(Ok(value)) = logs.next().await {
+        for line in value.to_string().lines() {
+            if !line.trim().is_empty() && sender.send((index, line.to_owned())).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Follow the logs of every container matched by the targets, printing each line to stdout with a container name prefix, until every container has stopped
+pub async fn run(docker: &Docker, config: &Config, logs: &LogsCommand) -> i32 {
+    let containers = match control::resolve_targets(docker, &logs.targets).await {
+        Ok(containers) => containers,
+        Err(code) => return code,
+    };
+
+    let color = std::io::stdout().is_terminal();
+    let width = containers
+        .iter()
+        .map(|(_, name)| name.chars().count())
+        .max()
+        .unwrap_or_default();
+    let prefixes = containers
+        .iter()
+        .enumerate()
+        .map(|(index, (_, name))| prefix(name, width, index, color))
+        .collect::<Vec<_>>();
+
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    for (index, (id, _)) in containers.into_iter().enumerate() {
+        let options = LogsOptions::<String> {
+            follow: true,
+            stdout: true,
+            stderr: config.show_std_err,
+            timestamps: true,
+            since: logs.since.unwrap_or_default(),
+            tail: logs
+                .tail
+                .map_or_else(|| "all".to_owned(), |i| i.to_string()),
+            ..Default::default()
+        };
+        tokio::spawn(stream_logs(
+            docker.clone(),
+            id,
+            index,
+            options,
+            sender.clone(),
+        ));
+    }
+    drop(sender);
+
+    let mut stdout = std::io::stdout();
+    while let Some(first) = receiver.recv().await {
+        let mut batch = vec![first];
+        let deadline = tokio::time::Instant::now() + INTERLEAVE_WINDOW;
+        while let Ok(Some(line)) = tokio::time::timeout_at(deadline, receiver.recv()).await {
+            batch.push(line);
+        }
+        batch.sort_by_cached_key(|(_, line)| line_timestamp(line));
+
+        let output = batch
+            .iter()
+            .filter_map(|(index, line)| {
+                format_line(line, &prefixes[*index], config, logs.grep.as_deref())
+            })
+            .map(|line| line + "\n")
+            .collect::<String>();
+        if stdout
+            .write_all(output.as_bytes())
+            .and_then(|()| stdout.flush())
+            .is_err()
+        {
+            return EXIT_FAILED;
+        }
+    }
+    EXIT_SUCCESS
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{config::Config, tests::gen_config};
+
+    use super::{format_line, line_timestamp, prefix};
+
+    const LINE: &str = "2025-01-01T00:00:01.000000000Z \x1b[31merror\x1b[0m connection refused";
+
+    fn gen_timestamp_config() -> Config {
+        Config {
+            show_timestamp: true,
+            timestamp_format: "%H:%M:%S".to_owned(),
+            ..gen_config()
+        }
+    }
+
+    #[test]
+    /// Names are padded to the same width, and coloured by index
+    fn test_log_tail_prefix() {
+        assert_eq!(prefix("db", 6, 0, false), "db     | ");
+        assert_eq!(prefix("web_1", 6, 1, false), "web_1  | ");
+        assert_eq!(prefix("db", 6, 0, true), "\x1b[36mdb    \x1b[0m | ");
+        assert_eq!(prefix("db", 6, 13, true), "\x1b[33mdb    \x1b[0m | ");
+    }
+
+    #[test]
+    /// Lines are sanitized with the same modes as the gui, and the timestamp is formatted with the timestamp_format
+    fn test_log_tail_format_line() {
+        let config = gen_config();
+        assert_eq!(
+            format_line(LINE, "db | ", &config, None).unwrap(),
+            "db | error connection refused"
+        );
+
+        let config = gen_timestamp_config();
+        assert_eq!(
+            format_line(LINE, "db | ", &config, None).unwrap(),
+            "db | 00:00:01 error connection refused"
+        );
+
+        let config = Config {
+            raw_logs: true,
+            ..gen_config()
+        };
+        assert_eq!(
+            format_line(LINE, "", &config, None).unwrap(),
+            "\\u{1b}[31merror\\u{1b}[0m connection refused"
+        );
+
+        let config = Config {
+            color_logs: true,
+            ..gen_config()
+        };
+        assert_eq!(
+            format_line(LINE, "", &config, None).unwrap(),
+            "\x1b[31merror\x1b[0m connection refused"
+        );
+    }
+
+    #[test]
+    /// Every line of a multi-line entry is prefixed, not just the first
+    fn test_log_tail_format_line_multi_line() {
+        let line = "2025-01-01T00:00:01.000000000Z panic: failed\n\tat main.rs:1";
+        let config = Config {
+            color_logs: true,
+            ..gen_config()
+        };
+        assert_eq!(
+            format_line(line, "db | ", &config, None).unwrap(),
+            "db | panic: failed\ndb | \tat main.rs:1"
+        );
+        let config = Config {
+            color_logs: true,
+            ..gen_timestamp_config()
+        };
+        assert_eq!(
+            format_line(line, "db | ", &config, None).unwrap(),
+            "db | 00:00:01 panic: failed\ndb | 00:00:01 \tat main.rs:1"
+        );
+    }
+
+    #[test]
+    /// Only lines containing the grep text are returned
+    fn test_log_tail_format_line_grep() {
+        let config = gen_config();
+        assert!(format_line(LINE, "", &config, Some("refused")).is_some());
+        assert!(format_line(LINE, "", &config, Some("accepted")).is_none());
+        // The grep text is matched against the sanitized line, so doesn't include the timestamp
+        assert!(format_line(LINE, "", &gen_timestamp_config(), Some("00:00")).is_none());
+    }
+
+    #[test]
+    /// Lines are sorted by the docker timestamp, lines without a timestamp are first
+    fn test_log_tail_line_timestamp() {
+        let mut lines = [
+            "2025-01-01T00:00:02.000000000Z second",
+            "invalid",
+            "2025-01-01T00:00:01.500000000Z first",
+        ];
+        lines.sort_by_cached_key(|i| line_timestamp(i));
+        assert_eq!(
+            lines,
+            [
+                "invalid",
+                "2025-01-01T00:00:01.500000000Z first",
+                "2025-01-01T00:00:02.000000000Z second"
+            ]
+        );
+    }
+}
@@ -7,20 +7,21 @@ use input_handler::InputMessages;
 use parking_lot::Mutex;
 use std::{
     process,
-    sync::{
-        Arc,
-        atomic::{AtomicBool, Ordering},
-    },
+    sync::{Arc, atomic::AtomicBool},
 };
 use tokio::sync::mpsc::{Receiver, Sender};
-use tracing::{Level, error, info};
+use tracing::{Level, error};
 
 mod app_data;
 mod app_error;
 mod config;
+mod control;
 mod docker_data;
 mod exec;
 mod input_handler;
+mod log_tail;
+mod metrics;
+mod output;
 mod ui;
 
 use ui::{GuiState, Rerender, Status, Ui};
@@ -44,7 +45,21 @@ fn read_docker_host(config: &Config) -> Option<String> {
     config
         .host
         .as_ref()
-        .map_or_else(|| std::env::var(DOCKER_HOST).ok(), |x| Some(x.to_string()))
+        .map_or_else(|| std::env::var(DOCKER_HOST).ok(), |x| Some(x.clone()))
+}
+
+/// Create docker daemon handler, only returned if a ping returns non-error
+async fn docker_connect(config: &Config) -> Option<Docker> {
+    let host = read_docker_host(config);
+
+    let connection = host.map_or_else(Docker::connect_with_socket_defaults, |host| {
+        Docker::connect_with_socket(&host, 120, API_DEFAULT_VERSION)
+    });
+
+    match connection {
+        Ok(docker) if docker.ping().await.is_ok() => Some(docker),
+        _ => None,
+    }
 }
 
 /// Create docker daemon handler, and only spawn up the docker data handler if a ping returns non-error
@@ -54,23 +69,16 @@ async fn docker_init(
     docker_tx: Sender<DockerMessage>,
     gui_state: &Arc<Mutex<GuiState>>,
 ) {
-    let host = read_docker_host(&app_data.lock().config);
-
-    let connection = host.map_or_else(Docker::connect_with_socket_defaults, |host| {
-        Docker::connect_with_socket(&host, 120, API_DEFAULT_VERSION)
-    });
-
-    if let Ok(docker) = connection {
-        if docker.ping().await.is_ok() {
-            tokio::spawn(DockerData::start(
-                Arc::clone(app_data),
-                docker,
-                docker_rx,
-                docker_tx,
-                Arc::clone(gui_state),
-            ));
-            return;
-        }
+    let config = app_data.lock().config.clone();
+    if let Some(docker) = docker_connect(&config).await {
+        tokio::spawn(DockerData::start(
+            Arc::clone(app_data),
+            docker,
+            docker_rx,
+            docker_tx,
+            Arc::clone(gui_state),
+        ));
+        return;
     }
     app_data
         .lock()
@@ -98,6 +106,27 @@ fn handler_init(
 async fn main() {
     setup_tracing();
     let config = config::Config::new();
+
+    if let Some(control) = config.control.as_ref() {
+        let code = if let Some(docker) = docker_connect(&config).await {
+            control::run(&docker, control).await
+        } else {
+            error!("{}", AppError::DockerConnect);
+            control::EXIT_FAILED
+        };
+        process::exit(code);
+    }
+
+    if let Some(logs) = config.logs.as_ref() {
+        let code = if let Some(docker) = docker_connect(&config).await {
+            log_tail::run(&docker, &config, logs).await
+        } else {
+            error!("{}", AppError::DockerConnect);
+            control::EXIT_FAILED
+        };
+        process::exit(code);
+    }
+
     let redraw = Arc::new(Rerender::new());
 
     let app_data = Arc::new(Mutex::new(AppData::new(config.clone(), &redraw)));
@@ -105,43 +134,25 @@ async fn main() {
     let is_running = Arc::new(AtomicBool::new(true));
     let (docker_tx, docker_rx) = tokio::sync::mpsc::channel(32);
 
+    // Set before DockerData starts, so that the non-interactive output can wait until the containers have been initialised
+    if !config.gui && config.metrics_listen.is_none() {
+        gui_state.lock().status_push(Status::Init);
+    }
+
     docker_init(&app_data, docker_rx, docker_tx.clone(), &gui_state).await;
 
-    if config.gui {
+    if let Some(addr) = config.metrics_listen {
+        if let Err(err) = metrics::start(addr, app_data).await {
+            error!("{}", err);
+            process::exit(1);
+        }
+    } else if config.gui {
         let (input_tx, input_rx) = tokio::sync::mpsc::channel(32);
         handler_init(&app_data, &docker_tx, &gui_state, input_rx, &is_running);
         Ui::start(app_data, gui_state, input_tx, is_running, redraw).await;
-    } else {
-        info!("in debug mode\n");
-        let mut now = std::time::Instant::now();
-        // Debug mode for testing, less pointless now, will display some basic information
-        while is_running.load(Ordering::SeqCst) {
-            let err = app_data.lock().get_error();
-            if let Some(err) = err {
-                error!("{}", err);
-                process::exit(1);
-            }
-            if let Some(Ok(to_sleep)) = u128::from(config.docker_interval_ms)
-                .checked_sub(now.elapsed().as_millis())
-                .map(u64::try_from)
-            {
-                tokio::time::sleep(std::time::Duration::from_millis(to_sleep)).await;
-            }
-            let containers = app_data
-                .lock()
-                .get_container_items()
-                .iter()
-                .map(|i| format!("{i}"))
-                .collect::<Vec<_>>();
-
-            if !containers.is_empty() {
-                for item in containers {
-                    info!("{item}");
-                }
-                println!();
-            }
-            now = std::time::Instant::now();
-        }
+    } else if let Err(err) = output::start(app_data, &gui_state, &config).await {
+        error!("{}", err);
+        process::exit(1);
     }
 }
 
@@ -155,10 +166,10 @@ mod tests {
 
     use crate::{
         app_data::{
-            AppData, ContainerId, ContainerItem, ContainerPorts, ContainerStatus, Filter,
-            RunningState, State, StatefulList,
+            AppData, ChartOverview, ContainerId, ContainerItem, ContainerPorts, ContainerStatus,
+            Filter, HistoryLength, NetworkInterface, RunningState, State, StatefulList,
         },
-        config::{AppColors, Config, Keymap},
+        config::{AppColors, Config, CpuMode, Keymap, MemoryFormula, OutputFormat},
         ui::Rerender,
     };
 
@@ -166,20 +177,39 @@ mod tests {
     pub fn gen_config() -> Config {
         Config {
             color_logs: false,
+            control: None,
+            cpu_mode: CpuMode::PerCore,
             docker_interval_ms: 1000,
             gui: true,
             host: None,
             show_std_err: false,
             in_container: false,
             save_dir: None,
+            metrics_listen: None,
+            once: false,
+            output_format: OutputFormat::Table,
             raw_logs: false,
+            record_max_mb: 100,
+            record_path: None,
             show_self: false,
             app_colors: AppColors::new(),
+            chart_overview_count: 5,
             keymap: Keymap::new(),
+            logs: None,
+            memory_critical_percentage: 95,
+            memory_formula: MemoryFormula::Docker,
+            memory_warning_percentage: 80,
             timestamp_format: "HH:MM:SS.NNNNN dd-mm-yyyy".to_owned(),
             show_timestamp: false,
             use_cli: false,
             show_logs: true,
+            show_io_chart: false,
+            show_net_chart: false,
+            show_host_summary: false,
+            sparkline: None,
+            sparkline_width: 10,
+            stats_history_seconds: 60,
+            structured_logs: false,
             timezone: None,
         }
     }
@@ -201,15 +231,34 @@ mod tests {
         )
     }
 
+    /// A single eth0 interface, with the given cumulative rx & tx bytes
+    pub fn gen_networks(rx: u64, tx: u64) -> Vec<NetworkInterface> {
+        vec![NetworkInterface {
+            name: "eth0".to_owned(),
+            rx_bytes: rx,
+            tx_bytes: tx,
+            ..NetworkInterface::default()
+        }]
+    }
+
     pub fn gen_appdata(containers: &[ContainerItem]) -> AppData {
         AppData {
+            chart_overview: ChartOverview::default(),
+            chart_window: 60,
             containers: StatefulList::new(containers.to_vec()),
+            cpu_mode: CpuMode::PerCore,
             hidden_containers: vec![],
+            host_summary: None,
+            history: HistoryLength::new(60, 1000),
+            log_search: None,
+            merged_logs: None,
             current_sorted_id: vec![],
             error: None,
             sorted_by: None,
             redraw: Arc::new(Rerender::new()),
             filter: Filter::new(),
+            show_mem_percent: false,
+            show_net_total: false,
             config: gen_config(),
         }
     }
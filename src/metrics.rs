@@ -0,0 +1,351 @@
+use std::{fmt::Write, net::SocketAddr, sync::Arc, time::Duration};
+
+use parking_lot::Mutex;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+use tracing::info;
+
+use crate::{
+    app_data::{AppData, ContainerItem, ContainerStatus, State, Stats},
+    app_error::AppError,
+};
+
+/// The Prometheus text exposition format content type
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// The path that the metrics are served from
+const METRICS_PATH: &str = "/metrics";
+
+/// Largest request that will be read, only the request line is actually used
+const MAX_REQUEST_BYTES: usize = 8192;
+
+/// Time to wait for a client to send the request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The name, type, and help text of each per container metric
+const METRICS: [(&str, &str, &str); 8] = [
+    (
+        "oxker_container_cpu_usage_percent",
+        "gauge",
+        "Cpu usage, as a percentage of a single core",
+    ),
+    (
+        "oxker_container_memory_usage_bytes",
+        "gauge",
+        "Memory usage in bytes",
+    ),
+    (
+        "oxker_container_memory_limit_bytes",
+        "gauge",
+        "Memory limit in bytes",
+    ),
+    (
+        "oxker_container_network_receive_bytes_total",
+        "counter",
+        "Bytes received over all network interfaces",
+    ),
+    (
+        "oxker_container_network_transmit_bytes_total",
+        "counter",
+        "Bytes transmitted over all network interfaces",
+    ),
+    (
+        "oxker_container_pids",
+        "gauge",
+        "Number of processes or threads",
+    ),
+    (
+        "oxker_container_state",
+        "gauge",
+        "Container state, the current state has a value of 1, every other state has a value of 0",
+    ),
+    (
+        "oxker_container_health",
+        "gauge",
+        "Container health check status, the current status has a value of 1, every other status has a value of 0",
+    ),
+];
+
+/// A single sample, with an optional extra label & label value
+type Sample = (Option<(&'static str, &'static str)>, f64);
+
+/// Escape a label value, as per the exposition format
+fn escape_label(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// A sample for every possible label value, the current value is 1, and every other value is 0, as per the Prometheus StateSet convention
+/// So that a series drops to 0, rather than disappearing, when the value changes
+fn state_set(label: &'static str, all: &[&'static str], current: &str) -> Vec<Sample> {
+    all.iter()
+        .map(|value| {
+            (
+                Some((label, *value)),
+                if *value == current { 1.0 } else { 0.0 },
+            )
+        })
+        .collect()
+}
+
+/// The samples of each metric in METRICS, for a single container, the state & health metrics have an extra label, and a sample for each possible value
+/// Cpu & memory are 0 if the container isn't running, as the stats history keeps the last values from when it was
+fn container_values(item: &ContainerItem) -> [Vec<Sample>; 8] {
+    let alive = item.state.is_alive();
+    let cpu = item
+        .cpu_stats
+        .back()
+        .filter(|_| alive)
+        .map_or(0.0, Stats::get_value);
+    let mem = item
+        .mem_stats
+        .back()
+        .filter(|_| alive)
+        .map_or(0.0, Stats::get_value);
+    #[allow(clippy::cast_precision_loss)]
+    let pids = item.pids.current as f64;
+    [
+        vec![(None, cpu)],
+        vec![(None, mem)],
+        vec![(None, item.mem_limit.get_value())],
+        vec![(None, item.rx.get_value())],
+        vec![(None, item.tx.get_value())],
+        vec![(None, pids)],
+        state_set("state", &State::LABELS, item.state.label()),
+        state_set(
+            "health",
+            &ContainerStatus::HEALTH_LABELS,
+            item.status.health(),
+        ),
+    ]
+}
+
+/// Generate the Prometheus text exposition of every container, including those hidden by a filter
+pub fn exposition(app_data: &AppData) -> String {
+    let items = app_data.get_all_container_items().collect::<Vec<_>>();
+    let values = items
+        .iter()
+        .map(|item| container_values(item))
+        .collect::<Vec<_>>();
+
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "# HELP oxker_docker_up Whether oxker is connected to the docker daemon\n# TYPE oxker_docker_up gauge\noxker_docker_up {}",
+        u8::from(app_data.get_error().is_none())
+    );
+
+    for (index, (name, metric_type, help)) in METRICS.iter().enumerate() {
+        let _ = writeln!(output, "# HELP {name} {help}\n# TYPE {name} {metric_type}");
+        for (item, values) in items.iter().zip(&values) {
+            for (extra, value) in &values[index] {
+                let extra = extra.map_or_else(String::new, |(label, label_value)| {
+                    format!(",{label}=\"{label_value}\"")
+                });
+                let _ = writeln!(
+                    output,
+                    "{name}{{id=\"{}\",name=\"{}\",image=\"{}\"{extra}}} {value}",
+                    escape_label(item.id.get()),
+                    escape_label(item.name.get()),
+                    escape_label(item.image.get()),
+                );
+            }
+        }
+    }
+    output
+}
+
+/// Create the http response for a given request, only GET or HEAD requests to the metrics path are accepted
+fn response(request: &str, app_data: &Arc<Mutex<AppData>>) -> String {
+    let mut request_line = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line
+        .next()
+        .unwrap_or_default()
+        .split('?')
+        .next()
+        .unwrap_or_default();
+
+    let (status, content_type, body) = match (method, path) {
+        ("GET" | "HEAD", METRICS_PATH) => ("200 OK", CONTENT_TYPE, exposition(&app_data.lock())),
+        ("GET" | "HEAD", _) => (
+            "404 Not Found",
+            "text/plain",
+            format!("metrics are served at {METRICS_PATH}\n"),
+        ),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed\n".to_owned(),
+        ),
+    };
+    let headers = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nAllow: GET, HEAD\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    if method == "HEAD" {
+        headers
+    } else {
+        format!("{headers}{body}")
+    }
+}
+
+/// Read a single request, respond, and then close the connection
+async fn handle_connection(mut stream: TcpStream, app_data: Arc<Mutex<AppData>>) {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+    let read = tokio::time::timeout(REQUEST_TIMEOUT, async {
+        while !request.windows(4).any(|i| i == b"\r\n\r\n") && request.len() < MAX_REQUEST_BYTES {
+            match stream.read(&mut buf).await {
+                Ok(0) | Err(_) => break,
+                Ok(len) => request.extend_from_slice(&buf[..len]),
+            }
+        }
+    })
+    .await;
+    if read.is_ok() && !request.is_empty() {
+        let response = response(&String::from_utf8_lossy(&request), &app_data);
+        stream.write_all(response.as_bytes()).await.ok();
+    }
+    stream.shutdown().await.ok();
+}
+
+/// Run without a gui, serving the container stats, which are updated by DockerData, as Prometheus metrics
+pub async fn start(addr: SocketAddr, app_data: Arc<Mutex<AppData>>) -> Result<(), AppError> {
+    let err = app_data.lock().get_error();
+    if let Some(err) = err {
+        return Err(err);
+    }
+    let listener = TcpListener::bind(addr)
+        .await
+        .map_err(|_| AppError::IO(format!("metrics listener on {addr}")))?;
+    info!("serving metrics at http://{addr}{METRICS_PATH}");
+    loop {
+        if let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(handle_connection(stream, Arc::clone(&app_data)));
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::{collections::VecDeque, sync::Arc};
+
+    use parking_lot::Mutex;
+
+    use crate::{
+        app_data::{ByteStats, ContainerPids, ContainerStatus, CpuStats, State},
+        app_error::AppError,
+        tests::{gen_appdata, gen_containers},
+    };
+
+    use super::{exposition, response};
+
+    #[test]
+    /// Every container has a sample for each metric, label values are escaped, and the state & health have a sample for every possible value
+    fn test_metrics_exposition() {
+        let (_, mut containers) = gen_containers();
+        containers[0].cpu_stats = VecDeque::from([CpuStats::new(1.0), CpuStats::new(12.5)]);
+        containers[0].mem_stats = VecDeque::from([ByteStats::new(1024)]);
+        containers[0].mem_limit = ByteStats::new(4096);
+        containers[0].rx = ByteStats::new(100);
+        containers[0].tx = ByteStats::new(200);
+        containers[0].pids = ContainerPids::new(Some(7), None);
+        containers[0].status = ContainerStatus::from("Up 1 hour (healthy)".to_owned());
+        containers[1].state = State::Exited;
+        containers[2].name = "container_\"3\"".into();
+
+        let mut app_data = gen_appdata(&containers);
+        let result = exposition(&app_data);
+        let lines = result.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[2], "oxker_docker_up 1");
+        assert_eq!(lines.len(), 3 + 8 * 2 + 3 * (6 + 7 + 4));
+        for line in [
+            "# TYPE oxker_container_network_receive_bytes_total counter",
+            "oxker_container_cpu_usage_percent{id=\"1\",name=\"container_1\",image=\"image_1\"} 12.5",
+            "oxker_container_cpu_usage_percent{id=\"2\",name=\"container_2\",image=\"image_2\"} 0",
+            "oxker_container_memory_usage_bytes{id=\"1\",name=\"container_1\",image=\"image_1\"} 1024",
+            "oxker_container_memory_limit_bytes{id=\"1\",name=\"container_1\",image=\"image_1\"} 4096",
+            "oxker_container_network_receive_bytes_total{id=\"1\",name=\"container_1\",image=\"image_1\"} 100",
+            "oxker_container_network_transmit_bytes_total{id=\"1\",name=\"container_1\",image=\"image_1\"} 200",
+            "oxker_container_pids{id=\"1\",name=\"container_1\",image=\"image_1\"} 7",
+            "oxker_container_state{id=\"1\",name=\"container_1\",image=\"image_1\",state=\"running\"} 1",
+            "oxker_container_state{id=\"2\",name=\"container_2\",image=\"image_2\",state=\"exited\"} 1",
+            "oxker_container_state{id=\"2\",name=\"container_2\",image=\"image_2\",state=\"running\"} 0",
+            "oxker_container_state{id=\"1\",name=\"container_1\",image=\"image_1\",state=\"exited\"} 0",
+            "oxker_container_health{id=\"1\",name=\"container_1\",image=\"image_1\",health=\"healthy\"} 1",
+            "oxker_container_health{id=\"2\",name=\"container_2\",image=\"image_2\",health=\"none\"} 1",
+            "oxker_container_health{id=\"1\",name=\"container_1\",image=\"image_1\",health=\"none\"} 0",
+            "oxker_container_health{id=\"1\",name=\"container_1\",image=\"image_1\",health=\"unhealthy\"} 0",
+            "oxker_container_pids{id=\"3\",name=\"container_\\\"3\\\"\",image=\"image_3\"} 0",
+        ] {
+            assert!(lines.contains(&line), "{line}");
+        }
+
+        app_data.error = Some(AppError::DockerConnect);
+        assert!(exposition(&app_data).contains("\noxker_docker_up 0\n"));
+    }
+
+    #[test]
+    /// A container that isn't running exports 0 for cpu & memory, even with a stats history
+    fn test_metrics_exposition_exited() {
+        let (_, mut containers) = gen_containers();
+        containers[1].state = State::Exited;
+        containers[1].cpu_stats = VecDeque::from([CpuStats::new(50.0)]);
+        containers[1].mem_stats = VecDeque::from([ByteStats::new(2048)]);
+        containers[1].mem_limit = ByteStats::new(4096);
+
+        let result = exposition(&gen_appdata(&containers));
+        let lines = result.lines().collect::<Vec<_>>();
+        for line in [
+            "oxker_container_cpu_usage_percent{id=\"2\",name=\"container_2\",image=\"image_2\"} 0",
+            "oxker_container_memory_usage_bytes{id=\"2\",name=\"container_2\",image=\"image_2\"} 0",
+            "oxker_container_memory_limit_bytes{id=\"2\",name=\"container_2\",image=\"image_2\"} 4096",
+        ] {
+            assert!(lines.contains(&line), "{line}");
+        }
+    }
+
+    #[test]
+    /// Only GET & HEAD requests to the metrics path return the metrics, HEAD responses have no body
+    fn test_metrics_response() {
+        let (_, containers) = gen_containers();
+        let app_data = Arc::new(Mutex::new(gen_appdata(&containers)));
+
+        let result = response(
+            "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            &app_data,
+        );
+        assert!(result.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(result.contains("Content-Type: text/plain; version=0.0.4; charset=utf-8\r\n"));
+        let (headers, body) = result.split_once("\r\n\r\n").unwrap();
+        assert!(headers.contains(&format!("Content-Length: {}\r\n", body.len())));
+        assert!(body.contains("oxker_container_state{id=\"1\""));
+
+        let result = response("GET /metrics?x=1 HTTP/1.1\r\n\r\n", &app_data);
+        assert!(result.starts_with("HTTP/1.1 200 OK\r\n"));
+
+        let result = response("HEAD /metrics HTTP/1.1\r\n\r\n", &app_data);
+        assert!(result.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(result.ends_with("\r\n\r\n"));
+
+        let result = response("GET / HTTP/1.1\r\n\r\n", &app_data);
+        assert!(result.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+        let result = response("POST /metrics HTTP/1.1\r\n\r\n", &app_data);
+        assert!(result.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+
+        let result = response("", &app_data);
+        assert!(result.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+    }
+}
@@ -0,0 +1,404 @@
+use std::{io::Write, sync::Arc, time::Duration};
+
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use crate::{
+    app_data::{AppData, ByteStats, ContainerItem, ContainerPorts, CpuStats, Stats},
+    app_error::AppError,
+    config::{Config, OutputFormat},
+    docker_data::csv_quote,
+    ui::{GuiState, Status},
+};
+
+/// Maximum time to wait for the first stats of every running container, before printing whatever is available
+const STATS_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Header of each column, in both the table & csv outputs
+const HEADERS: [&str; 16] = [
+    "timestamp",
+    "id",
+    "name",
+    "image",
+    "state",
+    "health",
+    "status",
+    "cpu",
+    "memory",
+    "memory_limit",
+    "rx",
+    "tx",
+    "block_read",
+    "block_write",
+    "pids",
+    "ports",
+];
+
+/// A single container, in a single snapshot, values are raw numbers, cpu & memory are empty if the container isn't running
+/// Network & block I/O are cumulative totals, the same as the stats recording, so they're available after the first stats sample
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct SnapshotRow {
+    timestamp: String,
+    id: String,
+    name: String,
+    image: String,
+    state: &'static str,
+    health: &'static str,
+    status: String,
+    cpu: Option<f64>,
+    memory: Option<u64>,
+    memory_limit: u64,
+    rx: u64,
+    tx: u64,
+    block_read: u64,
+    block_write: u64,
+    pids: u64,
+    ports: Vec<String>,
+}
+
+/// Format a port in the same style as docker ps, `ip:public->private` if published, else just the private port
+fn format_port(port: &ContainerPorts) -> String {
+    let (ip, private, public) = port.get_all();
+    match (ip.is_empty(), public.is_empty()) {
+        (_, true) => private,
+        (true, false) => format!("{public}->{private}"),
+        (false, false) => format!("{ip}:{public}->{private}"),
+    }
+}
+
+impl SnapshotRow {
+    fn new(item: &ContainerItem, timestamp: &str) -> Self {
+        let alive = item.state.is_alive();
+        Self {
+            timestamp: timestamp.to_owned(),
+            id: item.id.get().to_owned(),
+            name: item.name.get().to_owned(),
+            image: item.image.get().to_owned(),
+            state: item.state.label(),
+            health: item.status.health(),
+            status: item.status.get().clone(),
+            cpu: alive
+                .then(|| {
+                    item.cpu_stats
+                        .back()
+                        .map(|i| (i.get_value() * 100.0).round() / 100.0)
+                })
+                .flatten(),
+            memory: alive
+                .then(|| item.mem_stats.back().map(|i| i.get()))
+                .flatten(),
+            memory_limit: item.mem_limit.get(),
+            rx: item.rx.get(),
+            tx: item.tx.get(),
+            block_read: item.block_read.get(),
+            block_write: item.block_write.get(),
+            pids: item.pids.current,
+            ports: item.ports.iter().map(format_port).collect(),
+        }
+    }
+
+    /// Human readable columns, in the same order as HEADERS
+    fn to_table(&self) -> [String; 16] {
+        let bytes = |x: u64| ByteStats::new(x).to_string();
+        [
+            self.timestamp.clone(),
+            self.id.chars().take(8).collect(),
+            self.name.clone(),
+            self.image.clone(),
+            self.state.to_owned(),
+            self.health.to_owned(),
+            self.status.clone(),
+            self.cpu
+                .map_or_else(String::new, |i| CpuStats::new(i).to_string()),
+            self.memory.map_or_else(String::new, bytes),
+            bytes(self.memory_limit),
+            bytes(self.rx),
+            bytes(self.tx),
+            bytes(self.block_read),
+            bytes(self.block_write),
+            self.pids.to_string(),
+            self.ports.join(", "),
+        ]
+    }
+
+    /// Raw values, in the same order as HEADERS
+    fn to_csv(&self) -> String {
+        let option = |x: Option<String>| x.unwrap_or_default();
+        [
+            self.timestamp.clone(),
+            csv_quote(&self.id),
+            csv_quote(&self.name),
+            csv_quote(&self.image),
+            self.state.to_owned(),
+            self.health.to_owned(),
+            csv_quote(&self.status),
+            option(self.cpu.map(|i| format!("{i:.2}"))),
+            option(self.memory.map(|i| i.to_string())),
+            self.memory_limit.to_string(),
+            self.rx.to_string(),
+            self.tx.to_string(),
+            self.block_read.to_string(),
+            self.block_write.to_string(),
+            self.pids.to_string(),
+            csv_quote(&self.ports.join(" ")),
+        ]
+        .join(",")
+    }
+}
+
+/// Every container, including those hidden by a filter, at a single point in time
+fn snapshot(app_data: &AppData, timestamp: &str) -> Vec<SnapshotRow> {
+    app_data
+        .get_all_container_items()
+        .map(|item| SnapshotRow::new(item, timestamp))
+        .collect()
+}
+
+/// Render a snapshot, the header is only included for the table & csv formats when `header` is true
+/// JSON is output as a single line array, so that each snapshot in batch mode is a line of JSON Lines
+fn render(rows: &[SnapshotRow], format: OutputFormat, header: bool) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string(rows).unwrap_or_default() + "\n",
+        OutputFormat::Csv => {
+            let mut output = if header {
+                HEADERS.join(",") + "\n"
+            } else {
+                String::new()
+            };
+            for row in rows {
+                output.push_str(&row.to_csv());
+                output.push('\n');
+            }
+            output
+        }
+        OutputFormat::Table => {
+            let headers = HEADERS.map(str::to_uppercase);
+            let cells = rows.iter().map(SnapshotRow::to_table).collect::<Vec<_>>();
+            let widths = (0..HEADERS.len())
+                .map(|i| {
+                    cells
+                        .iter()
+                        .map(|row| row[i].chars().count())
+                        .chain(header.then(|| headers[i].chars().count()))
+                        .max()
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+            let line = |row: &[String; 16]| {
+                row.iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{cell:<width$}"))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+                    .trim_end()
+                    .to_owned()
+                    + "\n"
+            };
+            header
+                .then(|| line(&headers))
+                .into_iter()
+                .chain(cells.iter().map(line))
+                .collect()
+        }
+    }
+}
+
+/// Wait for DockerData to finish initialising, and for every running container to have a cpu & memory stat
+async fn wait_for_stats(
+    app_data: &Arc<Mutex<AppData>>,
+    gui_state: &Arc<Mutex<GuiState>>,
+) -> Result<(), AppError> {
+    let start = std::time::Instant::now();
+    loop {
+        let err = app_data.lock().get_error();
+        if let Some(err) = err {
+            return Err(err);
+        }
+        let initialised = !gui_state.lock().get_status().contains(&Status::Init);
+        let has_stats = app_data
+            .lock()
+            .get_all_container_items()
+            .filter(|i| i.state.is_alive())
+            .all(|i| !i.cpu_stats.is_empty() && !i.mem_stats.is_empty());
+        if (initialised && has_stats) || start.elapsed() > STATS_TIMEOUT {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+/// Print a snapshot of every container to stdout
+fn print(
+    app_data: &Arc<Mutex<AppData>>,
+    format: OutputFormat,
+    header: bool,
+) -> Result<(), AppError> {
+    let timestamp = jiff::Timestamp::now()
+        .strftime("%Y-%m-%dT%H:%M:%SZ")
+        .to_string();
+    let rows = snapshot(&app_data.lock(), &timestamp);
+    let mut stdout = std::io::stdout().lock();
+    stdout
+        .write_all(render(&rows, format, header).as_bytes())
+        .and_then(|()| stdout.flush())
+        .map_err(|_| AppError::IO("stdout".to_owned()))
+}
+
+/// Run without a gui, printing either a single snapshot with `--once`, or a snapshot at each docker update interval, like `top -b`
+/// GuiState is only used to check that DockerData has finished initialising
+pub async fn start(
+    app_data: Arc<Mutex<AppData>>,
+    gui_state: &Arc<Mutex<GuiState>>,
+    config: &Config,
+) -> Result<(), AppError> {
+    wait_for_stats(&app_data, gui_state).await?;
+    if config.once {
+        return print(&app_data, config.output_format, true);
+    }
+
+    let update_duration = Duration::from_millis(u64::from(config.docker_interval_ms));
+    let mut first = true;
+    loop {
+        let now = std::time::Instant::now();
+        let err = app_data.lock().get_error();
+        if let Some(err) = err {
+            return Err(err);
+        }
+        // Table headers are repeated, each snapshot separated by an empty line, csv headers are only printed once
+        let header = first || config.output_format == OutputFormat::Table;
+        if !first && config.output_format == OutputFormat::Table {
+            println!();
+        }
+        print(&app_data, config.output_format, header)?;
+        first = false;
+        if let Some(to_sleep) = update_duration.checked_sub(now.elapsed()) {
+            tokio::time::sleep(to_sleep).await;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::{collections::VecDeque, net::IpAddr};
+
+    use crate::{
+        app_data::{ByteStats, ContainerPids, ContainerPorts, ContainerStatus, CpuStats, State},
+        config::OutputFormat,
+        tests::{gen_appdata, gen_containers},
+    };
+
+    use super::{SnapshotRow, render, snapshot};
+
+    const TIMESTAMP: &str = "2025-01-01T00:00:00Z";
+
+    fn gen_rows() -> Vec<SnapshotRow> {
+        let (_, mut containers) = gen_containers();
+        containers[0].cpu_stats = VecDeque::from([CpuStats::new(1.0), CpuStats::new(12.345)]);
+        containers[0].mem_stats = VecDeque::from([ByteStats::new(1_500_000)]);
+        containers[0].mem_limit = ByteStats::new(4_000_000);
+        containers[0].rx = ByteStats::new(100);
+        containers[0].tx = ByteStats::new(200);
+        containers[0].block_read = ByteStats::new(3000);
+        containers[0].status = ContainerStatus::from("Up 1 hour (healthy)".to_owned());
+        containers[0].ports.push(ContainerPorts {
+            ip: Some(IpAddr::from([0, 0, 0, 0])),
+            private: 80,
+            public: Some(8080),
+        });
+        containers[1].state = State::Exited;
+        containers[1].cpu_stats = VecDeque::from([CpuStats::new(5.0)]);
+        containers[1].status = ContainerStatus::from("Exited (0) 1 hour ago".to_owned());
+        containers[2].name = "container, \"3\"".into();
+        snapshot(&gen_appdata(&containers), TIMESTAMP)
+    }
+
+    #[test]
+    /// Every container is included, cpu & memory are only set for running containers, ports are formatted like docker ps
+    fn test_output_snapshot() {
+        let rows = gen_rows();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].cpu, Some(12.35));
+        assert_eq!(rows[0].memory, Some(1_500_000));
+        assert_eq!(rows[0].ports, ["8001", "0.0.0.0:8080->80"]);
+        assert_eq!(rows[0].health, "healthy");
+        assert_eq!(rows[1].state, "exited");
+        assert_eq!(rows[1].cpu, None);
+        assert_eq!(rows[1].memory, None);
+    }
+
+    #[test]
+    /// Table columns are aligned, the header is optional
+    fn test_output_render_table() {
+        let rows = gen_rows();
+        let result = render(&rows, OutputFormat::Table, true);
+        let lines = result.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "TIMESTAMP             ID  NAME            IMAGE    STATE    HEALTH   STATUS                 CPU     MEMORY   MEMORY_LIMIT  RX       TX       BLOCK_READ  BLOCK_WRITE  PIDS  PORTS",
+                "2025-01-01T00:00:00Z  1   container_1     image_1  running  healthy  Up 1 hour (healthy)    12.35%  1.50 MB  4.00 MB       0.10 kB  0.20 kB  3.00 kB     0.00 kB      0     8001, 0.0.0.0:8080->80",
+                "2025-01-01T00:00:00Z  2   container_2     image_2  exited   none     Exited (0) 1 hour ago                   0.00 kB       0.00 kB  0.00 kB  0.00 kB     0.00 kB      0     8002",
+                "2025-01-01T00:00:00Z  3   container, \"3\"  image_3  running  none     Up 3 hour                               0.00 kB       0.00 kB  0.00 kB  0.00 kB     0.00 kB      0     8003",
+            ]
+        );
+
+        let result = render(&rows, OutputFormat::Table, false);
+        assert_eq!(result.lines().count(), 3);
+        assert!(result.starts_with("2025-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    /// Csv has a header row, raw values, and quoted fields where required
+    fn test_output_render_csv() {
+        let rows = gen_rows();
+        let result = render(&rows, OutputFormat::Csv, true);
+        let lines = result.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            [
+                "timestamp,id,name,image,state,health,status,cpu,memory,memory_limit,rx,tx,block_read,block_write,pids,ports",
+                "2025-01-01T00:00:00Z,1,container_1,image_1,running,healthy,Up 1 hour (healthy),12.35,1500000,4000000,100,200,3000,0,0,8001 0.0.0.0:8080->80",
+                "2025-01-01T00:00:00Z,2,container_2,image_2,exited,none,Exited (0) 1 hour ago,,,0,0,0,0,0,0,8002",
+                "2025-01-01T00:00:00Z,3,\"container, \"\"3\"\"\",image_3,running,none,Up 3 hour,,,0,0,0,0,0,0,8003",
+            ]
+        );
+        assert_eq!(render(&rows, OutputFormat::Csv, false).lines().count(), 3);
+    }
+
+    #[test]
+    /// Json is a single line array, with raw values, and null for missing stats
+    fn test_output_render_json() {
+        let rows = gen_rows();
+        let result = render(&rows, OutputFormat::Json, true);
+        assert_eq!(result.lines().count(), 1);
+        let json = serde_json::from_str::<serde_json::Value>(&result).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 3);
+        assert_eq!(json[0]["cpu"], 12.35);
+        assert_eq!(json[0]["memory"], 1_500_000);
+        assert_eq!(json[0]["ports"][1], "0.0.0.0:8080->80");
+        assert_eq!(json[1]["cpu"], serde_json::Value::Null);
+        assert_eq!(json[2]["name"], "container, \"3\"");
+    }
+
+    #[test]
+    /// A single stats sample, as used by `--once`, is enough for the network & block I/O totals
+    fn test_output_snapshot_once() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.update_stats_by_id(
+            &ids[0],
+            Some(1.0),
+            Some(100),
+            1000,
+            vec![],
+            (4096, 8192),
+            ContainerPids::default(),
+            1000,
+        );
+        let rows = snapshot(&app_data, TIMESTAMP);
+        assert_eq!(rows[0].block_read, 4096);
+        assert_eq!(rows[0].block_write, 8192);
+        assert_eq!(rows[0].rx, 0);
+    }
+}
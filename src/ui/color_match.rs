@@ -39,16 +39,17 @@ pub mod log_sanitizer {
         )]
     }
 
+    /// Remove all ansi formatting from a given string
+    pub fn strip_ansi(input: &str) -> String {
+        categorise_text(input)
+            .into_iter()
+            .map(|i| i.text)
+            .collect::<String>()
+    }
+
     /// Remove all ansi formatting from a given string and create ratatui Lines
     pub fn remove_ansi<'a>(input: &str) -> Vec<Line<'a>> {
-        vec![Line::from(
-            categorise_text(input)
-                .into_iter()
-                .map(|i| i.text)
-                .collect::<String>()
-                .trim()
-                .to_owned(),
-        )]
+        vec![Line::from(strip_ansi(input).trim().to_owned())]
     }
 
     /// create ratatui Lines that exactly match the given strings
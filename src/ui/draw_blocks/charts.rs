@@ -2,23 +2,42 @@ use std::fmt::Display;
 
 use ratatui::{
     Frame,
-    layout::{Alignment, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
     symbols,
     text::Span,
-    widgets::{Axis, Block, BorderType, Borders, Chart, Dataset, GraphType},
+    widgets::{Axis, Block, BorderType, Borders, Chart, Dataset, GraphType, LegendPosition},
 };
 
+use jiff::{Timestamp, tz::TimeZone};
+
 use super::{CONSTRAINT_50_50, FrameData};
 use crate::{
-    app_data::{ByteStats, CpuStats, State, Stats},
+    app_data::{
+        ByteStats, ChartOverview, CpuStats, MemTuple, OverviewTuple, RateStats, RateTuple,
+        RunningState, State, Stats,
+    },
     config::AppColors,
 };
 
+/// The colour of each container on the overview chart, in order of current usage
+const OVERVIEW_COLORS: [Color; 8] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::Green,
+    Color::Red,
+    Color::Blue,
+    Color::LightCyan,
+    Color::LightMagenta,
+];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ChartVariant {
     Cpu,
     Memory,
+    Net,
+    Io,
 }
 
 impl ChartVariant {
@@ -26,46 +45,58 @@ impl ChartVariant {
         match self {
             Self::Cpu => "cpu",
             Self::Memory => "memory",
+            Self::Net => "net",
+            Self::Io => "io",
         }
     }
 
-    const fn get_title_color(self, colors: AppColors, state: State) -> Color {
+    const fn get_title_color(self, colors: &AppColors, state: State) -> Color {
         if state.is_healthy() {
             match self {
                 Self::Cpu => colors.chart_cpu.title,
                 Self::Memory => colors.chart_memory.title,
+                Self::Net => colors.chart_net.title,
+                Self::Io => colors.chart_io.title,
             }
         } else {
             state.get_color(colors)
         }
     }
 
-    const fn get_bg_color(self, colors: AppColors) -> Color {
+    const fn get_bg_color(self, colors: &AppColors) -> Color {
         match self {
             Self::Cpu => colors.chart_cpu.background,
             Self::Memory => colors.chart_memory.background,
+            Self::Net => colors.chart_net.background,
+            Self::Io => colors.chart_io.background,
         }
     }
 
-    const fn get_border_color(self, colors: AppColors) -> Color {
+    const fn get_border_color(self, colors: &AppColors) -> Color {
         match self {
             Self::Cpu => colors.chart_cpu.border,
             Self::Memory => colors.chart_memory.border,
+            Self::Net => colors.chart_net.border,
+            Self::Io => colors.chart_io.border,
         }
     }
 
-    const fn get_y_axis_color(self, colors: AppColors) -> Color {
+    const fn get_y_axis_color(self, colors: &AppColors) -> Color {
         match self {
             Self::Cpu => colors.chart_cpu.y_axis,
             Self::Memory => colors.chart_memory.y_axis,
+            Self::Net => colors.chart_net.y_axis,
+            Self::Io => colors.chart_io.y_axis,
         }
     }
 
-    const fn get_max_color(self, colors: AppColors, state: State) -> Color {
+    const fn get_max_color(self, colors: &AppColors, state: State) -> Color {
         if state.is_healthy() {
             match self {
                 Self::Cpu => colors.chart_cpu.max,
                 Self::Memory => colors.chart_memory.max,
+                Self::Net => colors.chart_net.max,
+                Self::Io => colors.chart_io.max,
             }
         } else {
             state.get_color(colors)
@@ -73,13 +104,89 @@ impl ChartVariant {
     }
 }
 
+/// Format a timestamp, in ms, as a wall-clock time, None if the time isn't known
+fn format_time(timestamp_ms: i64, timezone: Option<&TimeZone>) -> Option<String> {
+    if timestamp_ms == 0 {
+        return None;
+    }
+    Timestamp::from_millisecond(timestamp_ms).ok().map(|ts| {
+        ts.to_zoned(timezone.cloned().unwrap_or(TimeZone::UTC))
+            .strftime("%H:%M:%S")
+            .to_string()
+    })
+}
+
+/// The x-axis, covering the given number of points, labelled with the time of the first & last points, if known
+fn make_x_axis<'a>(
+    chart_variant: ChartVariant,
+    colors: &AppColors,
+    x_max: f64,
+    fd: Option<&FrameData>,
+) -> Axis<'a> {
+    let axis = Axis::default().bounds([0.00, x_max]);
+    let labels = fd.and_then(|fd| {
+        let time = |i: Option<&i64>| i.and_then(|i| format_time(*i, fd.timezone.as_ref()));
+        time(fd.chart_times.first()).zip(time(fd.chart_times.last()))
+    });
+    if let Some((first, last)) = labels {
+        axis.labels(vec![Span::raw(first), Span::raw(last)])
+            .style(Style::new().fg(chart_variant.get_y_axis_color(colors)))
+    } else {
+        axis
+    }
+}
+
+/// The cursor used to inspect the history of the cpu & memory charts
+struct ChartCursor {
+    index: usize,
+    time: Option<String>,
+}
+
+impl ChartCursor {
+    /// The cursor is an offset from the most recent point, limited by the number of points in the chart
+    fn new(fd: &FrameData, len: usize) -> Option<Self> {
+        let offset = fd.chart_cursor?;
+        let last = len.checked_sub(1)?;
+        let index = last - offset.min(last);
+        Some(Self {
+            index,
+            time: fd
+                .chart_times
+                .get(index)
+                .and_then(|i| format_time(*i, fd.timezone.as_ref())),
+        })
+    }
+
+    /// A vertical line at the cursor, from zero to the given y-axis max
+    #[allow(clippy::cast_precision_loss)]
+    const fn line(&self, y_max: f64) -> [(f64, f64); 2] {
+        [(self.index as f64, 0.0), (self.index as f64, y_max)]
+    }
+
+    /// The value at the cursor, with the time of the sample if known
+    fn title(&self, value: &str) -> String {
+        self.time
+            .as_ref()
+            .map_or_else(|| value.to_owned(), |time| format!("{value} @ {time}"))
+    }
+}
+
+/// Create a vertical cursor line dataset
+fn cursor_dataset(line: &[(f64, f64)], color: Color) -> Dataset<'_> {
+    Dataset::default()
+        .marker(symbols::Marker::Dot)
+        .style(Style::default().fg(color))
+        .graph_type(GraphType::Line)
+        .data(line)
+}
+
 /// Create charts
-fn make_chart<'a, T: Stats + Display>(
+fn make_chart<'a, C: Display, T: Stats + Display>(
     chart_variant: ChartVariant,
-    colors: AppColors,
-    current: &'a T,
+    colors: &AppColors,
+    current: &C,
     dataset: Vec<Dataset<'a>>,
-    max: &'a T,
+    (max, x_axis): (&'a T, Axis<'a>),
     state: State,
 ) -> Chart<'a> {
     let max_color = chart_variant.get_max_color(colors, state);
@@ -100,7 +207,7 @@ fn make_chart<'a, T: Stats + Display>(
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(chart_variant.get_border_color(colors))),
         )
-        .x_axis(Axis::default().bounds([0.00, 60.0]))
+        .x_axis(x_axis)
         .y_axis(
             Axis::default()
                 .labels(vec![
@@ -116,51 +223,320 @@ fn make_chart<'a, T: Stats + Display>(
         )
 }
 
-/// Draw the cpu + mem charts
-pub fn draw(area: Rect, colors: AppColors, f: &mut Frame, fd: &FrameData) {
-    if let Some((cpu, mem)) = fd.chart_data.as_ref() {
-        let area = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(CONSTRAINT_50_50)
-            .split(area);
+/// Create a chart of two per second rates, such as network rx & tx, or block I/O read & write, the x-axis covers the chart window
+fn make_rate_chart<'a>(
+    chart_variant: ChartVariant,
+    colors: &AppColors,
+    data: &'a RateTuple,
+    labels: (&str, &str),
+    x_max: f64,
+) -> Chart<'a> {
+    let (first_color, second_color) = match chart_variant {
+        ChartVariant::Net => (colors.chart_net.rx, colors.chart_net.tx),
+        _ => (colors.chart_io.read, colors.chart_io.write),
+    };
+    let (first, second, max, state) = data;
+    let dataset = vec![
+        Dataset::default()
+            .marker(symbols::Marker::Dot)
+            .style(Style::default().fg(first_color))
+            .graph_type(GraphType::Line)
+            .data(first),
+        Dataset::default()
+            .marker(symbols::Marker::Dot)
+            .style(Style::default().fg(second_color))
+            .graph_type(GraphType::Line)
+            .data(second),
+    ];
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let current = |data: &[(f64, f64)]| RateStats::new(data.last().map_or(0, |f| f.1 as u64));
+    let current = format!(
+        "{} {} {} {}",
+        labels.0,
+        current(first),
+        labels.1,
+        current(second)
+    );
+    make_chart(
+        chart_variant,
+        colors,
+        &current,
+        dataset,
+        (max, make_x_axis(chart_variant, colors, x_max, None)),
+        *state,
+    )
+}
 
-        let cpu_dataset = vec![
-            Dataset::default()
-                .marker(symbols::Marker::Dot)
-                .style(Style::default().fg(colors.chart_cpu.points))
-                .graph_type(GraphType::Line)
-                .data(&cpu.0),
-        ];
-        let mem_dataset = vec![
+/// The memory chart data, either in bytes, or as a percentage of the memory limit
+/// A horizontal limit line is drawn when showing percentages, or once the current usage reaches the warning threshold
+struct MemChart {
+    dataset: Vec<(f64, f64)>,
+    limit_line: Option<[(f64, f64); 2]>,
+    cursor_line: Option<[(f64, f64); 2]>,
+    current: String,
+    max: ChartMax,
+    state: State,
+    x_max: f64,
+}
+
+/// The y-axis max of the memory & overview charts, either in bytes, or as a percentage
+enum ChartMax {
+    Bytes(ByteStats),
+    Percentage(CpuStats),
+}
+
+impl MemChart {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn new(mem: &MemTuple, fd: &FrameData) -> Self {
+        let (dataset, max, limit, state) = mem;
+        let limit_value = limit.get_value();
+        let cursor = ChartCursor::new(fd, dataset.len());
+        let current = mem.0.last().map_or(0.0, |f| f.1);
+        let selected = cursor
+            .as_ref()
+            .and_then(|cursor| dataset.get(cursor.index))
+            .map_or(current, |f| f.1);
+        let title = |value: String| {
+            cursor
+                .as_ref()
+                .map_or_else(|| value.clone(), |cursor| cursor.title(&value))
+        };
+        let x_max = fd.chart_points as f64;
+        if fd.show_mem_percent && limit_value > 0.0 {
+            let dataset = dataset
+                .iter()
+                .map(|(x, y)| (*x, y / limit_value * 100.0))
+                .collect::<Vec<_>>();
+            Self {
+                dataset,
+                limit_line: Some([(0.0, 100.0), (x_max, 100.0)]),
+                cursor_line: cursor.as_ref().map(|cursor| cursor.line(100.0)),
+                current: title(CpuStats::new(selected / limit_value * 100.0).to_string()),
+                max: ChartMax::Percentage(CpuStats::new(100.0)),
+                state: *state,
+                x_max,
+            }
+        } else {
+            let near_limit = limit_value > 0.0
+                && current >= limit_value * f64::from(fd.memory_thresholds.0) / 100.0;
+            let max = if near_limit { (*max).max(*limit) } else { *max };
+            Self {
+                dataset: dataset.clone(),
+                limit_line: near_limit.then_some([(0.0, limit_value), (x_max, limit_value)]),
+                cursor_line: cursor.as_ref().map(|cursor| cursor.line(max.get_value())),
+                current: title(ByteStats::new(selected as u64).to_string()),
+                max: ChartMax::Bytes(max),
+                state: *state,
+                x_max,
+            }
+        }
+    }
+
+    /// Create the memory chart, the limit & cursor lines are drawn first, so that the data points are drawn on top of them
+    fn make_chart<'a>(&'a self, colors: &AppColors, fd: &FrameData) -> Chart<'a> {
+        let mut dataset = self
+            .limit_line
+            .as_ref()
+            .map(|line| cursor_dataset(line, colors.chart_memory.limit))
+            .into_iter()
+            .chain(
+                self.cursor_line
+                    .as_ref()
+                    .map(|line| cursor_dataset(line, colors.chart_memory.max)),
+            )
+            .collect::<Vec<_>>();
+        dataset.push(
             Dataset::default()
                 .marker(symbols::Marker::Dot)
                 .style(Style::default().fg(colors.chart_memory.points))
                 .graph_type(GraphType::Line)
-                .data(&mem.0),
-        ];
-
-        let cpu_stats = CpuStats::new(cpu.0.last().map_or(0.00, |f| f.1));
-        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-        let mem_stats = ByteStats::new(mem.0.last().map_or(0, |f| f.1 as u64));
-        let cpu_chart = make_chart(
-            ChartVariant::Cpu,
-            colors,
-            &cpu_stats,
-            cpu_dataset,
-            &cpu.1,
-            cpu.2,
-        );
-        let mem_chart = make_chart(
-            ChartVariant::Memory,
-            colors,
-            &mem_stats,
-            mem_dataset,
-            &mem.1,
-            mem.2,
+                .data(&self.dataset),
         );
+        let x_axis = make_x_axis(ChartVariant::Memory, colors, self.x_max, Some(fd));
+        match &self.max {
+            ChartMax::Bytes(max) => make_chart(
+                ChartVariant::Memory,
+                colors,
+                &self.current,
+                dataset,
+                (max, x_axis),
+                self.state,
+            ),
+            ChartMax::Percentage(max) => make_chart(
+                ChartVariant::Memory,
+                colors,
+                &self.current,
+                dataset,
+                (max, x_axis),
+                self.state,
+            ),
+        }
+    }
+}
+
+/// The overview chart, the cpu, or memory, of multiple containers, each container is named in the legend
+struct OverviewChart<'a> {
+    variant: ChartVariant,
+    series: Vec<(String, &'a [(f64, f64)])>,
+    max: ChartMax,
+    x_max: f64,
+}
+
+impl<'a> OverviewChart<'a> {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn new(overview: &'a OverviewTuple, fd: &FrameData) -> Self {
+        let (metric, series, max) = overview;
+        let format = |value: f64| match metric {
+            ChartOverview::Memory => ByteStats::new(value as u64).to_string(),
+            _ => CpuStats::new(value).to_string(),
+        };
+        Self {
+            variant: if metric == &ChartOverview::Memory {
+                ChartVariant::Memory
+            } else {
+                ChartVariant::Cpu
+            },
+            series: series
+                .iter()
+                .map(|(name, dataset)| {
+                    let current = dataset.last().map_or(0.0, |i| i.1);
+                    (format!("{name} {}", format(current)), dataset.as_slice())
+                })
+                .collect(),
+            max: if metric == &ChartOverview::Memory {
+                ChartMax::Bytes(ByteStats::new(*max as u64))
+            } else {
+                ChartMax::Percentage(CpuStats::new(*max))
+            },
+            x_max: fd.chart_points as f64,
+        }
+    }
+
+    /// Create the overview chart, the legend is hidden if it would cover more than half of the chart
+    fn make_chart(&'a self, colors: &AppColors) -> Chart<'a> {
+        let dataset = self
+            .series
+            .iter()
+            .zip(OVERVIEW_COLORS.iter().cycle())
+            .map(|((name, data), color)| {
+                Dataset::default()
+                    .name(name.as_str())
+                    .marker(symbols::Marker::Dot)
+                    .style(Style::default().fg(*color))
+                    .graph_type(GraphType::Line)
+                    .data(data)
+            })
+            .collect::<Vec<_>>();
+        let x_axis = make_x_axis(self.variant, colors, self.x_max, None);
+        let chart = match &self.max {
+            ChartMax::Bytes(max) => make_chart(
+                self.variant,
+                colors,
+                &"overview",
+                dataset,
+                (max, x_axis),
+                State::Running(RunningState::Healthy),
+            ),
+            ChartMax::Percentage(max) => make_chart(
+                self.variant,
+                colors,
+                &"overview",
+                dataset,
+                (max, x_axis),
+                State::Running(RunningState::Healthy),
+            ),
+        };
+        chart
+            .legend_position(Some(LegendPosition::TopLeft))
+            .hidden_legend_constraints((Constraint::Ratio(1, 2), Constraint::Ratio(1, 1)))
+    }
+}
+
+/// Draw the cpu + mem charts, or the overview chart, and the network & block I/O charts if enabled
+pub fn draw(area: Rect, colors: &AppColors, f: &mut Frame, fd: &FrameData) {
+    if fd.chart_data.is_some() || fd.overview_data.is_some() {
+        #[allow(clippy::cast_precision_loss)]
+        let x_max = fd.chart_points as f64;
+        let rate_charts = [
+            fd.net_chart_data
+                .as_ref()
+                .map(|data| make_rate_chart(ChartVariant::Net, colors, data, ("↓", "↑"), x_max)),
+            fd.io_chart_data
+                .as_ref()
+                .map(|data| make_rate_chart(ChartVariant::Io, colors, data, ("r", "w"), x_max)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
 
-        f.render_widget(cpu_chart, area[0]);
-        f.render_widget(mem_chart, area[1]);
+        let constraints = if rate_charts.is_empty() {
+            CONSTRAINT_50_50.to_vec()
+        } else {
+            let total = u32::try_from(rate_charts.len() + 2).unwrap_or(2);
+            (0..total).map(|_| Constraint::Ratio(1, total)).collect()
+        };
+        let area = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(area);
+
+        if let Some(overview) = fd.overview_data.as_ref() {
+            let overview_chart = OverviewChart::new(overview, fd);
+            f.render_widget(overview_chart.make_chart(colors), area[0].union(area[1]));
+        } else if let Some((cpu, mem)) = fd.chart_data.as_ref() {
+            let cpu_cursor = ChartCursor::new(fd, cpu.0.len());
+            let cpu_cursor_line = cpu_cursor
+                .as_ref()
+                .map(|cursor| cursor.line(cpu.1.get_value()));
+            let cpu_dataset = cpu_cursor_line
+                .as_ref()
+                .map(|line| cursor_dataset(line, colors.chart_cpu.max))
+                .into_iter()
+                .chain([Dataset::default()
+                    .marker(symbols::Marker::Dot)
+                    .style(Style::default().fg(colors.chart_cpu.points))
+                    .graph_type(GraphType::Line)
+                    .data(&cpu.0)])
+                .collect::<Vec<_>>();
+            #[allow(clippy::cast_precision_loss)]
+            let x_max = fd.chart_points as f64;
+            let cpu_stats = cpu_cursor.as_ref().map_or_else(
+                || CpuStats::new(cpu.0.last().map_or(0.00, |f| f.1)).to_string(),
+                |cursor| {
+                    cursor.title(
+                        &CpuStats::new(cpu.0.get(cursor.index).map_or(0.00, |f| f.1)).to_string(),
+                    )
+                },
+            );
+            let cpu_chart = make_chart(
+                ChartVariant::Cpu,
+                colors,
+                &cpu_stats,
+                cpu_dataset,
+                (
+                    &cpu.1,
+                    make_x_axis(ChartVariant::Cpu, colors, x_max, Some(fd)),
+                ),
+                cpu.2,
+            );
+            let mem_chart = MemChart::new(mem, fd);
+            let mem_chart = mem_chart.make_chart(colors, fd);
+
+            f.render_widget(cpu_chart, area[0]);
+            f.render_widget(mem_chart, area[1]);
+        }
+
+        for (chart, rect) in rate_charts.into_iter().zip(area.iter().skip(2)) {
+            f.render_widget(chart, *rect);
+        }
     }
 }
 
@@ -171,10 +547,11 @@ mod tests {
     use ratatui::style::{Color, Modifier};
 
     use crate::{
-        app_data::State,
+        app_data::{ByteStats, ContainerPids, State},
         config::AppColors,
+        tests::gen_networks,
         ui::{
-            FrameData,
+            FrameData, Status,
             draw_blocks::tests::{COLOR_ORANGE, get_result, insert_chart_data, test_setup},
         },
     };
@@ -241,7 +618,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
@@ -270,6 +647,88 @@ mod tests {
         }
     }
 
+    #[test]
+    /// When show_io_chart is enabled, the block I/O chart is drawn alongside the cpu & memory charts, with read & write points in their own colors
+    fn test_draw_blocks_charts_io() {
+        let mut setup = test_setup(120, 10, true, true);
+        insert_chart_data(&setup);
+        setup.app_data.lock().config.show_io_chart = true;
+        for i in 1..=6 {
+            setup.app_data.lock().update_stats_by_id(
+                &setup.ids[0],
+                None,
+                None,
+                0,
+                vec![],
+                (i * i * 10000, i * 5000),
+                ContainerPids::default(),
+                i64::try_from(i).unwrap() * 1000,
+            );
+        }
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let colors = get_result(&setup)
+            .flat_map(|(_, row)| row.iter().skip(80))
+            .filter(|cell| cell.symbol() == "•")
+            .map(|cell| cell.fg)
+            .collect::<Vec<_>>();
+        assert!(colors.contains(&Color::Blue));
+        assert!(colors.contains(&Color::LightRed));
+        assert!(
+            colors
+                .iter()
+                .all(|i| [Color::Blue, Color::LightRed].contains(i))
+        );
+    }
+
+    #[test]
+    /// When show_net_chart & show_io_chart are both enabled, four charts are drawn, with the network chart third
+    fn test_draw_blocks_charts_net() {
+        let mut setup = test_setup(160, 10, true, true);
+        insert_chart_data(&setup);
+        setup.app_data.lock().config.show_io_chart = true;
+        setup.app_data.lock().config.show_net_chart = true;
+        for i in 1..=6 {
+            setup.app_data.lock().update_stats_by_id(
+                &setup.ids[0],
+                None,
+                None,
+                0,
+                gen_networks(i * i * 10000, i * 5000),
+                (0, 0),
+                ContainerPids::default(),
+                i64::try_from(i).unwrap() * 1000,
+            );
+        }
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let net_colors = [Color::Rgb(255, 233, 193), Color::Rgb(205, 140, 140)];
+        let colors = get_result(&setup)
+            .flat_map(|(_, row)| row.iter().skip(80).take(40))
+            .filter(|cell| cell.symbol() == "•")
+            .map(|cell| cell.fg)
+            .collect::<Vec<_>>();
+        assert!(colors.contains(&net_colors[0]));
+        assert!(colors.contains(&net_colors[1]));
+        assert!(colors.iter().all(|i| net_colors.contains(i)));
+    }
+
     #[test]
     /// When status is Running, charts correctly drawn
     fn test_draw_blocks_charts_running_some() {
@@ -281,7 +740,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
 
@@ -306,6 +765,11 @@ mod tests {
                         assert_eq!(result_cell.fg, Color::Cyan);
                         assert!(result_cell.modifier.is_empty());
                     }
+                    // usage has reached the limit, so the limit line is drawn
+                    (5, 51..=78) => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                        assert!(result_cell.modifier.is_empty());
+                    }
                     (0 | 9, 0..=80) | (1..=9, 0 | 7 | 39 | 40 | 50 | 79) => {
                         assert_eq!(result_cell.fg, Color::White);
                         assert!(result_cell.modifier.is_empty());
@@ -319,6 +783,193 @@ mod tests {
         }
     }
 
+    #[test]
+    /// When showing the memory percentage, the memory chart is relative to the limit, with the limit line at the top
+    fn test_draw_blocks_charts_mem_percent() {
+        let mut setup = test_setup(80, 10, true, true);
+        insert_chart_data(&setup);
+        setup.app_data.lock().toggle_show_mem_percent();
+        setup.app_data.lock().containers.items[0].mem_limit = ByteStats::new(200_000);
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        let rows = get_result(&setup)
+            .map(|(_, row)| {
+                row.iter()
+                    .skip(40)
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert!(rows[0].contains("memory 15.00%"));
+        assert!(rows[1].contains("100.00%"));
+
+        // The limit line is drawn along the top row of the memory chart
+        assert!(
+            get_result(&setup)
+                .find(|(row_index, _)| *row_index == 1)
+                .unwrap()
+                .1
+                .iter()
+                .skip(49)
+                .take(29)
+                .all(|i| i.symbol() == "•" && i.fg == Color::Red)
+        );
+    }
+
+    #[test]
+    /// The overview chart replaces the cpu & memory charts, each container is drawn in its own colour, and named in the legend with its current value
+    fn test_draw_blocks_charts_overview() {
+        let mut setup = test_setup(80, 10, true, true);
+        for i in 1..=10u32 {
+            for (index, id) in setup.ids.iter().enumerate() {
+                let multiplier = f64::from(u32::try_from(index).unwrap() + 1);
+                setup.app_data.lock().update_stats_by_id(
+                    id,
+                    Some(f64::from(i) * multiplier),
+                    Some(u64::from(i) * 10000),
+                    0,
+                    vec![],
+                    (0, 0),
+                    ContainerPids::default(),
+                    0,
+                );
+            }
+        }
+        setup.app_data.lock().cycle_chart_overview();
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        let rows = get_result(&setup)
+            .map(|(_, row)| {
+                row.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert!(rows[0].contains(" cpu overview "));
+        assert!(rows[1].contains("30.00%"));
+
+        // Legend is ordered by current usage, and each container is drawn in its own colour
+        for (name, color) in [
+            ("container_3 30.00%", Color::Cyan),
+            ("container_2 20.00%", Color::Magenta),
+            ("container_1 10.00%", Color::Yellow),
+        ] {
+            let (row_index, row) = rows
+                .iter()
+                .enumerate()
+                .find(|(_, row)| row.contains(name))
+                .unwrap();
+            let column = row[..row.find(name).unwrap()].chars().count();
+            let cells = get_result(&setup)
+                .find(|(index, _)| *index == row_index)
+                .unwrap()
+                .1;
+            assert!(
+                cells
+                    .iter()
+                    .skip(column)
+                    .take(name.len())
+                    .all(|i| i.fg == color)
+            );
+            assert!(
+                get_result(&setup)
+                    .flat_map(|(_, row)| row)
+                    .any(|i| i.symbol() == "•" && i.fg == color)
+            );
+        }
+
+        setup.app_data.lock().cycle_chart_overview();
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+        let rows = get_result(&setup)
+            .map(|(_, row)| {
+                row.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert!(rows[0].contains(" memory overview "));
+        assert!(rows[1].contains("100.00 kB"));
+    }
+
+    #[test]
+    /// With the chart cursor active, the values & time at the cursor are shown in the titles, and the x-axis is labelled with the time of the first & last points
+    fn test_draw_blocks_charts_cursor() {
+        let mut setup = test_setup(80, 12, true, true);
+        for i in 1..=10u32 {
+            setup.app_data.lock().update_stats_by_id(
+                &setup.ids[0],
+                Some(f64::from(i)),
+                Some(u64::from(i) * 10000),
+                1_000_000,
+                vec![],
+                (0, 0),
+                ContainerPids::default(),
+                1_735_689_600_000 + i64::from(i) * 1000,
+            );
+        }
+        setup.gui_state.lock().status_push(Status::ChartCursor);
+        for _ in 0..3 {
+            setup.gui_state.lock().chart_cursor_left(10);
+        }
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        let rows = get_result(&setup)
+            .map(|(_, row)| {
+                row.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert!(rows[0].contains("cpu 07.00% @ 00:00:07"));
+        assert!(rows[0].contains("memory 70.00 kB @ 00:00:07"));
+        assert!(rows[10].contains("00:00:01"));
+        assert!(rows[10].contains("00:00:10"));
+
+        // The cursor can't move past the oldest point
+        for _ in 0..20 {
+            setup.gui_state.lock().chart_cursor_left(10);
+        }
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        assert_eq!(fd.chart_cursor, Some(9));
+
+        setup.gui_state.lock().status_del(Status::ChartCursor);
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        assert!(fd.chart_cursor.is_none());
+    }
+
     #[test]
     /// Whens status paused, some text is now Yellow
     fn test_draw_blocks_charts_paused() {
@@ -331,7 +982,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
 
@@ -352,6 +1003,11 @@ mod tests {
                         assert_eq!(result_cell.fg, Color::Cyan);
                         assert!(result_cell.modifier.is_empty());
                     }
+                    // usage has reached the limit, so the limit line is drawn
+                    (5, 51..=78) => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                        assert!(result_cell.modifier.is_empty());
+                    }
                     (0 | 9, 0..=80) | (1..=9, 0 | 7 | 39 | 40 | 50 | 79) => {
                         assert_eq!(result_cell.fg, Color::White);
                         assert!(result_cell.modifier.is_empty());
@@ -376,7 +1032,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
@@ -395,6 +1051,11 @@ mod tests {
                         assert_eq!(result_cell.fg, Color::Cyan);
                         assert!(result_cell.modifier.is_empty());
                     }
+                    // usage has reached the limit, so the limit line is drawn
+                    (5, 51..=78) => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                        assert!(result_cell.modifier.is_empty());
+                    }
                     (0 | 9, 0..=80) | (1..=9, 0 | 7 | 39 | 40 | 50 | 79) => {
                         assert_eq!(result_cell.fg, Color::White);
                         assert!(result_cell.modifier.is_empty());
@@ -426,6 +1087,7 @@ mod tests {
         colors.chart_memory.max = Color::Magenta;
         colors.chart_memory.points = Color::Black;
         colors.chart_memory.y_axis = Color::Blue;
+        colors.chart_memory.limit = Color::Yellow;
 
         let mut setup = test_setup(80, 10, true, true);
 
@@ -435,7 +1097,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, colors, f, &fd);
+                super::draw(setup.area, &colors, f, &fd);
             })
             .unwrap();
 
@@ -462,6 +1124,10 @@ mod tests {
                     xy if CPU_XY.contains(&xy) | MEM_XY.contains(&xy) => {
                         assert_eq!(result_cell.fg, Color::Black);
                     }
+                    // memory limit line
+                    (5, 51..=78) => {
+                        assert_eq!(result_cell.fg, Color::Yellow);
+                    }
                     // y axis
                     (1..=8, 7 | 50) => {
                         assert_eq!(result_cell.fg, Color::Blue);
@@ -21,7 +21,7 @@ use super::generate_block;
 pub fn draw(
     app_data: &Arc<Mutex<AppData>>,
     area: Rect,
-    colors: AppColors,
+    colors: &AppColors,
     f: &mut Frame,
     fd: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
@@ -81,7 +81,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -96,7 +96,7 @@ mod tests {
     /// Test that when DockerCommands are available, they are drawn correctly, dependant on container state
     /// In this test, container is running
     fn test_draw_blocks_commands_some() {
-        let mut setup = test_setup(12, 6, true, true);
+        let mut setup = test_setup(12, 7, true, true);
 
         let colors = setup.app_data.lock().config.app_colors;
         setup
@@ -105,7 +105,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -120,7 +120,7 @@ mod tests {
                 assert_eq!(result_cell.bg, Color::Reset);
                 match (row_index, result_cell_index) {
                     // Borders & delete
-                    (0 | 5, _) | (1..=4, 0 | 11) | (4, 3..=8) => {
+                    (0 | 6, _) | (1..=5, 0 | 11) | (5, 3..=8) => {
                         assert_eq!(result_cell.fg, Color::Gray);
                     }
                     // pause
@@ -135,6 +135,10 @@ mod tests {
                     (3, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Red);
                     }
+                    // recreate
+                    (4, 3..=10) => {
+                        assert_eq!(result_cell.fg, Color::Cyan);
+                    }
                     _ => {
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
@@ -156,7 +160,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -177,7 +181,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -199,8 +203,12 @@ mod tests {
                     (2, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Red);
                     }
+                    // recreate
+                    (3, 3..=10) => {
+                        assert_eq!(result_cell.fg, Color::Cyan);
+                    }
                     // delete
-                    (0 | 5, _) | (1..=4, 0 | 11) | (3, 3..=8) => {
+                    (0 | 5, _) | (1..=4, 0 | 11) | (4, 3..=8) => {
                         assert_eq!(result_cell.fg, Color::Gray);
                     }
                     _ => {
@@ -223,7 +231,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -252,7 +260,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -281,13 +289,14 @@ mod tests {
     #[test]
     /// Custom colors are rendered correctly
     fn test_draw_blocks_commands_custom_colors_running() {
-        let mut setup = test_setup(12, 6, true, true);
+        let mut setup = test_setup(12, 7, true, true);
         let mut colors = AppColors::new();
         colors.commands.background = Color::White;
         colors.commands.pause = Color::Black;
         colors.commands.restart = Color::Green;
         colors.commands.stop = Color::Blue;
         colors.commands.delete = Color::Magenta;
+        colors.commands.recreate = Color::Red;
         colors.commands.resume = Color::Yellow;
         colors.commands.start = Color::Cyan;
 
@@ -297,7 +306,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -322,8 +331,12 @@ mod tests {
                     (3, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Blue);
                     }
+                    // recreate
+                    (4, 3..=10) => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                    }
                     // delete
-                    (4, 3..=8) => {
+                    (5, 3..=8) => {
                         assert_eq!(result_cell.fg, Color::Magenta);
                     }
                     _ => (),
@@ -341,6 +354,7 @@ mod tests {
         colors.commands.restart = Color::Green;
         colors.commands.stop = Color::Blue;
         colors.commands.delete = Color::Magenta;
+        colors.commands.recreate = Color::Red;
         colors.commands.resume = Color::Yellow;
         colors.commands.start = Color::Cyan;
 
@@ -350,7 +364,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -371,7 +385,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -394,8 +408,12 @@ mod tests {
                     (2, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Blue);
                     }
+                    // recreate
+                    (3, 3..=10) => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                    }
                     // delete
-                    (3, 3..=8) => {
+                    (4, 3..=8) => {
                         assert_eq!(result_cell.fg, Color::Magenta);
                     }
                     _ => (),
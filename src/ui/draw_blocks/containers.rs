@@ -11,18 +11,60 @@ use ratatui::{
 };
 
 use crate::{
-    app_data::{AppData, ByteStats, Columns, ContainerItem, CpuStats},
+    app_data::{AppData, ByteStats, ContainerItem},
     config::AppColors,
     ui::{FrameData, GuiState, SelectablePanel},
 };
 
 use super::{CIRCLE, generate_block};
 
+/// Format the memory column, either "current / limit", or a percentage of the limit, using the same width in both cases
+fn format_memory(i: &ContainerItem, fd: &FrameData) -> String {
+    let widths = &fd.columns;
+    i.mem_display(fd.show_mem_percent).map_or_else(
+        || {
+            format!(
+                "{:>width_current$} / {:>width_limit$}{MARGIN}",
+                i.mem_stats.back().map_or_else(ByteStats::default, |f| *f),
+                i.mem_limit,
+                width_current = &widths.mem.1.into(),
+                width_limit = &widths.mem.2.into()
+            )
+        },
+        |percentage| {
+            format!(
+                "{percentage:>width$}{MARGIN}",
+                width = usize::from(widths.mem.1) + usize::from(widths.mem.2) + 3
+            )
+        },
+    )
+}
+
+/// Format the optional sparkline column, right aligned, so that the most recent samples line up
+fn format_sparkline<'a>(i: &ContainerItem, fd: &FrameData, style: Style) -> Option<Span<'a>> {
+    fd.sparkline
+        .zip(fd.columns.sparkline)
+        .map(|((metric, samples), (_, width))| {
+            Span::styled(
+                format!(
+                    "{:>width$}{MARGIN}",
+                    i.sparkline(metric, samples),
+                    width = width.into()
+                ),
+                style,
+            )
+        })
+}
+
 /// Format the container data to display nicely on the screen
-fn format_containers<'a>(colors: AppColors, i: &ContainerItem, widths: &Columns) -> Line<'a> {
+/// When the memory usage is near the memory limit, the whole row is recolored, and made bold when at the critical threshold
+/// Containers marked for the merged logs have an underlined name
+#[allow(clippy::too_many_lines)]
+fn format_containers<'a>(colors: &AppColors, i: &ContainerItem, fd: &FrameData) -> Line<'a> {
+    let widths = &fd.columns;
     let state_style = Style::default().fg(i.state.get_color(colors));
 
-    Line::from(vec![
+    let mut line = Line::from(vec![
         Span::styled(
             format!(
                 "{:<width$}{MARGIN}",
@@ -50,21 +92,12 @@ fn format_containers<'a>(colors: AppColors, i: &ContainerItem, widths: &Columns)
         Span::styled(
             format!(
                 "{:>width$}{MARGIN}",
-                i.cpu_stats.back().map_or_else(CpuStats::default, |f| *f),
+                i.cpu_display(fd.cpu_mode),
                 width = &widths.cpu.1.into()
             ),
             state_style,
         ),
-        Span::styled(
-            format!(
-                "{:>width_current$} / {:>width_limit$}{MARGIN}",
-                i.mem_stats.back().map_or_else(ByteStats::default, |f| *f),
-                i.mem_limit,
-                width_current = &widths.mem.1.into(),
-                width_limit = &widths.mem.2.into()
-            ),
-            state_style,
-        ),
+        Span::styled(format_memory(i, fd), state_style),
         Span::styled(
             format!(
                 "{:>width$}{MARGIN}",
@@ -82,21 +115,67 @@ fn format_containers<'a>(colors: AppColors, i: &ContainerItem, widths: &Columns)
             colors.containers.text,
         ),
         Span::styled(
-            format!("{:>width$}{MARGIN}", i.rx, width = widths.net_rx.1.into()),
+            format!(
+                "{:>width$}{MARGIN}",
+                i.rx_display(fd.show_net_total),
+                width = widths.net_rx.1.into()
+            ),
             Style::default().fg(colors.containers.text_rx),
         ),
         Span::styled(
-            format!("{:>width$}{MARGIN}", i.tx, width = widths.net_tx.1.into()),
+            format!(
+                "{:>width$}{MARGIN}",
+                i.tx_display(fd.show_net_total),
+                width = widths.net_tx.1.into()
+            ),
             Style::default().fg(colors.containers.text_tx),
         ),
-    ])
+        Span::styled(
+            format!(
+                "{:>width$}{MARGIN}",
+                i.block_io.current.0,
+                width = widths.block_read.1.into()
+            ),
+            Style::default().fg(colors.containers.text_block_read),
+        ),
+        Span::styled(
+            format!(
+                "{:>width$}{MARGIN}",
+                i.block_io.current.1,
+                width = widths.block_write.1.into()
+            ),
+            Style::default().fg(colors.containers.text_block_write),
+        ),
+        Span::styled(
+            format!("{:>width$}{MARGIN}", i.pids, width = widths.pids.1.into()),
+            Style::default().fg(i.pids.get_color(colors)),
+        ),
+    ]);
+
+    if i.marked {
+        line.spans[0].style = line.spans[0].style.add_modifier(Modifier::UNDERLINED);
+    }
+
+    // The optional sparkline column is placed directly after the memory column
+    if let Some(sparkline) = format_sparkline(i, fd, state_style) {
+        line.spans.insert(5, sparkline);
+    }
+
+    if let Some(style) =
+        i.get_mem_alert_style(colors, fd.memory_thresholds.0, fd.memory_thresholds.1)
+    {
+        for span in &mut line.spans {
+            span.style = span.style.patch(style);
+        }
+    }
+    line
 }
 
 /// Draw the containers panel
 pub fn draw(
     app_data: &Arc<Mutex<AppData>>,
     area: Rect,
-    colors: AppColors,
+    colors: &AppColors,
     f: &mut Frame,
     fd: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
@@ -108,7 +187,7 @@ pub fn draw(
         .lock()
         .get_container_items()
         .iter()
-        .map(|i| ListItem::new(format_containers(colors, i, &fd.columns)))
+        .map(|i| ListItem::new(format_containers(colors, i, fd)))
         .collect::<Vec<_>>();
 
     if items.is_empty() {
@@ -136,17 +215,22 @@ pub fn draw(
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
+    use std::collections::VecDeque;
+
     use insta::assert_snapshot;
     use ratatui::style::{Color, Modifier};
 
     use crate::{
-        app_data::{ContainerImage, ContainerName, ContainerStatus, State, StatefulList},
-        config::AppColors,
+        app_data::{
+            ByteStats, ContainerImage, ContainerName, ContainerStatus, CpuStats, State,
+            StatefulList,
+        },
+        config::{AppColors, SparklineMetric},
         ui::{
             FrameData,
             draw_blocks::tests::{
-                BORDER_CHARS, COLOR_ORANGE, COLOR_RX, COLOR_TX, TuiTestSetup, get_result,
-                test_setup,
+                BORDER_CHARS, COLOR_BLOCK_READ, COLOR_BLOCK_WRITE, COLOR_ORANGE, COLOR_RX,
+                COLOR_TX, TuiTestSetup, get_result, test_setup,
             },
         },
     };
@@ -170,7 +254,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -199,7 +283,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -229,7 +313,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -272,7 +356,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -289,6 +373,46 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Only the name of a container marked for the merged logs is underlined
+    fn test_draw_blocks_containers_marked() {
+        let mut setup = test_setup(130, 6, true, true);
+        setup.app_data.lock().containers.items[1].marked = true;
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    &colors,
+                    f,
+                    &setup.fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+
+        let underlined = get_result(&setup)
+            .flat_map(|(row_index, result_row)| {
+                result_row
+                    .iter()
+                    .filter(|i| i.modifier.contains(Modifier::UNDERLINED))
+                    .map(|i| (row_index, i.symbol().to_owned()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert!(underlined.iter().all(|(row_index, _)| *row_index == 2));
+        assert!(
+            underlined
+                .iter()
+                .map(|(_, symbol)| symbol.as_str())
+                .collect::<String>()
+                .starts_with("container_2")
+        );
+    }
+
     #[test]
     /// Columns on all rows are coloured correctly
     fn test_draw_blocks_containers_colors() {
@@ -303,7 +427,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -329,13 +453,21 @@ mod tests {
                         assert_eq!(result_cell.fg, Color::Green);
                     }
                     // rx column
-                    (1..=3, 92..=101) => {
+                    (1..=3, 92..=103) => {
                         assert_eq!(result_cell.fg, COLOR_RX);
                     }
                     // tx column
-                    (1..=3, 102..=111) => {
+                    (1..=3, 104..=115) => {
                         assert_eq!(result_cell.fg, COLOR_TX);
                     }
+                    // block read column
+                    (1..=3, 116..=127) => {
+                        assert_eq!(result_cell.fg, COLOR_BLOCK_READ);
+                    }
+                    // block write column
+                    (1..=3, 128) => {
+                        assert_eq!(result_cell.fg, COLOR_BLOCK_WRITE);
+                    }
                     _ => assert_eq!(result_cell.fg, Color::Reset),
                 }
             }
@@ -361,7 +493,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -394,13 +526,21 @@ mod tests {
                         assert_eq!(result_cell.fg, Color::Green);
                     }
                     // rx column
-                    (1..=3, 92..=101) => {
+                    (1..=3, 92..=103) => {
                         assert_eq!(result_cell.fg, COLOR_RX);
                     }
                     // tx column
-                    (1..=3, 102..=111) => {
+                    (1..=3, 104..=115) => {
                         assert_eq!(result_cell.fg, COLOR_TX);
                     }
+                    // block read column
+                    (1..=3, 116..=127) => {
+                        assert_eq!(result_cell.fg, COLOR_BLOCK_READ);
+                    }
+                    // block write column
+                    (1..=3, 128) => {
+                        assert_eq!(result_cell.fg, COLOR_BLOCK_WRITE);
+                    }
                     _ => assert_eq!(result_cell.fg, Color::Reset),
                 }
             }
@@ -422,7 +562,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -448,7 +588,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -475,7 +615,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -501,7 +641,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -528,7 +668,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -558,13 +698,17 @@ mod tests {
                         assert_eq!(result_cell.fg, Color::Green);
                     }
                     // rx column
-                    (1..=3, 95..=104) => {
+                    (1..=3, 95..=106) => {
                         assert_eq!(result_cell.fg, COLOR_RX);
                     }
                     // tx column
-                    (1..=3, 105..=114) => {
+                    (1..=3, 107..=118) => {
                         assert_eq!(result_cell.fg, COLOR_TX);
                     }
+                    // block read column
+                    (1..=3, 119..=128) => {
+                        assert_eq!(result_cell.fg, COLOR_BLOCK_READ);
+                    }
                     _ => {
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
@@ -591,7 +735,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -621,13 +765,17 @@ mod tests {
                         assert_eq!(result_cell.fg, Color::Green);
                     }
                     // rx column
-                    (1..=3, 104..=113) => {
+                    (1..=3, 104..=115) => {
                         assert_eq!(result_cell.fg, COLOR_RX);
                     }
                     // tx column
-                    (1..=3, 114..=123) => {
+                    (1..=3, 116..=127) => {
                         assert_eq!(result_cell.fg, COLOR_TX);
                     }
+                    // block read column
+                    (1..=3, 128) => {
+                        assert_eq!(result_cell.fg, COLOR_BLOCK_READ);
+                    }
                     _ => assert_eq!(result_cell.fg, Color::Reset),
                 }
             }
@@ -649,7 +797,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -673,6 +821,8 @@ mod tests {
         colors.containers.text = Color::Yellow;
         colors.containers.text_rx = Color::Red;
         colors.containers.text_tx = Color::Blue;
+        colors.containers.text_block_read = Color::Cyan;
+        colors.containers.text_block_write = Color::LightMagenta;
 
         colors.container_state.running_healthy = Color::Magenta;
 
@@ -682,7 +832,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -711,13 +861,21 @@ mod tests {
                         assert_eq!(result_cell.fg, Color::Magenta);
                     }
                     // rx column
-                    (1..=3, 92..=101) => {
+                    (1..=3, 92..=103) => {
                         assert_eq!(result_cell.fg, Color::Red);
                     }
                     // tx column
-                    (1..=3, 102..=111) => {
+                    (1..=3, 104..=115) => {
                         assert_eq!(result_cell.fg, Color::Blue);
                     }
+                    // block read column
+                    (1..=3, 116..=127) => {
+                        assert_eq!(result_cell.fg, Color::Cyan);
+                    }
+                    // block write column
+                    (1..=3, 128) => {
+                        assert_eq!(result_cell.fg, Color::LightMagenta);
+                    }
                     _ => assert_eq!(result_cell.fg, Color::Reset),
                 }
             }
@@ -741,7 +899,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -777,7 +935,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -812,7 +970,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -846,7 +1004,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -882,7 +1040,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -917,7 +1075,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -952,7 +1110,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -988,7 +1146,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -1006,4 +1164,102 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    /// Memory column shows a percentage of the limit, and the whole row is recolored at the warning & critical thresholds, critical is also bold
+    fn test_draw_blocks_containers_mem_percent_alert() {
+        let mut setup = test_setup(130, 6, true, true);
+        setup.app_data.lock().toggle_show_mem_percent();
+        for (index, usage) in [(0, 85), (1, 100)] {
+            let item = &mut setup.app_data.lock().containers.items[index];
+            item.mem_stats = VecDeque::from([ByteStats::new(usage)]);
+            item.mem_limit = ByteStats::new(100);
+        }
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    &colors,
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for result_cell in result_row
+                .iter()
+                .skip(3)
+                .take(120)
+                .filter(|i| i.symbol() != " ")
+            {
+                match row_index {
+                    1 => assert_eq!(result_cell.fg, colors.containers.memory_warning),
+                    2 => {
+                        assert_eq!(result_cell.fg, colors.containers.memory_critical);
+                        assert!(result_cell.modifier.contains(Modifier::BOLD));
+                    }
+                    3 => assert_ne!(result_cell.fg, colors.containers.memory_critical),
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// The optional sparkline column is drawn after the memory column, with the recent cpu history of each container
+    fn test_draw_blocks_containers_sparkline() {
+        let mut setup = test_setup(140, 6, true, true);
+        setup.app_data.lock().config.sparkline = Some(SparklineMetric::Cpu);
+        for (index, item) in setup
+            .app_data
+            .lock()
+            .containers
+            .items
+            .iter_mut()
+            .enumerate()
+        {
+            item.cpu_stats = (0..12u32)
+                .map(|i| CpuStats::new(f64::from((i + u32::try_from(index).unwrap()) % 8)))
+                .collect();
+        }
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    &colors,
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        let rows = get_result(&setup)
+            .map(|(_, row)| {
+                row.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert!(rows[1].contains("▃▄▅▆▇█▁▂▃▄"));
+        assert!(rows[2].contains("▄▅▆▇█▁▂▃▄▅"));
+    }
 }
@@ -0,0 +1,271 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    symbols,
+    text::{Line, Span},
+    widgets::{Axis, Block, BorderType, Borders, Chart, Clear, Dataset, GraphType, Paragraph},
+};
+
+use super::popup;
+use crate::{
+    app_data::{ContainerName, CpuDetail, CpuStats, State, Stats},
+    config::{AppColors, Keymap},
+    ui::gui_state::BoxLocation,
+};
+
+/// Width of the popup, including border
+const WIDTH: usize = 64;
+/// Height of the throttled chart, including border
+const CHART_HEIGHT: usize = 9;
+/// Width of the bar for each cpu core
+const BAR_WIDTH: usize = 40;
+
+/// Generate a bar, and the percentage, for a single core, a core at 100% will fill the whole bar
+fn core_line<'a>(index: usize, usage: CpuStats, style: Style, highlight: Style) -> Line<'a> {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let filled = ((usage.get_value() / 100.0).clamp(0.0, 1.0) * BAR_WIDTH as f64).round() as usize;
+    Line::from(vec![
+        Span::styled(format!("cpu {index:<3}"), style),
+        Span::styled("█".repeat(filled), highlight),
+        Span::styled("░".repeat(BAR_WIDTH - filled), style),
+        Span::styled(format!("{usage:>9}"), style),
+    ])
+}
+
+/// Chart of the throttled percentage over time, the x-axis covers the chart window
+fn make_chart<'a>(
+    colors: &AppColors,
+    cpu_detail: &CpuDetail,
+    dataset: &'a [(f64, f64)],
+    max: CpuStats,
+    state: State,
+    x_max: f64,
+) -> Chart<'a> {
+    let style = Style::default()
+        .bg(colors.popup_help.background)
+        .fg(colors.popup_help.text);
+    let title_color = if state.is_healthy() {
+        colors.chart_cpu.title
+    } else {
+        state.get_color(colors)
+    };
+    #[allow(clippy::cast_precision_loss)]
+    let throttled_time = cpu_detail.throttled_time as f64 / 1_000_000_000.0;
+    Chart::new(vec![
+        Dataset::default()
+            .marker(symbols::Marker::Dot)
+            .style(Style::default().fg(colors.chart_cpu.points))
+            .graph_type(GraphType::Line)
+            .data(dataset),
+    ])
+    .style(style)
+    .block(
+        Block::default()
+            .title_alignment(Alignment::Center)
+            .title(Span::styled(
+                format!(
+                    " throttled {} - total {throttled_time:.2}s ",
+                    cpu_detail.throttled.back().copied().unwrap_or_default()
+                ),
+                Style::default()
+                    .fg(title_color)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(colors.chart_cpu.border)),
+    )
+    .x_axis(Axis::default().bounds([0.00, x_max]))
+    .y_axis(
+        Axis::default()
+            .labels(vec![
+                Span::raw(""),
+                Span::styled(
+                    format!("{max}"),
+                    Style::default()
+                        .fg(colors.chart_cpu.max)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ])
+            .style(Style::new().fg(colors.chart_cpu.y_axis))
+            .bounds([0.0, max.get_value() + 0.01]),
+    )
+}
+
+/// Draw the cpu throttling history, within the chart window, and per core usage, of the selected container in the centre of the screen
+pub fn draw(
+    colors: &AppColors,
+    f: &mut Frame,
+    keymap: &Keymap,
+    name: &ContainerName,
+    cpu_detail: &CpuDetail,
+    state: State,
+    points: usize,
+) {
+    let style = Style::default()
+        .bg(colors.popup_help.background)
+        .fg(colors.popup_help.text);
+    let highlight = Style::default()
+        .bg(colors.popup_help.background)
+        .fg(colors.popup_help.text_highlight)
+        .add_modifier(Modifier::BOLD);
+    let block = Block::default()
+        .title(format!(" cpu {name} "))
+        .border_type(BorderType::Rounded)
+        .style(style)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let core_lines = if cpu_detail.per_core.is_empty() {
+        vec![Line::from(Span::styled(
+            "per core usage is only reported by cgroup v1",
+            style,
+        ))]
+    } else {
+        cpu_detail
+            .per_core
+            .iter()
+            .enumerate()
+            .map(|(index, usage)| core_line(index, *usage, style, highlight))
+            .collect::<Vec<_>>()
+    };
+
+    let area = popup::draw(
+        CHART_HEIGHT + core_lines.len() + 6,
+        WIDTH,
+        f.area(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(u16::try_from(CHART_HEIGHT).unwrap_or_default()),
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .horizontal_margin(2)
+        .split(area);
+
+    let (dataset, max) = cpu_detail.get_chart_data(points);
+    #[allow(clippy::cast_precision_loss)]
+    let chart = make_chart(colors, cpu_detail, &dataset, max, state, points as f64);
+
+    let instructions = Line::from(Span::styled(
+        format!(
+            "( {} ) or ( {} ) close",
+            keymap.toggle_cpu_detail.0, keymap.clear.0
+        ),
+        style,
+    ));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(chart, split_popup[1]);
+    f.render_widget(Paragraph::new(core_lines), split_popup[3]);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[4],
+    );
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{ContainerName, CpuDetail, HistoryLength, RunningState, State},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    fn gen_cpu_detail(per_core: &[f64]) -> CpuDetail {
+        let mut cpu_detail = CpuDetail::default();
+        for i in 0..10 {
+            cpu_detail.update(
+                HistoryLength::new(60, 1000),
+                f64::from(i % 4) * 10.0,
+                1_500_000_000,
+                per_core.to_vec(),
+            );
+        }
+        cpu_detail
+    }
+
+    #[test]
+    /// Cpu detail popup, with the throttled chart, and a bar for each core
+    fn test_draw_blocks_cpu_detail() {
+        let mut setup = test_setup(70, 24, true, true);
+        let colors = AppColors::new();
+        let cpu_detail = gen_cpu_detail(&[50.0, 12.5, 100.0, 0.0]);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &colors,
+                    f,
+                    &Keymap::new(),
+                    &ContainerName::from("container_1"),
+                    &cpu_detail,
+                    State::Running(RunningState::Healthy),
+                    60,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        // the bar of the first core is half filled, in the highlight color
+        let bar = get_result(&setup)
+            .find(|(_, row)| row.iter().any(|i| i.symbol() == "█"))
+            .map(|(_, row)| {
+                row.iter()
+                    .filter(|i| i.symbol() == "█")
+                    .map(|i| i.fg)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(bar.len(), 20);
+        assert!(bar.iter().all(|i| *i == colors.popup_help.text_highlight));
+    }
+
+    #[test]
+    /// Cpu detail popup when no per core data, and the container isn't running, chart title uses the state color
+    fn test_draw_blocks_cpu_detail_no_cores() {
+        let mut setup = test_setup(70, 20, true, true);
+        let cpu_detail = gen_cpu_detail(&[]);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &AppColors::new(),
+                    f,
+                    &Keymap::new(),
+                    &ContainerName::from("container_1"),
+                    &cpu_detail,
+                    State::Paused,
+                    60,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        assert!(
+            get_result(&setup)
+                .flat_map(|(_, row)| row.iter())
+                .filter(|i| i.symbol() == "h")
+                .any(|i| i.fg == Color::Yellow)
+        );
+    }
+}
@@ -24,7 +24,7 @@ use super::popup;
 /// Draw the delete confirm box in the centre of the screen
 /// take in container id and container name here?
 pub fn draw(
-    colors: AppColors,
+    colors: &AppColors,
     f: &mut Frame,
     gui_state: &Arc<Mutex<GuiState>>,
     keymap: &Keymap,
@@ -149,7 +149,7 @@ mod tests {
             .terminal
             .draw(|f| {
                 super::draw(
-                    colors,
+                    &colors,
                     f,
                     &setup.gui_state,
                     keymap,
@@ -193,7 +193,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(colors, f, &setup.gui_state, keymap, &name);
+                super::draw(&colors, f, &setup.gui_state, keymap, &name);
             })
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
@@ -231,7 +231,7 @@ mod tests {
             .terminal
             .draw(|f| {
                 super::draw(
-                    colors,
+                    &colors,
                     f,
                     &setup.gui_state,
                     &Keymap::new(),
@@ -274,7 +274,7 @@ mod tests {
             .terminal
             .draw(|f| {
                 super::draw(
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &setup.gui_state,
                     &keymap,
@@ -295,7 +295,7 @@ mod tests {
             .terminal
             .draw(|f| {
                 super::draw(
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &setup.gui_state,
                     &keymap,
@@ -316,7 +316,7 @@ mod tests {
             .terminal
             .draw(|f| {
                 super::draw(
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &setup.gui_state,
                     &keymap,
@@ -19,7 +19,7 @@ const SUFFIX_QUIT: &str = "quit oxker";
 
 /// Draw an error popup over whole screen
 pub fn draw(
-    colors: AppColors,
+    colors: &AppColors,
     error: &AppError,
     f: &mut Frame,
     keymap: &Keymap,
@@ -112,7 +112,7 @@ mod tests {
             .terminal
             .draw(|f| {
                 super::draw(
-                    AppColors::new(),
+                    &AppColors::new(),
                     &AppError::DockerConnect,
                     f,
                     &Keymap::new(),
@@ -143,7 +143,7 @@ mod tests {
             .terminal
             .draw(|f| {
                 super::draw(
-                    AppColors::new(),
+                    &AppColors::new(),
                     &AppError::DockerExec,
                     f,
                     &Keymap::new(),
@@ -183,7 +183,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(colors, &AppError::DockerExec, f, &Keymap::new(), Some(4));
+                super::draw(&colors, &AppError::DockerExec, f, &Keymap::new(), Some(4));
             })
             .unwrap();
 
@@ -218,7 +218,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(AppColors::new(), &AppError::DockerExec, f, &keymap, None);
+                super::draw(&AppColors::new(), &AppError::DockerExec, f, &keymap, None);
             })
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
@@ -235,7 +235,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(AppColors::new(), &AppError::DockerExec, f, &keymap, None);
+                super::draw(&AppColors::new(), &AppError::DockerExec, f, &keymap, None);
             })
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
@@ -252,7 +252,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(AppColors::new(), &AppError::DockerExec, f, &keymap, None);
+                super::draw(&AppColors::new(), &AppError::DockerExec, f, &keymap, None);
             })
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
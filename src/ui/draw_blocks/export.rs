@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::popup;
+use crate::{
+    config::{AppColors, Keymap},
+    docker_data::ContainerExport,
+    ui::{GuiState, gui_state::BoxLocation},
+};
+
+/// Draw the docker run command & compose file of a container in the centre of the screen, the text can be scrolled if it doesn't fit
+pub fn draw(
+    colors: &AppColors,
+    f: &mut Frame,
+    gui_state: &Arc<Mutex<GuiState>>,
+    keymap: &Keymap,
+    export: &ContainerExport,
+    offset: usize,
+) {
+    let style = Style::default()
+        .bg(colors.popup_help.background)
+        .fg(colors.popup_help.text);
+    let highlight = Style::default()
+        .bg(colors.popup_help.background)
+        .fg(colors.popup_help.text_highlight)
+        .add_modifier(Modifier::BOLD);
+
+    let block = Block::default()
+        .title(format!(" Export {} ", export.name))
+        .border_type(BorderType::Rounded)
+        .style(style)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let instructions = Line::from(Span::styled(
+        format!(
+            "( {} {} ) scroll   ( {} ) save   ( {} ) close",
+            keymap.scroll_up_one.0, keymap.scroll_down_one.0, keymap.save_logs.0, keymap.clear.0
+        ),
+        style,
+    ));
+
+    let lines = export.lines();
+    let max_line_width = lines
+        .iter()
+        .map(|i| i.chars().count())
+        .chain(std::iter::once(instructions.width()))
+        .max()
+        .unwrap_or_default()
+        + 6;
+
+    let area = popup::draw(
+        lines.len() + 4,
+        max_line_width,
+        f.area(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .horizontal_margin(2)
+        .split(area);
+
+    let view_height = usize::from(split_popup[1].height);
+    gui_state.lock().set_export_view_height(view_height);
+    let offset = offset.min(lines.len().saturating_sub(view_height));
+
+    let text = lines
+        .into_iter()
+        .skip(offset)
+        .map(|i| {
+            if i.starts_with('#') {
+                Line::from(Span::styled(i, highlight))
+            } else {
+                Line::from(Span::styled(i, style))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(text), split_popup[1]);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[2],
+    );
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::service::{ContainerConfig, ContainerInspectResponse};
+    use insta::assert_snapshot;
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{
+        config::{AppColors, Keymap},
+        docker_data::ContainerExport,
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    fn gen_export() -> ContainerExport {
+        ContainerExport::new(
+            &ContainerInspectResponse {
+                name: Some("/container_1".to_owned()),
+                config: Some(ContainerConfig {
+                    image: Some("image_1".to_owned()),
+                    env: Some(vec!["A=1".to_owned()]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            None,
+        )
+    }
+
+    #[test]
+    /// Export popup drawn in the centre, with comment lines highlighted
+    fn test_draw_blocks_export() {
+        let mut setup = test_setup(60, 18, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+        let export = gen_export();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&colors, f, &setup.gui_state, &Keymap::new(), &export, 0);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (2, 6..=17) => {
+                        assert_eq!(result_cell.bg, Color::Magenta);
+                        assert_eq!(result_cell.fg, Color::White);
+                        assert!(result_cell.modifier.contains(Modifier::BOLD));
+                    }
+                    (1..=16, 4..=55) => {
+                        assert_eq!(result_cell.bg, Color::Magenta);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Export popup is scrollable when it doesn't fit, and the offset is limited to the final visible line
+    fn test_draw_blocks_export_scroll() {
+        let mut setup = test_setup(60, 10, true, true);
+        let export = gen_export();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &AppColors::new(),
+                    f,
+                    &setup.gui_state,
+                    &Keymap::new(),
+                    &export,
+                    100,
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
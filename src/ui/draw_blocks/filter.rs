@@ -8,7 +8,7 @@ use ratatui::{
 use crate::{app_data::FilterBy, config::AppColors, ui::FrameData};
 
 /// Create the filter_by by spans, coloured dependant on which one is selected
-fn filter_by_spans(colors: AppColors, fd: &FrameData) -> [Span; 4] {
+fn filter_by_spans<'a>(colors: &AppColors, fd: &'a FrameData) -> [Span<'a>; 4] {
     let selected = Style::default()
         .bg(colors.filter.selected_filter_background)
         .fg(colors.filter.selected_filter_text);
@@ -35,7 +35,7 @@ fn filter_by_spans(colors: AppColors, fd: &FrameData) -> [Span; 4] {
 }
 
 /// Draw the filter bar
-pub fn draw(area: Rect, colors: AppColors, frame: &mut Frame, fd: &FrameData) {
+pub fn draw(area: Rect, colors: &AppColors, frame: &mut Frame, fd: &FrameData) {
     let style_but = Style::default()
         .fg(colors.filter.selected_filter_text)
         .bg(colors.filter.highlight);
@@ -95,7 +95,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, AppColors::new(), f, &setup.fd);
+                super::draw(setup.area, &AppColors::new(), f, &setup.fd);
             })
             .unwrap();
 
@@ -141,7 +141,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, AppColors::new(), f, &setup.fd);
+                super::draw(setup.area, &AppColors::new(), f, &setup.fd);
             })
             .unwrap();
 
@@ -153,7 +153,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, AppColors::new(), f, &fd);
+                super::draw(setup.area, &AppColors::new(), f, &fd);
             })
             .unwrap();
 
@@ -201,7 +201,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, AppColors::new(), f, &setup.fd);
+                super::draw(setup.area, &AppColors::new(), f, &setup.fd);
             })
             .unwrap();
 
@@ -260,7 +260,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, colors, f, &fd);
+                super::draw(setup.area, &colors, f, &fd);
             })
             .unwrap();
 
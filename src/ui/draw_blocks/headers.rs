@@ -17,16 +17,22 @@ use crate::{
 
 /// Generate a header paragraph with it's width
 fn gen_header<'a>(
-    colors: AppColors,
+    colors: &AppColors,
     fd: &FrameData,
     header: Header,
     width: usize,
 ) -> (Paragraph<'a>, u16) {
     let block = gen_header_block(colors, fd, header);
+    let suffix = match header {
+        Header::Cpu => fd.cpu_mode.header_suffix(),
+        Header::Memory if fd.show_mem_percent => " %",
+        Header::Sparkline => fd.sparkline.map_or("", |i| i.0.header_suffix()),
+        _ => "",
+    };
 
     let text = format!(
         "{x:<width$}{MARGIN}",
-        x = format!("{header}{ic}", ic = block.1),
+        x = format!("{header}{suffix}{ic}", ic = block.1),
     );
     let count = u16::try_from(text.chars().count()).unwrap_or_default();
     let status = Paragraph::new(text)
@@ -36,17 +42,17 @@ fn gen_header<'a>(
 }
 
 // Generate a block for the header, if the header is currently being used to sort a column, then highlight it white
-fn gen_header_block<'a>(colors: AppColors, fd: &FrameData, header: Header) -> (Color, &'a str) {
+fn gen_header_block<'a>(colors: &AppColors, fd: &FrameData, header: Header) -> (Color, &'a str) {
     let mut color = colors.headers_bar.text;
     let mut suffix = "";
-    if let Some((a, b)) = &fd.sorted_by {
-        if &header == a {
-            match b {
-                SortedOrder::Asc => suffix = " ▲",
-                SortedOrder::Desc => suffix = " ▼",
-            }
-            color = colors.headers_bar.text_selected;
+    if let Some((a, b)) = &fd.sorted_by
+        && &header == a
+    {
+        match b {
+            SortedOrder::Asc => suffix = " ▲",
+            SortedOrder::Desc => suffix = " ▼",
         }
+        color = colors.headers_bar.text_selected;
     }
 
     (color, suffix)
@@ -81,7 +87,7 @@ fn gen_help_text(fd: &FrameData, keymap: &Keymap) -> String {
 
 /// Draw the show/hide help section
 fn draw_help(
-    colors: AppColors,
+    colors: &AppColors,
     f: &mut Frame,
     fd: &FrameData,
     help_text: String,
@@ -107,7 +113,7 @@ fn draw_help(
 }
 
 // Draw loading icon, or not, and a prefix with a single space
-fn draw_loading_spinner(colors: AppColors, f: &mut Frame, fd: &FrameData, rect: Rect) {
+fn draw_loading_spinner(colors: &AppColors, f: &mut Frame, fd: &FrameData, rect: Rect) {
     let loading_paragraph = Paragraph::new(format!("{:>2}", fd.loading_icon))
         .style(gen_style(None, colors.headers_bar.loading_spinner))
         .alignment(Alignment::Left);
@@ -116,7 +122,7 @@ fn draw_loading_spinner(colors: AppColors, f: &mut Frame, fd: &FrameData, rect:
 
 /// Draw the sortable column headers (name/state/status etc)
 fn draw_columns(
-    colors: AppColors,
+    colors: &AppColors,
     f: &mut Frame,
     fd: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
@@ -134,15 +140,21 @@ fn draw_columns(
             (Header::Status, fd.columns.status.1),
             (Header::Cpu, fd.columns.cpu.1),
             (Header::Memory, fd.columns.mem.1 + fd.columns.mem.2 + 3),
+        ]
+        .into_iter()
+        .chain(fd.columns.sparkline)
+        .chain([
             (Header::Id, fd.columns.id.1),
             (Header::Image, fd.columns.image.1),
             (Header::Rx, fd.columns.net_rx.1),
             (Header::Tx, fd.columns.net_tx.1),
-        ];
+            (Header::BlockRead, fd.columns.block_read.1),
+            (Header::BlockWrite, fd.columns.block_write.1),
+            (Header::Pids, fd.columns.pids.1),
+        ]);
 
         // Only show a header if the header cumulative header width is less than the header section width
         let header_data = header_meta
-            .into_iter()
             .filter_map(|(header, width)| {
                 let header_block = gen_header(colors, fd, header, usize::from(width));
                 counter += header_block.1;
@@ -172,7 +184,7 @@ fn draw_columns(
 // Draw heading bar at top of program, always visible
 pub fn draw(
     area: Rect,
-    colors: AppColors,
+    colors: &AppColors,
     f: &mut Frame,
     fd: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
@@ -246,7 +258,7 @@ mod tests {
             .draw(|f| {
                 super::draw(
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -278,7 +290,7 @@ mod tests {
             .draw(|f| {
                 super::draw(
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -306,7 +318,7 @@ mod tests {
             .draw(|f| {
                 super::draw(
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -323,8 +335,8 @@ mod tests {
                     result_cell.fg,
                     match result_cell_index {
                         0..=3 => Color::White,
-                        4..=111 => Color::Black,
-                        112..=121 => Color::Reset,
+                        4..=115 => Color::Black,
+                        116..=121 => Color::Reset,
                         _ => Color::Gray,
                     }
                 );
@@ -332,6 +344,34 @@ mod tests {
         }
     }
 
+    #[test]
+    /// The cpu header shows which cpu mode is active
+    fn test_draw_blocks_headers_cpu_mode() {
+        let mut setup = test_setup(140, 1, true, true);
+        setup.app_data.lock().cycle_cpu_mode();
+        setup.app_data.lock().cycle_cpu_mode();
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    setup.area,
+                    &AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let header = get_result(&setup)
+            .flat_map(|(_, row)| row.iter().map(ratatui::buffer::Cell::symbol))
+            .collect::<String>();
+        assert!(header.contains("cpu quota"));
+    }
+
     #[test]
     /// Only show the headings that fit the reduced-in-size header section
     fn test_draw_blocks_headers_some_containers_reduced_width() {
@@ -343,7 +383,7 @@ mod tests {
             .draw(|f| {
                 super::draw(
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -382,7 +422,7 @@ mod tests {
             .draw(|f| {
                 super::draw(
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -399,7 +439,7 @@ mod tests {
                     result_cell.fg,
                     match result_cell_index {
                         0..=3 => Color::White,
-                        4..=111 => Color::Black,
+                        4..=115 => Color::Black,
                         122..=140 => Color::Gray,
                         _ => Color::Reset,
                     }
@@ -426,7 +466,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, colors, f, &fd, &setup.gui_state, keymap);
+                super::draw(setup.area, &colors, f, &fd, &setup.gui_state, keymap);
             })
             .unwrap();
 
@@ -439,7 +479,7 @@ mod tests {
                     result_cell.fg,
                     match result_cell_index {
                         0..=3 => Color::Green,
-                        4..=111 => Color::Blue,
+                        4..=115 => Color::Blue,
                         122..=140 => Color::Yellow,
                         _ => Color::Reset,
                     }
@@ -462,7 +502,7 @@ mod tests {
             .draw(|f| {
                 super::draw(
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -487,7 +527,7 @@ mod tests {
             .draw(|f| {
                 super::draw(
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -510,7 +550,7 @@ mod tests {
                         122..=139 => Color::Gray,
                         // given range | help section
                         x if range.contains(&x) => Color::Gray,
-                        112..=121 => Color::Reset,
+                        116..=121 => Color::Reset,
                         _ => Color::Black,
                     }
                 );
@@ -531,7 +571,7 @@ mod tests {
                     .draw(|f| {
                         super::draw(
                             setup.area,
-                            AppColors::new(),
+                            &AppColors::new(),
                             f,
                             &fd,
                             &setup.gui_state,
@@ -647,27 +687,27 @@ mod tests {
         test_draw_blocks_headers_sort_containers_rx_asc,
         Header::Rx,
         SortedOrder::Asc,
-        92..=101
+        92..=103
     );
 
     test_draw_blocks_headers_sort!(
         test_draw_blocks_headers_sort_containers_rx_desc,
         Header::Rx,
         SortedOrder::Desc,
-        92..=101
+        92..=103
     );
 
     test_draw_blocks_headers_sort!(
         test_draw_blocks_headers_sort_containers_tx_asc,
         Header::Tx,
         SortedOrder::Asc,
-        102..=111
+        104..=115
     );
 
     test_draw_blocks_headers_sort!(
         test_draw_blocks_headers_sort_containers_tx_desc,
         Header::Tx,
         SortedOrder::Desc,
-        102..=111
+        104..=115
     );
 }
@@ -43,17 +43,17 @@ impl HelpInfo {
     }
 
     /// &str to black text span
-    fn text_span<'a>(input: &str, color: AppColors) -> Span<'a> {
+    fn text_span<'a>(input: &str, color: &AppColors) -> Span<'a> {
         Self::span(input, color.popup_help.text)
     }
 
     /// &str to white text span
-    fn highlighted_text_span<'a>(input: &str, color: AppColors) -> Span<'a> {
+    fn highlighted_text_span<'a>(input: &str, color: &AppColors) -> Span<'a> {
         Self::span(input, color.popup_help.text_highlight)
     }
 
     /// Generate the `oxker` name span + metadata
-    fn gen_name(colors: AppColors) -> Self {
+    fn gen_name(colors: &AppColors) -> Self {
         let mut lines = NAME_TEXT
             .lines()
             .map(|i| Line::from(Self::highlighted_text_span(i, colors)))
@@ -70,7 +70,7 @@ impl HelpInfo {
     }
 
     /// Generate the description span + metadata
-    fn gen_description(colors: AppColors) -> Self {
+    fn gen_description(colors: &AppColors) -> Self {
         let lines = [
             Self::empty_span(),
             Line::from(Self::highlighted_text_span(DESCRIPTION, colors)),
@@ -86,7 +86,7 @@ impl HelpInfo {
 
     /// Generate the button information span + metadata
     #[allow(clippy::too_many_lines)]
-    fn gen_keymap_info(colors: AppColors, zone: Option<&TimeZone>, show_timestamp: bool) -> Self {
+    fn gen_keymap_info(colors: &AppColors, zone: Option<&TimeZone>, show_timestamp: bool) -> Self {
         let button_item = |x: &str| Self::highlighted_text_span(&format!(" ( {x} ) "), colors);
         let button_desc = |x: &str| Self::text_span(x, colors);
         let or = || button_desc("or");
@@ -114,7 +114,7 @@ impl HelpInfo {
             Line::from(vec![
                 space(),
                 button_item("enter"),
-                button_desc("send docker container command"),
+                button_desc("send docker container command, or expand the selected log line"),
             ]),
             Line::from(vec![
                 space(),
@@ -133,6 +133,76 @@ impl HelpInfo {
                 button_item("s"),
                 button_desc("save logs to file"),
             ]),
+            Line::from(vec![
+                space(),
+                button_item("x"),
+                button_desc("export docker run command & compose service"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("u"),
+                button_desc("show cpu throttling & per core usage"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("v"),
+                button_desc("show memory breakdown"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("l"),
+                button_desc("toggle memory bytes & percentage of limit"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("[ ]"),
+                button_desc("zoom charts out & in"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("i"),
+                button_desc("inspect chart history, ← → move the cursor"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("a"),
+                button_desc("cycle overview chart, cpu or memory of all containers"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("z"),
+                button_desc("start or stop recording stats to a file"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("g"),
+                button_desc("toggle merged logs of the marked, or all filtered, containers"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("M"),
+                button_desc("mark, or unmark, a container for the merged logs"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("f"),
+                button_desc("search logs - tab switches between plain & regex"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item(", ."),
+                button_desc("previous & next log search match"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("b"),
+                button_desc("filter logs - ← → edit include or exclude term, ↑ ↓ case sensitivity"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("d"),
+                button_desc("cycle minimum log level - debug, info, warn, error, or all"),
+            ]),
             Line::from(vec![
                 space(),
                 button_item("m"),
@@ -151,8 +221,20 @@ impl HelpInfo {
             Line::from(vec![
                 space(),
                 button_item("1 - 9"),
+                or(),
+                button_item("r w p"),
                 button_desc("sort by header - or click header"),
             ]),
+            Line::from(vec![
+                space(),
+                button_item("t"),
+                button_desc("toggle rx & tx between per second rates & totals"),
+            ]),
+            Line::from(vec![
+                space(),
+                button_item("o"),
+                button_desc("cycle cpu between per core, host, & quota percentages"),
+            ]),
             Line::from(vec![
                 space(),
                 button_item("- ="),
@@ -196,7 +278,7 @@ impl HelpInfo {
     }
 
     /// Generate the final lines, GitHub link etc, + metadata
-    fn gen_final(colors: AppColors) -> Self {
+    fn gen_final(colors: &AppColors) -> Self {
         let lines = [
             Self::empty_span(),
             Line::from(vec![Self::text_span(
@@ -220,7 +302,7 @@ impl HelpInfo {
 
     /// Display timezone in timestamps are visible
     /// Has ability to display if keymap or colors are customized, but currently not in use
-    fn custom_text<'a>(colors: AppColors, _keymap: &Keymap, zone: Option<&TimeZone>) -> Line<'a> {
+    fn custom_text<'a>(colors: &AppColors, _keymap: &Keymap, zone: Option<&TimeZone>) -> Line<'a> {
         let highlighted = |x: &str| Self::highlighted_text_span(x, colors);
         let text = |x: &str| Self::text_span(x, colors);
         let zone = zone.and_then(|i| i.iana_name()).unwrap_or("Etc/UTC");
@@ -228,8 +310,9 @@ impl HelpInfo {
     }
 
     /// Generate the display information when a custom keymap is being used
+    #[allow(clippy::too_many_lines)]
     fn gen_custom_keymap_info(
-        colors: AppColors,
+        colors: &AppColors,
         km: &Keymap,
         zone: Option<&TimeZone>,
         show_timestamp: bool,
@@ -271,7 +354,7 @@ impl HelpInfo {
             Line::from(vec![
                 space(),
                 button_item("enter"),
-                button_desc("send docker container command"),
+                button_desc("send docker container command, or expand the selected log line"),
             ]),
             #[cfg(not(target_os = "windows"))]
             or_secondary(km.exec, "exec into a container"),
@@ -282,6 +365,7 @@ impl HelpInfo {
                 "toggle this help information - or click heading",
             ),
             or_secondary(km.save_logs, "save logs to file"),
+            or_secondary(km.export, "export docker run command & compose service"),
             or_secondary(
                 km.toggle_mouse_capture,
                 "toggle mouse capture - if disabled, text on screen can be selected & copied",
@@ -297,6 +381,35 @@ impl HelpInfo {
             or_secondary(km.sort_by_image, "sort containers by image"),
             or_secondary(km.sort_by_rx, "sort containers by rx"),
             or_secondary(km.sort_by_tx, "sort containers by tx"),
+            or_secondary(km.sort_by_block_read, "sort containers by block read"),
+            or_secondary(km.sort_by_block_write, "sort containers by block write"),
+            or_secondary(km.sort_by_pids, "sort containers by pids"),
+            or_secondary(
+                km.toggle_net_total,
+                "toggle rx & tx between per second rates & totals",
+            ),
+            or_secondary(km.toggle_cpu_detail, "show cpu throttling & per core usage"),
+            or_secondary(
+                km.cycle_cpu_mode,
+                "cycle cpu between per core, host, & quota percentages",
+            ),
+            or_secondary(km.toggle_mem_detail, "show memory breakdown"),
+            or_secondary(
+                km.toggle_mem_percent,
+                "toggle memory bytes & percentage of limit",
+            ),
+            or_secondary(km.chart_zoom_out, "zoom charts out"),
+            or_secondary(km.chart_zoom_in, "zoom charts in"),
+            or_secondary(km.toggle_chart_cursor, "inspect chart history"),
+            or_secondary(km.toggle_chart_overview, "cycle overview chart"),
+            or_secondary(km.toggle_record, "record stats to file"),
+            or_secondary(km.toggle_merged_logs, "toggle merged logs"),
+            or_secondary(km.toggle_mark, "mark container for merged logs"),
+            or_secondary(km.log_search, "search logs"),
+            or_secondary(km.log_search_next, "next log search match"),
+            or_secondary(km.log_search_previous, "previous log search match"),
+            or_secondary(km.log_filter, "filter logs"),
+            or_secondary(km.log_level, "cycle minimum log level"),
             or_secondary(
                 km.log_section_height_decrease,
                 "decrease log section height",
@@ -330,7 +443,7 @@ impl HelpInfo {
 
 /// Draw the help box in the centre of the screen
 pub fn draw(
-    colors: AppColors,
+    colors: &AppColors,
     f: &mut Frame,
     keymap: &Keymap,
     show_timestamp: bool,
@@ -437,14 +550,14 @@ mod tests {
     #[test]
     /// This will cause issues once the version has more than the current 5 chars (0.5.0)
     fn test_draw_blocks_help() {
-        let mut setup = test_setup(87, 35, true, true);
+        let mut setup = test_setup(87, 50, true, true);
         let tz = setup.app_data.lock().config.timezone.clone();
 
         setup
             .terminal
             .draw(|f| {
                 super::draw(
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &setup.app_data.lock().config.keymap,
                     false,
@@ -459,12 +572,12 @@ mod tests {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
                     // first & last row, and first & last char on each row, is reset/reset, making sure that the help info is centered in the given area
-                    (0 | 34, _) | (0..=33, 0 | 86) => {
+                    (0 | 49, _) | (0..=48, 0 | 86) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
                     // border is black on magenta
-                    (1 | 32, _) | (1..=31, 1 | 85) => {
+                    (1 | 48, _) | (1..=47, 1 | 85) => {
                         assert_eq!(result_cell.bg, Color::Magenta);
                         assert_eq!(result_cell.fg, Color::Black);
                     }
@@ -473,15 +586,16 @@ mod tests {
                     | (12, 19..=66)
                     | (14, 2..=10 | 13..=27)
                     | (15, 2..=10 | 13..=21 | 24..=40 | 43..=56)
-                    | (16 | 23, 2..=12)
-                    | (17..=20 | 22 | 25 | 27, 2..=8)
-                    | (21, 2..=9 | 12..=18)
-                    | (24 | 26, 2..=10) => {
+                    | (16, 2..=12)
+                    | (37, 2..=12 | 15..=25)
+                    | (17..=23 | 25..=30 | 32 | 33 | 34 | 36 | 38 | 39 | 41 | 43, 2..=8)
+                    | (35, 2..=9 | 12..=18)
+                    | (24 | 31 | 40 | 42, 2..=10) => {
                         assert_eq!(result_cell.bg, Color::Magenta);
                         assert_eq!(result_cell.fg, Color::White);
                     }
                     // The URL is white and underlined
-                    (30, 25..=60) => {
+                    (46, 25..=60) => {
                         assert_eq!(result_cell.bg, Color::Magenta);
                         assert_eq!(result_cell.fg, Color::White);
                         assert_eq!(result_cell.modifier, Modifier::UNDERLINED);
@@ -499,7 +613,7 @@ mod tests {
     #[test]
     /// Test that the help panel gets drawn with custom colors
     fn test_draw_blocks_help_custom_colors() {
-        let mut setup = test_setup(87, 35, true, true);
+        let mut setup = test_setup(87, 50, true, true);
         let mut colors = AppColors::new();
         let tz = setup.app_data.lock().config.timezone.clone();
 
@@ -511,7 +625,7 @@ mod tests {
             .terminal
             .draw(|f| {
                 super::draw(
-                    colors,
+                    &colors,
                     f,
                     &setup.app_data.lock().config.keymap,
                     false,
@@ -526,12 +640,12 @@ mod tests {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
                     // first & last row, and first & last char on each row, is reset/reset, making sure that the help info is centered in the given area
-                    (0 | 34, _) | (0..=33, 0 | 86) => {
+                    (0 | 49, _) | (0..=48, 0 | 86) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
                     // border is red on black
-                    (1 | 32, _) | (1..=31, 1 | 85) => {
+                    (1 | 48, _) | (1..=47, 1 | 85) => {
                         assert_eq!(result_cell.bg, Color::Black);
                         assert_eq!(result_cell.fg, Color::Red);
                     }
@@ -540,15 +654,16 @@ mod tests {
                     | (12, 19..=66)
                     | (14, 2..=10 | 13..=27)
                     | (15, 2..=10 | 13..=21 | 24..=40 | 43..=56)
-                    | (16 | 23, 2..=12)
-                    | (17..=20 | 22 | 25 | 27, 2..=8)
-                    | (21, 2..=9 | 12..=18)
-                    | (24 | 26, 2..=10) => {
+                    | (16, 2..=12)
+                    | (37, 2..=12 | 15..=25)
+                    | (17..=23 | 25..=30 | 32 | 33 | 34 | 36 | 38 | 39 | 41 | 43, 2..=8)
+                    | (35, 2..=9 | 12..=18)
+                    | (24 | 31 | 40 | 42, 2..=10) => {
                         assert_eq!(result_cell.bg, Color::Black);
                         assert_eq!(result_cell.fg, Color::Yellow);
                     }
                     // The URL is yellow and underlined
-                    (30, 25..=60) => {
+                    (46, 25..=60) => {
                         assert_eq!(result_cell.bg, Color::Black);
                         assert_eq!(result_cell.fg, Color::Yellow);
                         assert_eq!(result_cell.modifier, Modifier::UNDERLINED);
@@ -566,13 +681,14 @@ mod tests {
     #[test]
     /// Help panel will show custom keymap if in use, with one definition for each entry
     fn test_draw_blocks_help_custom_keymap_one_definition() {
-        let mut setup = test_setup(98, 47, true, true);
+        let mut setup = test_setup(98, 70, true, true);
 
         let input = Keymap {
             clear: (KeyCode::Char('a'), None),
             delete_deny: (KeyCode::Char('c'), None),
             delete_confirm: (KeyCode::Char('e'), None),
             exec: (KeyCode::Char('g'), None),
+            export: (KeyCode::Char('E'), None),
             log_section_height_decrease: (KeyCode::Char('z'), None),
             log_section_height_increase: (KeyCode::Char('x'), None),
             log_section_toggle: (KeyCode::Char('W'), None),
@@ -596,15 +712,35 @@ mod tests {
             sort_by_image: (KeyCode::Char(','), None),
             sort_by_rx: (KeyCode::Char('.'), None),
             sort_by_tx: (KeyCode::Insert, None),
+            sort_by_block_read: (KeyCode::Char('H'), None),
+            sort_by_block_write: (KeyCode::Char('J'), None),
+            sort_by_pids: (KeyCode::Char('N'), None),
             sort_reset: (KeyCode::Up, None),
             toggle_help: (KeyCode::Home, None),
             toggle_mouse_capture: (KeyCode::PageDown, None),
+            toggle_net_total: (KeyCode::Char('L'), None),
+            toggle_cpu_detail: (KeyCode::Char('P'), None),
+            cycle_cpu_mode: (KeyCode::Char('R'), None),
+            toggle_mem_detail: (KeyCode::Char('T'), None),
+            toggle_mem_percent: (KeyCode::Char('V'), None),
+            chart_zoom_out: (KeyCode::F(3), None),
+            chart_zoom_in: (KeyCode::F(5), None),
+            toggle_chart_cursor: (KeyCode::F(7), None),
+            toggle_chart_overview: (KeyCode::F(9), None),
+            toggle_record: (KeyCode::F(11), None),
+            toggle_merged_logs: (KeyCode::Char(';'), None),
+            toggle_mark: (KeyCode::Char('`'), None),
+            log_search: (KeyCode::Delete, None),
+            log_search_next: (KeyCode::Left, None),
+            log_search_previous: (KeyCode::Esc, None),
+            log_filter: (KeyCode::Char('Y'), None),
+            log_level: (KeyCode::Char('D'), None),
         };
 
         setup
             .terminal
             .draw(|f| {
-                super::draw(AppColors::new(), f, &input, false, None);
+                super::draw(&AppColors::new(), f, &input, false, None);
             })
             .unwrap();
 
@@ -614,13 +750,14 @@ mod tests {
     #[test]
     /// Help panel will show custom keymap if in use, with two definition for each entry
     fn test_draw_blocks_help_custom_keymap_two_definitions() {
-        let mut setup = test_setup(110, 47, true, true);
+        let mut setup = test_setup(110, 70, true, true);
 
         let keymap = Keymap {
             clear: (KeyCode::Char('a'), Some(KeyCode::Char('b'))),
             delete_deny: (KeyCode::Char('c'), Some(KeyCode::Char('d'))),
             delete_confirm: (KeyCode::Char('e'), Some(KeyCode::Char('f'))),
             exec: (KeyCode::Char('g'), Some(KeyCode::Char('h'))),
+            export: (KeyCode::Char('E'), Some(KeyCode::Char('G'))),
             log_section_height_decrease: (KeyCode::Char('A'), Some(KeyCode::Char('Z'))),
             log_section_height_increase: (KeyCode::Char('B'), Some(KeyCode::Char('X'))),
             log_section_toggle: (KeyCode::Char('C'), Some(KeyCode::Char('W'))),
@@ -644,15 +781,35 @@ mod tests {
             sort_by_image: (KeyCode::Char(','), Some(KeyCode::Char('\\'))),
             sort_by_rx: (KeyCode::Char('.'), Some(KeyCode::Char(']'))),
             sort_by_tx: (KeyCode::Insert, Some(KeyCode::BackTab)),
+            sort_by_block_read: (KeyCode::Char('H'), Some(KeyCode::Char('I'))),
+            sort_by_block_write: (KeyCode::Char('J'), Some(KeyCode::Char('K'))),
+            sort_by_pids: (KeyCode::Char('N'), Some(KeyCode::Char('O'))),
             sort_reset: (KeyCode::Up, Some(KeyCode::Down)),
             toggle_help: (KeyCode::Home, Some(KeyCode::End)),
             toggle_mouse_capture: (KeyCode::PageDown, Some(KeyCode::PageUp)),
+            toggle_net_total: (KeyCode::Char('L'), Some(KeyCode::Char('M'))),
+            toggle_cpu_detail: (KeyCode::Char('P'), Some(KeyCode::Char('Q'))),
+            cycle_cpu_mode: (KeyCode::Char('R'), Some(KeyCode::Char('S'))),
+            toggle_mem_detail: (KeyCode::Char('T'), Some(KeyCode::Char('U'))),
+            toggle_mem_percent: (KeyCode::Char('V'), Some(KeyCode::F(2))),
+            chart_zoom_out: (KeyCode::F(3), Some(KeyCode::F(4))),
+            chart_zoom_in: (KeyCode::F(5), Some(KeyCode::F(6))),
+            toggle_chart_cursor: (KeyCode::F(7), Some(KeyCode::F(8))),
+            toggle_chart_overview: (KeyCode::F(9), Some(KeyCode::F(10))),
+            toggle_record: (KeyCode::F(11), Some(KeyCode::F(12))),
+            toggle_merged_logs: (KeyCode::Char(';'), Some(KeyCode::Char('\''))),
+            toggle_mark: (KeyCode::Char('`'), Some(KeyCode::Char('~'))),
+            log_search: (KeyCode::Delete, Some(KeyCode::Backspace)),
+            log_search_next: (KeyCode::Left, Some(KeyCode::Right)),
+            log_search_previous: (KeyCode::Esc, Some(KeyCode::Tab)),
+            log_filter: (KeyCode::Char('Y'), Some(KeyCode::Char('['))),
+            log_level: (KeyCode::Char('D'), Some(KeyCode::Char('F'))),
         };
 
         setup
             .terminal
             .draw(|f| {
-                super::draw(AppColors::new(), f, &keymap, false, None);
+                super::draw(&AppColors::new(), f, &keymap, false, None);
             })
             .unwrap();
 
@@ -662,13 +819,14 @@ mod tests {
     #[test]
     /// Help panel will show custom keymap if in use, with either one or two definition for each entry
     fn test_draw_blocks_help_one_and_two_definitions() {
-        let mut setup = test_setup(110, 47, true, true);
+        let mut setup = test_setup(110, 70, true, true);
 
         let keymap = Keymap {
             clear: (KeyCode::Char('a'), Some(KeyCode::Char('b'))),
             delete_deny: (KeyCode::Char('c'), None),
             delete_confirm: (KeyCode::Char('e'), Some(KeyCode::Char('f'))),
             exec: (KeyCode::Char('g'), None),
+            export: (KeyCode::Char('E'), None),
             filter_mode: (KeyCode::Char('i'), Some(KeyCode::Char('j'))),
             log_section_height_decrease: (KeyCode::Char('A'), Some(KeyCode::Char('Z'))),
             log_section_height_increase: (KeyCode::Char('B'), Some(KeyCode::Char('X'))),
@@ -692,9 +850,29 @@ mod tests {
             sort_by_image: (KeyCode::Char(','), None),
             sort_by_rx: (KeyCode::Char('.'), Some(KeyCode::Char(']'))),
             sort_by_tx: (KeyCode::Insert, None),
+            sort_by_block_read: (KeyCode::Char('H'), None),
+            sort_by_block_write: (KeyCode::Char('J'), None),
+            sort_by_pids: (KeyCode::Char('N'), None),
             sort_reset: (KeyCode::Up, Some(KeyCode::Down)),
             toggle_help: (KeyCode::Home, None),
             toggle_mouse_capture: (KeyCode::PageDown, Some(KeyCode::PageUp)),
+            toggle_net_total: (KeyCode::Char('L'), None),
+            toggle_cpu_detail: (KeyCode::Char('P'), None),
+            cycle_cpu_mode: (KeyCode::Char('R'), None),
+            toggle_mem_detail: (KeyCode::Char('T'), None),
+            toggle_mem_percent: (KeyCode::Char('V'), None),
+            chart_zoom_out: (KeyCode::F(3), None),
+            chart_zoom_in: (KeyCode::F(5), None),
+            toggle_chart_cursor: (KeyCode::F(7), None),
+            toggle_chart_overview: (KeyCode::F(9), None),
+            toggle_record: (KeyCode::F(11), None),
+            toggle_merged_logs: (KeyCode::Char(';'), None),
+            toggle_mark: (KeyCode::Char('`'), None),
+            log_search: (KeyCode::Delete, None),
+            log_search_next: (KeyCode::Left, None),
+            log_search_previous: (KeyCode::Esc, None),
+            log_filter: (KeyCode::Char('Y'), None),
+            log_level: (KeyCode::Char('D'), None),
         };
 
         let tz = setup.app_data.lock().config.timezone.clone();
@@ -702,7 +880,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(AppColors::new(), f, &keymap, false, tz.as_ref());
+                super::draw(&AppColors::new(), f, &keymap, false, tz.as_ref());
             })
             .unwrap();
 
@@ -711,13 +889,13 @@ mod tests {
 
     #[test]
     fn test_draw_blocks_help_show_timezone() {
-        let mut setup = test_setup(87, 37, true, true);
+        let mut setup = test_setup(87, 52, true, true);
 
         setup
             .terminal
             .draw(|f| {
                 super::draw(
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &Keymap::new(),
                     true,
@@ -0,0 +1,104 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+};
+
+use crate::{config::AppColors, ui::FrameData};
+
+/// Draw the host summary bar, docker engine & host information, container counts, and the combined usage of all containers
+pub fn draw(area: Rect, colors: &AppColors, frame: &mut Frame, fd: &FrameData) {
+    if let Some((host, cpu, mem)) = fd.host_summary.as_ref() {
+        let label = Style::default().fg(colors.headers_bar.text);
+        let value = Style::default().fg(colors.headers_bar.text_selected);
+
+        let line = [
+            ("docker", host.engine_version.clone()),
+            ("api", host.api_version.clone()),
+            ("os", format!("{} {}", host.os, host.kernel)),
+            ("cpus", host.ncpu.to_string()),
+            ("memory", host.mem_total.to_string()),
+            ("running", host.running.to_string()),
+            ("paused", host.paused.to_string()),
+            ("stopped", host.stopped.to_string()),
+            ("cpu", cpu.to_string()),
+            ("mem", mem.to_string()),
+        ]
+        .into_iter()
+        .flat_map(|(name, text)| {
+            [
+                Span::styled(format!(" {name} "), label),
+                Span::styled(text, value),
+                Span::styled(" ", label),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+        frame.render_widget(Line::from(line).bg(colors.headers_bar.background), area);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+
+    use std::collections::VecDeque;
+
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{ByteStats, CpuStats, HostSummary},
+        config::AppColors,
+        ui::{
+            FrameData,
+            draw_blocks::tests::{get_result, test_setup},
+        },
+    };
+
+    #[test]
+    /// Host summary bar is drawn with the host info, and the combined usage of all containers, labels & values use the headers bar colors
+    fn test_draw_blocks_host_summary() {
+        let mut setup = test_setup(150, 1, true, true);
+        setup.app_data.lock().host_summary = Some(HostSummary {
+            api_version: "1.47".to_owned(),
+            engine_version: "27.3.1".to_owned(),
+            kernel: "6.8.0".to_owned(),
+            mem_total: ByteStats::new(1_000_000),
+            ncpu: 4,
+            os: "Ubuntu".to_owned(),
+            paused: 1,
+            running: 2,
+            stopped: 0,
+        });
+        for item in &mut setup.app_data.lock().containers.items {
+            item.cpu_stats = VecDeque::from([CpuStats::new(50.0)]);
+            item.mem_stats = VecDeque::from([ByteStats::new(100_000)]);
+        }
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &AppColors::new(), f, &fd);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        let (_, row) = get_result(&setup).next().unwrap();
+        let text = row
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect::<String>();
+        // 3 containers using 50% of a single core, on a 4 core host, and 3 x 100 kB of 1 MB
+        assert!(text.contains(" cpu 37.50% "));
+        assert!(text.contains(" mem 30.00% "));
+
+        for cell in row.iter().filter(|i| i.symbol() != " ") {
+            assert_eq!(cell.bg, Color::Magenta);
+            assert!(cell.fg == Color::Black || cell.fg == Color::Gray);
+        }
+    }
+}
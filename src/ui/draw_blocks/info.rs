@@ -18,7 +18,7 @@ use super::{max_line_width, popup};
 /// Draw info box in one of the 9 BoxLocations
 // TODO is this broken - I don't think so
 pub fn draw(
-    colors: AppColors,
+    colors: &AppColors,
     f: &mut Frame,
     gui_state: &Arc<Mutex<GuiState>>,
     instant: &Instant,
@@ -76,7 +76,7 @@ mod tests {
             .terminal
             .draw(|f| {
                 super::draw(
-                    colors,
+                    &colors,
                     f,
                     &setup.gui_state,
                     &std::time::Instant::now(),
@@ -112,7 +112,7 @@ mod tests {
             .terminal
             .draw(|f| {
                 super::draw(
-                    colors,
+                    &colors,
                     f,
                     &setup.gui_state,
                     &std::time::Instant::now(),
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::popup;
+use crate::{
+    app_data::LogDetail,
+    config::{AppColors, Keymap},
+    ui::{GuiState, gui_state::BoxLocation},
+};
+
+/// Split a line into chunks of at most `width` chars, an empty line is kept as a single empty chunk
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    let chars = line.chars().collect::<Vec<_>>();
+    if chars.is_empty() || width == 0 {
+        return vec![line.to_owned()];
+    }
+    chars
+        .chunks(width)
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
+
+/// Draw the selected log line in the centre of the screen, pretty printed if structured, long lines are wrapped, and the text can be scrolled if it doesn't fit
+pub fn draw(
+    colors: &AppColors,
+    f: &mut Frame,
+    gui_state: &Arc<Mutex<GuiState>>,
+    keymap: &Keymap,
+    log_detail: &LogDetail,
+    offset: usize,
+) {
+    let style = Style::default()
+        .bg(colors.popup_help.background)
+        .fg(colors.popup_help.text);
+
+    let block = Block::default()
+        .title(format!(" Log {} ", log_detail.timestamp))
+        .border_type(BorderType::Rounded)
+        .style(style)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let instructions = Line::from(Span::styled(
+        format!(
+            "( {} {} ) scroll   ( {} ) close",
+            keymap.scroll_up_one.0, keymap.scroll_down_one.0, keymap.clear.0
+        ),
+        style,
+    ));
+
+    let max_line_width = log_detail
+        .lines
+        .iter()
+        .map(|i| i.chars().count())
+        .chain(std::iter::once(instructions.width()))
+        .max()
+        .unwrap_or_default()
+        + 6;
+    let max_line_width = max_line_width.min(usize::from(f.area().width));
+    let text_width = max_line_width.saturating_sub(4);
+
+    let lines = log_detail
+        .lines
+        .iter()
+        .flat_map(|i| wrap(i, text_width))
+        .collect::<Vec<_>>();
+
+    let area = popup::draw(
+        lines.len() + 4,
+        max_line_width,
+        f.area(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .horizontal_margin(2)
+        .split(area);
+
+    let view_height = usize::from(split_popup[1].height);
+    gui_state
+        .lock()
+        .set_log_detail_view(view_height, lines.len());
+    let offset = offset.min(lines.len().saturating_sub(view_height));
+
+    let text = lines
+        .into_iter()
+        .skip(offset)
+        .map(|i| Line::from(Span::styled(i, style)))
+        .collect::<Vec<_>>();
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(text), split_popup[1]);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[2],
+    );
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{LogDetail, StructuredLog},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    fn gen_detail() -> LogDetail {
+        LogDetail {
+            lines: StructuredLog::parse(
+                r#"{"level":"info","msg":"request finished","status":200,"path":"/api/containers/a_long_path_that_needs_wrapping"}"#,
+            )
+            .unwrap()
+            .pretty(),
+            timestamp: "12:00:00".to_owned(),
+        }
+    }
+
+    #[test]
+    /// Log detail popup drawn in the centre, with long lines wrapped
+    fn test_draw_blocks_log_detail() {
+        let mut setup = test_setup(60, 14, true, true);
+        let detail = gen_detail();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &AppColors::new(),
+                    f,
+                    &setup.gui_state,
+                    &Keymap::new(),
+                    &detail,
+                    0,
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (1..=12, 0..=59) => {
+                        assert_eq!(result_cell.bg, Color::Magenta);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Log detail popup is scrollable when it doesn't fit, and the offset is limited to the final visible line
+    fn test_draw_blocks_log_detail_scroll() {
+        let mut setup = test_setup(60, 8, true, true);
+        let detail = gen_detail();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &AppColors::new(),
+                    f,
+                    &setup.gui_state,
+                    &Keymap::new(),
+                    &detail,
+                    100,
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
@@ -0,0 +1,153 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+};
+
+use crate::{
+    app_data::{LogFilter, SearchMode},
+    config::AppColors,
+    ui::FrameData,
+};
+
+/// Draw the log filter bar, uses the same colours as the filter bar, with the current value of each setting highlighted, and the term being edited
+pub fn draw(area: Rect, colors: &AppColors, frame: &mut Frame, fd: &FrameData) {
+    let style_but = Style::default()
+        .fg(colors.filter.selected_filter_text)
+        .bg(colors.filter.highlight);
+    let style_desc = Style::default()
+        .fg(colors.filter.text)
+        .bg(colors.filter.background);
+    let style_selected = Style::default()
+        .bg(colors.filter.selected_filter_background)
+        .fg(colors.filter.selected_filter_text);
+
+    let filter = fd.log_filter.clone().unwrap_or_else(LogFilter::new);
+    let kind = if filter.is_editing_exclude() {
+        " Exclude "
+    } else {
+        " Include "
+    };
+    let mode = match filter.get_mode() {
+        SearchMode::Plain => " Plain ",
+        SearchMode::Regex => " Regex ",
+    };
+    let case = if filter.is_case_sensitive() {
+        " Match case "
+    } else {
+        " Ignore case "
+    };
+
+    let line = vec![
+        Span::styled(" Esc ", style_but),
+        Span::styled(" clear ", style_desc),
+        Span::styled(" ← → ", style_but),
+        Span::styled(kind, style_selected),
+        Span::from(" "),
+        Span::styled(" Tab ", style_but),
+        Span::styled(mode, style_selected),
+        Span::from(" "),
+        Span::styled(" ↑ ↓ ", style_but),
+        Span::styled(case, style_selected),
+        Span::styled(
+            " filter: ",
+            Style::default()
+                .fg(colors.filter.highlight)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            filter.get_term().to_owned(),
+            Style::default().fg(colors.filter.text),
+        ),
+    ];
+    frame.render_widget(Line::from(line).bg(colors.filter.background), area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+
+    use insta::assert_snapshot;
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{
+        app_data::LogFilter,
+        config::AppColors,
+        ui::{
+            FrameData, Status,
+            draw_blocks::tests::{get_result, insert_logs, test_setup},
+        },
+    };
+
+    #[test]
+    /// Log filter row is drawn correctly, showing the current settings & term
+    fn test_draw_blocks_log_filter_row() {
+        let mut setup = test_setup(80, 1, true, true);
+        insert_logs(&setup);
+
+        setup.gui_state.lock().status_push(Status::LogFilter);
+        setup.app_data.lock().log_filter_edit(|f| f.push('2'));
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &AppColors::new(), f, &fd);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        for (_, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match result_cell_index {
+                    0..=4 | 12..=16 | 27..=31 | 40..=44 => {
+                        assert_eq!(result_cell.bg, Color::Magenta);
+                        assert_eq!(result_cell.fg, Color::Black);
+                    }
+                    5..=11 | 67 => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                        assert_eq!(result_cell.fg, Color::Gray);
+                    }
+                    17..=25 | 32..=38 | 45..=57 => {
+                        assert_eq!(result_cell.bg, Color::Gray);
+                        assert_eq!(result_cell.fg, Color::Black);
+                    }
+                    58..=66 => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Log filter row shows the exclude, regex, and case sensitive, settings
+    fn test_draw_blocks_log_filter_row_settings() {
+        let mut setup = test_setup(80, 1, true, true);
+        insert_logs(&setup);
+
+        setup.gui_state.lock().status_push(Status::LogFilter);
+        setup.app_data.lock().log_filter_edit(|f| {
+            f.toggle_editing();
+            f.mode_next();
+            f.toggle_case_sensitive();
+            f.push('^');
+        });
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        assert_eq!(fd.log_filter.as_ref().map(LogFilter::get_term), Some("^"));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &AppColors::new(), f, &fd);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
@@ -0,0 +1,125 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+};
+
+use crate::{app_data::SearchMode, config::AppColors, ui::FrameData};
+
+/// Create the search mode spans, coloured dependant on which one is selected
+fn mode_spans(colors: &AppColors, mode: SearchMode) -> [Span<'static>; 2] {
+    let selected = Style::default()
+        .bg(colors.filter.selected_filter_background)
+        .fg(colors.filter.selected_filter_text);
+    let not_selected = Style::default()
+        .bg(colors.filter.background)
+        .fg(colors.filter.text);
+
+    [SearchMode::Plain, SearchMode::Regex].map(|i| {
+        Span::styled(
+            format!(" {i} "),
+            if i == mode { selected } else { not_selected },
+        )
+    })
+}
+
+/// Draw the log search bar, uses the same colours as the filter bar
+pub fn draw(area: Rect, colors: &AppColors, frame: &mut Frame, fd: &FrameData) {
+    let style_but = Style::default()
+        .fg(colors.filter.selected_filter_text)
+        .bg(colors.filter.highlight);
+    let style_desc = Style::default()
+        .fg(colors.filter.text)
+        .bg(colors.filter.background);
+
+    let (term, mode) = fd.log_search.as_ref().map_or_else(
+        || (String::new(), SearchMode::default()),
+        |(term, mode)| (term.clone(), *mode),
+    );
+
+    let mut line = vec![
+        Span::styled(" Esc ", style_but),
+        Span::styled(" clear ", style_desc),
+        Span::styled(" Tab ", style_but),
+        Span::styled(" mode ", style_desc),
+        Span::from(" "),
+    ];
+    line.extend_from_slice(&mode_spans(colors, mode));
+    line.extend_from_slice(&[
+        Span::from(" "),
+        Span::styled(" ↑ ↓ ", style_but),
+        Span::styled(" match ", style_desc),
+        Span::styled(
+            " search: ",
+            Style::default()
+                .fg(colors.filter.highlight)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(term, Style::default().fg(colors.filter.text)),
+    ]);
+    frame.render_widget(Line::from(line).bg(colors.filter.background), area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+
+    use insta::assert_snapshot;
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{
+        config::AppColors,
+        ui::{
+            FrameData, Status,
+            draw_blocks::tests::{get_result, test_setup},
+        },
+    };
+
+    #[test]
+    /// Log search row is drawn correctly, with the selected mode highlighted, and the term shown
+    fn test_draw_blocks_log_search_row() {
+        let mut setup = test_setup(80, 1, true, true);
+
+        setup.gui_state.lock().status_push(Status::LogSearch);
+        setup.app_data.lock().log_search_start();
+        setup.app_data.lock().log_search_mode_next();
+        setup.app_data.lock().log_search_push('a');
+        setup.app_data.lock().log_search_push('+');
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &AppColors::new(), f, &fd);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        for (_, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match result_cell_index {
+                    0..=4 | 12..=16 | 39..=43 => {
+                        assert_eq!(result_cell.bg, Color::Magenta);
+                        assert_eq!(result_cell.fg, Color::Black);
+                    }
+                    5..=11 | 17..=22 | 24..=30 | 44..=50 | 60..=61 => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                        assert_eq!(result_cell.fg, Color::Gray);
+                    }
+                    31..=37 => {
+                        assert_eq!(result_cell.bg, Color::Gray);
+                        assert_eq!(result_cell.fg, Color::Black);
+                    }
+                    51..=59 => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+}
@@ -20,7 +20,7 @@ use super::{RIGHT_ARROW, generate_block};
 pub fn draw(
     app_data: &Arc<Mutex<AppData>>,
     area: Rect,
-    colors: AppColors,
+    colors: &AppColors,
     f: &mut Frame,
     fd: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
@@ -102,7 +102,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -143,7 +143,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -176,7 +176,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -221,7 +221,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -261,7 +261,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -288,6 +288,92 @@ mod tests {
             }
         }
     }
+    #[test]
+    /// Merged logs are interleaved by timestamp, with each container name prefixed in its own color
+    fn test_draw_blocks_logs_merged() {
+        let mut setup = test_setup(40, 6, true, true);
+
+        for (index, seconds) in [[1, 4], [2, 3]].iter().enumerate() {
+            let logs = seconds
+                .iter()
+                .map(|i| format!("2025-01-01T00:00:0{i}.000000000Z line {i}"))
+                .collect::<Vec<_>>();
+            setup
+                .app_data
+                .lock()
+                .update_log_by_id(logs, &setup.ids[index]);
+        }
+        setup.app_data.lock().toggle_merged_logs();
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    &AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (1 | 4, 3..=16) => assert_eq!(result_cell.fg, Color::Cyan),
+                    (2 | 3, 3..=16) => assert_eq!(result_cell.fg, Color::Yellow),
+                    (1..=4, 17..=38) => assert_eq!(result_cell.fg, Color::Reset),
+                    _ => (),
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Log search matches are highlighted, the nearest match is selected, and the match counter is shown in the title
+    fn test_draw_blocks_logs_search() {
+        let mut setup = test_setup(60, 6, true, true);
+        insert_logs(&setup);
+        setup.app_data.lock().log_search_start();
+        setup.app_data.lock().log_search_push('2');
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    &AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (2, 8) => {
+                        assert_eq!(result_cell.bg, Color::Yellow);
+                        assert_eq!(result_cell.fg, Color::Black);
+                    }
+                    (1..=3, 3..=58) => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                        assert_eq!(result_cell.fg, Color::Reset);
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+
     #[test]
     /// Logs correct displayed, changing log state also draws correctly
     fn test_draw_blocks_logs_some_line_two() {
@@ -302,7 +388,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -318,7 +404,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -363,7 +449,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    AppColors::new(),
+                    &AppColors::new(),
                     f,
                     &fd,
                     &setup.gui_state,
@@ -393,7 +479,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -420,7 +506,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -453,7 +539,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -479,7 +565,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &setup.fd,
                     &setup.gui_state,
@@ -516,7 +602,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
@@ -547,7 +633,7 @@ mod tests {
                 super::draw(
                     &setup.app_data,
                     setup.area,
-                    colors,
+                    &colors,
                     f,
                     &fd,
                     &setup.gui_state,
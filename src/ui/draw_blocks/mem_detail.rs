@@ -0,0 +1,260 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::popup;
+use crate::{
+    app_data::{ByteStats, MemDetailTuple, MemoryDetail},
+    config::{AppColors, Keymap, MemoryFormula},
+    ui::gui_state::BoxLocation,
+};
+
+/// Width of the popup, including border
+const WIDTH: usize = 60;
+/// Width of the label column
+const LABEL_WIDTH: usize = 14;
+/// Width of the value column
+const VALUE_WIDTH: usize = 10;
+/// Width of the bar, a value equal to the total usage will fill the whole bar
+const BAR_WIDTH: usize = 28;
+
+/// Generate a line with a label, a value, and a bar relative to the total usage, n/a if the value isn't reported
+fn detail_line<'a>(
+    label: &str,
+    value: Option<u64>,
+    usage: u64,
+    style: Style,
+    highlight: Style,
+) -> Line<'a> {
+    let mut line = vec![Span::styled(format!("{label:<LABEL_WIDTH$}"), style)];
+    if let Some(value) = value {
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        let filled = if usage == 0 {
+            0
+        } else {
+            ((value as f64 / usage as f64).clamp(0.0, 1.0) * BAR_WIDTH as f64).round() as usize
+        };
+        line.extend([
+            Span::styled(
+                format!("{:>VALUE_WIDTH$}  ", ByteStats::new(value).to_string()),
+                style,
+            ),
+            Span::styled("█".repeat(filled), highlight),
+            Span::styled("░".repeat(BAR_WIDTH - filled), style),
+        ]);
+    } else {
+        line.push(Span::styled(format!("{:>VALUE_WIDTH$}", "n/a"), style));
+    }
+    Line::from(line)
+}
+
+/// Generate the lines of the memory breakdown
+fn detail_lines<'a>(
+    detail: &MemoryDetail,
+    memory_formula: MemoryFormula,
+    style: Style,
+    highlight: Style,
+) -> Vec<Line<'a>> {
+    let usage = detail.usage;
+    vec![
+        detail_line(
+            "current",
+            Some(detail.calculate(memory_formula)),
+            usage,
+            highlight,
+            highlight,
+        ),
+        Line::default(),
+        detail_line("usage", Some(usage), usage, style, highlight),
+        detail_line("anon", Some(detail.anon), usage, style, highlight),
+        detail_line("file", Some(detail.file), usage, style, highlight),
+        detail_line(
+            "  active",
+            Some(detail.active_file),
+            usage,
+            style,
+            highlight,
+        ),
+        detail_line(
+            "  inactive",
+            Some(detail.inactive_file),
+            usage,
+            style,
+            highlight,
+        ),
+        detail_line("kernel", detail.kernel, usage, style, highlight),
+        detail_line("shmem", detail.shmem, usage, style, highlight),
+    ]
+}
+
+/// Draw the memory breakdown of the selected container in the centre of the screen
+pub fn draw(
+    colors: &AppColors,
+    f: &mut Frame,
+    keymap: &Keymap,
+    mem_detail: &MemDetailTuple,
+    memory_formula: MemoryFormula,
+) {
+    let (name, detail, limit, _) = mem_detail;
+    let style = Style::default()
+        .bg(colors.popup_help.background)
+        .fg(colors.popup_help.text);
+    let highlight = Style::default()
+        .bg(colors.popup_help.background)
+        .fg(colors.popup_help.text_highlight)
+        .add_modifier(Modifier::BOLD);
+    let block = Block::default()
+        .title(format!(" memory {name} "))
+        .border_type(BorderType::Rounded)
+        .style(style)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!("{:<LABEL_WIDTH$}", "formula"), style),
+            Span::styled(memory_formula.description(), highlight),
+        ]),
+        Line::from(vec![
+            Span::styled(format!("{:<LABEL_WIDTH$}", "limit"), style),
+            Span::styled(format!("{limit:>VALUE_WIDTH$}"), style),
+        ]),
+        Line::default(),
+    ];
+    if let Some(detail) = detail {
+        lines.extend(detail_lines(detail, memory_formula, style, highlight));
+    } else {
+        lines.push(Line::from(Span::styled(
+            "detailed memory stats are not reported",
+            style,
+        )));
+    }
+
+    let area = popup::draw(lines.len() + 6, WIDTH, f.area(), BoxLocation::MiddleCentre);
+
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .horizontal_margin(2)
+        .split(area);
+
+    let instructions = Line::from(Span::styled(
+        format!(
+            "( {} ) or ( {} ) close",
+            keymap.toggle_mem_detail.0, keymap.clear.0
+        ),
+        style,
+    ));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(lines), split_popup[1]);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[3],
+    );
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::{
+        app_data::{ByteStats, ContainerName, MemoryDetail, RunningState, State},
+        config::{AppColors, Keymap, MemoryFormula},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    #[test]
+    /// Memory detail popup, with the current usage & the formula used, and a bar for each value
+    fn test_draw_blocks_mem_detail() {
+        let mut setup = test_setup(64, 20, true, true);
+        let colors = AppColors::new();
+        let detail = MemoryDetail {
+            usage: 1_000_000,
+            anon: 400_000,
+            file: 500_000,
+            active_file: 200_000,
+            inactive_file: 300_000,
+            kernel: Some(100_000),
+            shmem: None,
+        };
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &colors,
+                    f,
+                    &Keymap::new(),
+                    &(
+                        ContainerName::from("container_1"),
+                        Some(detail),
+                        ByteStats::new(2_000_000),
+                        State::Running(RunningState::Healthy),
+                    ),
+                    MemoryFormula::WorkingSet,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        // The usage bar is completely filled, in the highlight color
+        let bar = get_result(&setup)
+            .find(|(_, row)| {
+                row.iter()
+                    .map(ratatui::buffer::Cell::symbol)
+                    .collect::<String>()
+                    .contains("usage")
+            })
+            .map(|(_, row)| {
+                row.iter()
+                    .filter(|i| i.symbol() == "█")
+                    .map(|i| i.fg)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(bar.len(), 28);
+        assert!(bar.iter().all(|i| *i == colors.popup_help.text_highlight));
+    }
+
+    #[test]
+    /// Memory detail popup when docker doesn't report the detailed memory stats
+    fn test_draw_blocks_mem_detail_none() {
+        let mut setup = test_setup(64, 12, true, true);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &AppColors::new(),
+                    f,
+                    &Keymap::new(),
+                    &(
+                        ContainerName::from("container_1"),
+                        None,
+                        ByteStats::new(2_000_000),
+                        State::Paused,
+                    ),
+                    MemoryFormula::Docker,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
@@ -14,15 +14,24 @@ use super::{FrameData, GuiState, SelectablePanel, Status, gui_state::Region};
 pub mod charts;
 pub mod commands;
 pub mod containers;
+pub mod cpu_detail;
 pub mod delete_confirm;
 pub mod error;
+pub mod export;
 pub mod filter;
 pub mod headers;
 pub mod help;
+pub mod host_summary;
 pub mod info;
+pub mod log_detail;
+pub mod log_filter;
+pub mod log_search;
 pub mod logs;
+pub mod mem_detail;
+pub mod networks;
 pub mod popup;
 pub mod ports;
+pub mod recreate;
 
 pub const NAME_TEXT: &str = r#"
                           88                               
@@ -77,7 +86,7 @@ pub fn max_line_width(text: &str) -> usize {
 /// add custom title based on state of each panel
 fn generate_block<'a>(
     area: Rect,
-    colors: AppColors,
+    colors: &AppColors,
     fd: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
     panel: SelectablePanel,
@@ -126,9 +135,11 @@ pub mod tests {
     use ratatui::{Terminal, backend::TestBackend, layout::Rect, style::Color};
 
     use crate::{
-        app_data::{AppData, ContainerId, ContainerImage, ContainerName, ContainerPorts},
+        app_data::{
+            AppData, ContainerId, ContainerImage, ContainerName, ContainerPids, ContainerPorts,
+        },
         app_error::AppError,
-        tests::{gen_appdata, gen_containers},
+        tests::{gen_appdata, gen_containers, gen_networks},
         ui::{GuiState, Rerender, Status, draw_frame},
     };
 
@@ -146,12 +157,15 @@ pub mod tests {
     pub const BORDER_CHARS: [&str; 6] = ["╭", "╮", "─", "│", "╰", "╯"];
     pub const COLOR_RX: Color = Color::Rgb(255, 233, 193);
     pub const COLOR_TX: Color = Color::Rgb(205, 140, 140);
+    pub const COLOR_BLOCK_READ: Color = Color::Rgb(193, 220, 255);
+    pub const COLOR_BLOCK_WRITE: Color = Color::Rgb(205, 180, 219);
     pub const COLOR_ORANGE: Color = Color::Rgb(255, 178, 36);
 
     /// Create a FrameData struct from two Arc<mutex>'s, instead of from UI
     impl From<(&Arc<Mutex<AppData>>, &Arc<Mutex<GuiState>>)> for FrameData {
         fn from(data: (&Arc<Mutex<AppData>>, &Arc<Mutex<GuiState>>)) -> Self {
-            let (app_data, gui_data) = (data.0.lock(), data.1.lock());
+            let (mut app_data, gui_data) = (data.0.lock(), data.1.lock());
+            app_data.refresh_log_search();
 
             // let container_section_height = app_data.get_container_len();
             // let container_section_height = if container_section_height < 12 {
@@ -163,11 +177,43 @@ pub mod tests {
             let (filter_by, filter_term) = app_data.get_filter();
             Self {
                 chart_data: app_data.get_chart_data(),
+                io_chart_data: app_data.get_io_chart_data(),
+                net_chart_data: app_data.get_net_chart_data(),
+                networks: app_data.get_selected_networks(),
+                cpu_detail: gui_data
+                    .get_status()
+                    .contains(&Status::CpuDetail)
+                    .then(|| app_data.get_selected_cpu_detail())
+                    .flatten(),
+                mem_detail: gui_data
+                    .get_status()
+                    .contains(&Status::MemDetail)
+                    .then(|| app_data.get_selected_mem_detail())
+                    .flatten(),
+                show_net_total: app_data.get_show_net_total(),
+                cpu_mode: app_data.get_cpu_mode(),
+                memory_formula: app_data.config.memory_formula,
+                chart_points: app_data.get_chart_points(),
+                chart_cursor: gui_data.get_chart_cursor(),
+                chart_times: app_data.get_chart_times(),
+                overview_data: app_data.get_overview_chart_data(),
+                timezone: app_data.config.timezone.clone(),
+                show_mem_percent: app_data.get_show_mem_percent(),
+                memory_thresholds: (
+                    app_data.config.memory_warning_percentage,
+                    app_data.config.memory_critical_percentage,
+                ),
+                host_summary: app_data.get_host_summary(),
+                sparkline: app_data
+                    .config
+                    .sparkline
+                    .map(|metric| (metric, app_data.config.sparkline_width)),
                 color_logs: app_data.config.color_logs,
                 columns: app_data.get_width(),
                 // container_section_height,
                 container_title: app_data.get_container_title(),
                 delete_confirm: gui_data.get_delete_container(),
+                export: gui_data.get_export(),
                 filter_by,
                 filter_term: filter_term.cloned(),
                 has_containers: app_data.get_container_len() > 0,
@@ -176,10 +222,14 @@ pub mod tests {
                 info_text: gui_data.info_box_text.clone(),
                 is_loading: gui_data.is_loading(),
                 loading_icon: gui_data.get_loading().to_string(),
+                log_detail: gui_data.get_log_detail(),
+                log_filter: app_data.get_log_filter(),
                 log_height: gui_data.get_log_height(),
+                log_search: app_data.get_log_search(),
                 log_title: app_data.get_log_title(),
                 port_max_lens: app_data.get_longest_port(),
                 ports: app_data.get_selected_ports(),
+                recreate_form: gui_data.get_recreate_form(),
                 selected_panel: gui_data.get_selected_panel(),
                 sorted_by: app_data.get_sorted(),
                 status: gui_data.get_status(),
@@ -222,7 +272,7 @@ pub mod tests {
     pub fn get_result(
         setup: &TuiTestSetup,
         // w: u16,
-    ) -> std::iter::Enumerate<std::slice::Chunks<ratatui::buffer::Cell>> {
+    ) -> std::iter::Enumerate<std::slice::Chunks<'_, ratatui::buffer::Cell>> {
         setup
             .terminal
             .backend()
@@ -247,8 +297,10 @@ pub mod tests {
                 Some(i as f64),
                 Some(i * 10000),
                 i * 10000,
-                i,
-                i,
+                gen_networks(i, i),
+                (0, 0),
+                ContainerPids::default(),
+                0,
             );
         }
         for i in 1..=3 {
@@ -257,8 +309,10 @@ pub mod tests {
                 Some(i as f64),
                 Some(i * 10000),
                 i * 10000,
-                i,
-                i,
+                gen_networks(i, i),
+                (0, 0),
+                ContainerPids::default(),
+                0,
             );
         }
     }
@@ -276,7 +330,7 @@ pub mod tests {
         setup.app_data.lock().containers.items[0]
             .ports
             .push(ContainerPorts {
-                ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                ip: Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
                 private: 8003,
                 public: Some(8003),
             });
@@ -287,7 +341,7 @@ pub mod tests {
         setup
             .terminal
             .draw(|f| {
-                draw_frame(&setup.app_data, colors, &keymap, f, &fd, &setup.gui_state);
+                draw_frame(&setup.app_data, &colors, &keymap, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
@@ -305,7 +359,7 @@ pub mod tests {
         setup.app_data.lock().containers.items[1]
             .ports
             .push(ContainerPorts {
-                ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                ip: Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
                 private: 8003,
                 public: Some(8003),
             });
@@ -323,7 +377,7 @@ pub mod tests {
         setup
             .terminal
             .draw(|f| {
-                draw_frame(&setup.app_data, colors, &keymap, f, &fd, &setup.gui_state);
+                draw_frame(&setup.app_data, &colors, &keymap, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
@@ -340,7 +394,7 @@ pub mod tests {
         setup.app_data.lock().containers.items[0]
             .ports
             .push(ContainerPorts {
-                ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                ip: Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
                 private: 8003,
                 public: Some(8003),
             });
@@ -356,7 +410,7 @@ pub mod tests {
         setup
             .terminal
             .draw(|f| {
-                draw_frame(&setup.app_data, colors, &keymap, f, &fd, &setup.gui_state);
+                draw_frame(&setup.app_data, &colors, &keymap, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
@@ -373,7 +427,7 @@ pub mod tests {
         setup.app_data.lock().containers.items[0]
             .ports
             .push(ContainerPorts {
-                ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                ip: Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
                 private: 8003,
                 public: Some(8003),
             });
@@ -385,7 +439,7 @@ pub mod tests {
         setup
             .terminal
             .draw(|f| {
-                draw_frame(&setup.app_data, colors, &keymap, f, &fd, &setup.gui_state);
+                draw_frame(&setup.app_data, &colors, &keymap, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
@@ -402,7 +456,7 @@ pub mod tests {
         setup.app_data.lock().containers.items[0]
             .ports
             .push(ContainerPorts {
-                ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                ip: Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
                 private: 8003,
                 public: Some(8003),
             });
@@ -417,7 +471,7 @@ pub mod tests {
         setup
             .terminal
             .draw(|f| {
-                draw_frame(&setup.app_data, colors, &keymap, f, &fd, &setup.gui_state);
+                draw_frame(&setup.app_data, &colors, &keymap, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
@@ -434,7 +488,7 @@ pub mod tests {
         setup.app_data.lock().containers.items[0]
             .ports
             .push(ContainerPorts {
-                ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                ip: Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
                 private: 8003,
                 public: Some(8003),
             });
@@ -447,7 +501,7 @@ pub mod tests {
         setup
             .terminal
             .draw(|f| {
-                draw_frame(&setup.app_data, colors, &keymap, f, &fd, &setup.gui_state);
+                draw_frame(&setup.app_data, &colors, &keymap, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
@@ -464,7 +518,7 @@ pub mod tests {
         setup.app_data.lock().containers.items[0]
             .ports
             .push(ContainerPorts {
-                ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                ip: Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
                 private: 8003,
                 public: Some(8003),
             });
@@ -481,7 +535,7 @@ pub mod tests {
         setup
             .terminal
             .draw(|f| {
-                draw_frame(&setup.app_data, colors, &keymap, f, &fd, &setup.gui_state);
+                draw_frame(&setup.app_data, &colors, &keymap, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
@@ -498,7 +552,7 @@ pub mod tests {
         setup.app_data.lock().containers.items[0]
             .ports
             .push(ContainerPorts {
-                ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                ip: Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
                 private: 8003,
                 public: Some(8003),
             });
@@ -513,7 +567,7 @@ pub mod tests {
         setup
             .terminal
             .draw(|f| {
-                draw_frame(&setup.app_data, colors, &keymap, f, &fd, &setup.gui_state);
+                draw_frame(&setup.app_data, &colors, &keymap, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
@@ -530,7 +584,7 @@ pub mod tests {
         setup.app_data.lock().containers.items[0]
             .ports
             .push(ContainerPorts {
-                ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                ip: Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
                 private: 8003,
                 public: Some(8003),
             });
@@ -541,7 +595,7 @@ pub mod tests {
         setup
             .terminal
             .draw(|f| {
-                draw_frame(&setup.app_data, colors, &keymap, f, &fd, &setup.gui_state);
+                draw_frame(&setup.app_data, &colors, &keymap, f, &fd, &setup.gui_state);
             })
             .unwrap();
 
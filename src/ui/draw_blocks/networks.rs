@@ -0,0 +1,202 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    app_data::{NetworkInterface, State},
+    config::AppColors,
+    ui::FrameData,
+};
+
+/// Width of the rx & tx columns, enough for "999.99 kB" plus padding
+const BYTES_WIDTH: usize = 11;
+
+/// Get the networks title color, at the moment the color is only customizable if the container is alive
+const fn get_networks_title_color(colors: &AppColors, state: State) -> Color {
+    if state.is_alive() {
+        colors.chart_networks.title
+    } else {
+        state.get_color(colors)
+    }
+}
+
+/// Calculate the width of the name, errors, and dropped columns
+fn column_widths(networks: &[NetworkInterface]) -> (usize, usize, usize) {
+    networks.iter().fold((4, 8, 9), |acc, i| {
+        let (_, _, errors, dropped) = i.get_all();
+        (
+            acc.0.max(i.name.chars().count()),
+            acc.1.max(errors.chars().count() + 2),
+            acc.2.max(dropped.chars().count() + 2),
+        )
+    })
+}
+
+/// The total width required to display every interface, including the border
+pub fn get_width(networks: &[NetworkInterface]) -> u16 {
+    let (name, errors, dropped) = column_widths(networks);
+    u16::try_from(name + BYTES_WIDTH * 2 + errors + dropped + 2).unwrap_or(u16::MAX)
+}
+
+/// Display each network interface, with its cumulative rx, tx, errors, and dropped packets
+pub fn draw(area: Rect, colors: &AppColors, f: &mut Frame, fd: &FrameData) {
+    if let Some((networks, state)) = fd.networks.as_ref() {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(Style::new().fg(colors.chart_networks.border))
+            .title_alignment(Alignment::Center)
+            .title(Span::styled(
+                " networks ",
+                Style::default()
+                    .fg(get_networks_title_color(colors, *state))
+                    .bg(colors.chart_networks.background)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+        let (name, errors, dropped) = column_widths(networks);
+        let bytes = BYTES_WIDTH;
+
+        let mut output = vec![Line::from(
+            Span::from(format!(
+                "{:<name$}{:>bytes$}{:>bytes$}{:>errors$}{:>dropped$}",
+                "name", "↓ rx", "↑ tx", "errors", "dropped"
+            ))
+            .fg(colors.chart_networks.headings),
+        )];
+        for item in networks {
+            let strings = item.get_all();
+            output.push(Line::from(vec![
+                Span::from(format!("{:<name$}", item.name)).fg(colors.chart_networks.text),
+                Span::from(format!("{:>bytes$}", strings.0)).fg(colors.chart_networks.text),
+                Span::from(format!("{:>bytes$}", strings.1)).fg(colors.chart_networks.text),
+                Span::from(format!("{:>errors$}", strings.2)).fg(colors.chart_networks.text),
+                Span::from(format!("{:>dropped$}", strings.3)).fg(colors.chart_networks.text),
+            ]));
+        }
+        let paragraph = Paragraph::new(output)
+            .block(block)
+            .bg(colors.chart_networks.background);
+        f.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{
+        app_data::{NetworkInterface, State},
+        ui::{
+            FrameData,
+            draw_blocks::tests::{get_result, test_setup},
+        },
+    };
+
+    /// Two interfaces, one with errors & dropped packets
+    fn gen_networks() -> Vec<NetworkInterface> {
+        vec![
+            NetworkInterface {
+                name: "eth0".to_owned(),
+                rx_bytes: 1_200_000,
+                tx_bytes: 300_000,
+                ..NetworkInterface::default()
+            },
+            NetworkInterface {
+                name: "backend".to_owned(),
+                rx_bytes: 5_000,
+                tx_bytes: 40_000,
+                rx_errors: 3,
+                tx_errors: 0,
+                rx_dropped: 12,
+                tx_dropped: 1,
+            },
+        ]
+    }
+
+    #[test]
+    /// Width is calculated from the longest interface name, and the longest errors & dropped strings
+    fn test_draw_blocks_networks_get_width() {
+        assert_eq!(super::get_width(&[]), 45);
+        assert_eq!(super::get_width(&gen_networks()), 48);
+    }
+
+    #[test]
+    /// Networks panel isn't drawn when the selected container has no interfaces
+    fn test_draw_blocks_networks_none() {
+        let mut setup = test_setup(48, 5, true, true);
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        assert!(fd.networks.is_none());
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// Each interface is listed, with headings in yellow & text in white
+    fn test_draw_blocks_networks_multiple() {
+        let mut setup = test_setup(48, 5, true, true);
+        setup.app_data.lock().containers.items[0].networks = gen_networks();
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                assert_eq!(result_cell.bg, Color::Reset);
+                match (row_index, result_cell_index) {
+                    (0, 19..=28) => {
+                        assert_eq!(result_cell.fg, Color::Green);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    (1, 1..=46) => {
+                        assert_eq!(result_cell.fg, Color::Yellow);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::White);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// When the container is dead, the title uses the state color
+    fn test_draw_blocks_networks_dead() {
+        let mut setup = test_setup(48, 5, true, true);
+        setup.app_data.lock().containers.items[0].networks = gen_networks();
+        setup.app_data.lock().containers.items[0].state = State::Dead;
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
+            })
+            .unwrap();
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if let (0, 19..=28) = (row_index, result_cell_index) {
+                    assert_eq!(result_cell.fg, Color::Red);
+                }
+            }
+        }
+    }
+}
@@ -9,7 +9,7 @@ use ratatui::{
 use crate::{app_data::State, config::AppColors, ui::FrameData};
 
 /// Get the port title color, at the moment the color is only customizable if the container is alive
-const fn get_port_title_color(colors: AppColors, state: State) -> Color {
+const fn get_port_title_color(colors: &AppColors, state: State) -> Color {
     if state.is_alive() {
         colors.chart_ports.title
     } else {
@@ -18,7 +18,7 @@ const fn get_port_title_color(colors: AppColors, state: State) -> Color {
 }
 
 /// Display the ports in a formatted list
-pub fn draw(area: Rect, colors: AppColors, f: &mut Frame, fd: &FrameData) {
+pub fn draw(area: Rect, colors: &AppColors, f: &mut Frame, fd: &FrameData) {
     if let Some(ports) = fd.ports.as_ref() {
         let block = Block::default()
             .borders(Borders::ALL)
@@ -98,7 +98,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
@@ -137,7 +137,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
         // split
@@ -148,7 +148,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
 
@@ -182,7 +182,7 @@ mod tests {
         setup.app_data.lock().containers.items[0]
             .ports
             .push(ContainerPorts {
-                ip: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))),
+                ip: Some(IpAddr::V4(Ipv4Addr::LOCALHOST)),
                 private: 8003,
                 public: Some(8003),
             });
@@ -191,7 +191,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
@@ -231,7 +231,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
 
@@ -252,7 +252,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
 
@@ -271,7 +271,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, setup.app_data.lock().config.app_colors, f, &fd);
+                super::draw(setup.area, &setup.app_data.lock().config.app_colors, f, &fd);
             })
             .unwrap();
 
@@ -302,7 +302,7 @@ mod tests {
         setup
             .terminal
             .draw(|f| {
-                super::draw(setup.area, colors, f, &fd);
+                super::draw(setup.area, &colors, f, &fd);
             })
             .unwrap();
 
@@ -367,7 +367,7 @@ mod tests {
             setup
                 .terminal
                 .draw(|f| {
-                    super::draw(setup.area, colors, f, &fd);
+                    super::draw(setup.area, &colors, f, &fd);
                 })
                 .unwrap();
 
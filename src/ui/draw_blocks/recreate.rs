@@ -0,0 +1,226 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, Paragraph},
+};
+
+use super::popup;
+use crate::{
+    config::AppColors,
+    docker_data::{RecreateField, RecreateForm},
+    ui::gui_state::BoxLocation,
+};
+
+/// Text shown at the bottom of the recreate popup
+const INSTRUCTIONS: &str = "( enter ) recreate   ( esc ) cancel   ( ↑ ↓ ) select";
+
+/// Shown after any line that can't be parsed
+const INVALID: &str = " (invalid)";
+
+/// Draw the recreate form in the centre of the screen, each line can be edited, and the selected line is highlighted
+pub fn draw(colors: &AppColors, f: &mut Frame, form: &RecreateForm) {
+    let block = Block::default()
+        .title(format!(" Recreate {} ", form.name()))
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_recreate.background)
+                .fg(colors.popup_recreate.text),
+        )
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let label_width = [
+        RecreateField::Image,
+        RecreateField::Env,
+        RecreateField::Port,
+        RecreateField::Mount,
+    ]
+    .iter()
+    .map(|i| i.to_string().len())
+    .max()
+    .unwrap_or_default();
+
+    let lines = form
+        .lines
+        .items
+        .iter()
+        .map(|(field, value)| {
+            let mut spans = vec![
+                Span::styled(
+                    format!(" {field:<label_width$} "),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::from(value.clone()),
+            ];
+            if !RecreateForm::line_valid(*field, value) {
+                spans.push(Span::from(INVALID));
+            }
+            Line::from(spans)
+        })
+        .collect::<Vec<_>>();
+
+    let max_line_width = lines
+        .iter()
+        .map(Line::width)
+        .chain(std::iter::once(INSTRUCTIONS.chars().count()))
+        .max()
+        .unwrap_or_default()
+        + 8;
+
+    let items = lines.into_iter().map(ListItem::new).collect::<Vec<_>>();
+
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .fg(colors.popup_recreate.text_highlight)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let area = popup::draw(
+        form.lines.items.len() + 5,
+        max_line_width,
+        f.area(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .horizontal_margin(2)
+        .split(area);
+
+    let instructions = Paragraph::new(INSTRUCTIONS).alignment(Alignment::Center);
+
+    let mut state = form.lines.state.clone();
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_stateful_widget(list, split_popup[1], &mut state);
+    f.render_widget(instructions, split_popup[3]);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::service::{ContainerConfig, ContainerInspectResponse, HostConfig, PortBinding};
+    use insta::assert_snapshot;
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{
+        app_data::ContainerId,
+        config::AppColors,
+        docker_data::RecreateForm,
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    fn gen_form() -> RecreateForm {
+        RecreateForm::new(
+            ContainerId::from("1"),
+            ContainerInspectResponse {
+                name: Some("/container_1".to_owned()),
+                config: Some(ContainerConfig {
+                    image: Some("image_1:1.0".to_owned()),
+                    env: Some(vec!["A=1".to_owned()]),
+                    ..Default::default()
+                }),
+                host_config: Some(HostConfig {
+                    port_bindings: Some(HashMap::from([(
+                        "80/tcp".to_owned(),
+                        Some(vec![PortBinding {
+                            host_ip: None,
+                            host_port: Some("8080".to_owned()),
+                        }]),
+                    )])),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            None,
+        )
+    }
+
+    #[test]
+    /// Recreate form drawn in the centre, with the first line selected
+    fn test_draw_blocks_recreate() {
+        let mut setup = test_setup(70, 16, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+        let form = gen_form();
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&colors, f, &form);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (3, 7..=62) => {
+                        assert_eq!(result_cell.bg, Color::Cyan);
+                        assert_eq!(result_cell.fg, Color::White);
+                        assert!(result_cell.modifier.contains(Modifier::BOLD));
+                    }
+                    (2..=13, 5..=64) => {
+                        assert_eq!(result_cell.bg, Color::Cyan);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Invalid lines are marked, and custom colors are applied
+    fn test_draw_blocks_recreate_invalid_custom_color() {
+        let mut setup = test_setup(70, 16, true, true);
+        let mut colors = AppColors::new();
+        colors.popup_recreate.background = Color::Black;
+        colors.popup_recreate.text = Color::Red;
+        colors.popup_recreate.text_highlight = Color::Green;
+
+        let mut form = gen_form();
+        form.lines.next();
+        form.lines.next();
+        form.push('B');
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(&colors, f, &form);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (5, 7..=62) => {
+                        assert_eq!(result_cell.bg, Color::Black);
+                        assert_eq!(result_cell.fg, Color::Green);
+                    }
+                    (2..=13, 5..=64) => {
+                        assert_eq!(result_cell.bg, Color::Black);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -9,7 +9,8 @@ use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 use crate::{
-    app_data::{AppData, ContainerId, Header},
+    app_data::{AppData, ContainerId, Header, LogDetail},
+    docker_data::{ContainerExport, RecreateForm},
     exec::ExecMode,
 };
 
@@ -162,21 +163,33 @@ const FRAMES_LEN: u8 = 9;
 /// Various functions (e.g input handler), operate differently depending upon current Status
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Status {
+    ChartCursor,
+    CpuDetail,
     DeleteConfirm,
     DockerConnect,
     Error,
     Exec,
+    Export,
     Filter,
     Help,
     Init,
+    LogDetail,
+    LogFilter,
+    LogSearch,
     Logs,
+    MemDetail,
+    Recreate,
 }
 
 /// Global gui_state, stored in an Arc<Mutex>
 #[derive(Debug)]
 pub struct GuiState {
+    chart_cursor: usize,
     delete_container_id: Option<ContainerId>,
     exec_mode: Option<ExecMode>,
+    export: Option<ContainerExport>,
+    export_offset: usize,
+    export_view_height: usize,
     intersect_delete: HashMap<DeleteButton, Rect>,
     intersect_heading: HashMap<Header, Rect>,
     intersect_help: Option<Rect>,
@@ -184,7 +197,11 @@ pub struct GuiState {
     loading_handle: Option<JoinHandle<()>>,
     loading_index: u8,
     loading_set: HashSet<Uuid>,
+    log_detail: Option<LogDetail>,
+    log_detail_offset: usize,
+    log_detail_view: (usize, usize),
     log_height: u16,
+    recreate_form: Option<RecreateForm>,
     rerender: Arc<Rerender>,
     selected_panel: SelectablePanel,
     show_logs: bool,
@@ -194,8 +211,12 @@ pub struct GuiState {
 impl GuiState {
     pub fn new(redraw: &Arc<Rerender>, show_logs: bool) -> Self {
         Self {
+            chart_cursor: 0,
             delete_container_id: None,
             exec_mode: None,
+            export: None,
+            export_offset: 0,
+            export_view_height: 0,
             info_box_text: None,
             intersect_delete: HashMap::new(),
             intersect_heading: HashMap::new(),
@@ -204,7 +225,11 @@ impl GuiState {
             loading_handle: None,
             loading_index: 0,
             loading_set: HashSet::new(),
+            log_detail: None,
+            log_detail_offset: 0,
+            log_detail_view: (0, 0),
             log_height: 75,
+            recreate_form: None,
             rerender: Arc::clone(redraw),
             selected_panel: SelectablePanel::default(),
             show_logs,
@@ -351,6 +376,106 @@ impl GuiState {
         self.rerender.update();
     }
 
+    /// Get a clone of the recreate form, if one is currently being edited
+    pub fn get_recreate_form(&self) -> Option<RecreateForm> {
+        self.recreate_form.clone()
+    }
+
+    /// Set either a RecreateForm, or None, to the recreate_form field
+    /// If Some, will also insert the Recreate status into self.status
+    pub fn set_recreate_form(&mut self, form: Option<RecreateForm>) {
+        if form.is_some() {
+            self.status.insert(Status::Recreate);
+        } else {
+            self.status_del(Status::Recreate);
+        }
+        self.recreate_form = form;
+        self.rerender.update();
+    }
+
+    /// Remove the recreate form from self, and remove the Recreate status
+    pub fn take_recreate_form(&mut self) -> Option<RecreateForm> {
+        let form = self.recreate_form.take();
+        self.status_del(Status::Recreate);
+        form
+    }
+
+    /// Edit the recreate form in place, then rerender
+    pub fn edit_recreate_form(&mut self, edit: impl FnOnce(&mut RecreateForm)) {
+        if let Some(form) = self.recreate_form.as_mut() {
+            edit(form);
+            self.rerender.update();
+        }
+    }
+
+    /// Get a clone of the container export, and the current scroll offset
+    pub fn get_export(&self) -> Option<(ContainerExport, usize)> {
+        self.export.clone().map(|i| (i, self.export_offset))
+    }
+
+    /// Set either a ContainerExport, or None, to the export field, and reset the scroll offset
+    /// If Some, will also insert the Export status into self.status
+    pub fn set_export(&mut self, export: Option<ContainerExport>) {
+        if export.is_some() {
+            self.status.insert(Status::Export);
+        } else {
+            self.status_del(Status::Export);
+        }
+        self.export = export;
+        self.export_offset = 0;
+        self.rerender.update();
+    }
+
+    /// Set the number of export lines visible on screen, used to limit the scroll offset
+    pub const fn set_export_view_height(&mut self, height: usize) {
+        self.export_view_height = height;
+    }
+
+    /// Scroll the export popup by a given amount, limited so that the final line can't be scrolled past the bottom of the popup
+    pub fn export_scroll(&mut self, amount: isize) {
+        if let Some(export) = self.export.as_ref() {
+            let max = export.lines().len().saturating_sub(self.export_view_height);
+            self.export_offset = self.export_offset.saturating_add_signed(amount).min(max);
+            self.rerender.update();
+        }
+    }
+
+    /// Get a clone of the expanded log line, and the current scroll offset
+    pub fn get_log_detail(&self) -> Option<(LogDetail, usize)> {
+        self.log_detail.clone().map(|i| (i, self.log_detail_offset))
+    }
+
+    /// Set either a LogDetail, or None, to the log_detail field, and reset the scroll offset
+    /// If Some, will also insert the LogDetail status into self.status
+    pub fn set_log_detail(&mut self, log_detail: Option<LogDetail>) {
+        if log_detail.is_some() {
+            self.status.insert(Status::LogDetail);
+        } else {
+            self.status_del(Status::LogDetail);
+        }
+        self.log_detail = log_detail;
+        self.log_detail_offset = 0;
+        self.rerender.update();
+    }
+
+    /// Set the number of log detail lines visible on screen, and the total number of lines once wrapped, used to limit the scroll offset
+    pub const fn set_log_detail_view(&mut self, height: usize, total: usize) {
+        self.log_detail_view = (height, total);
+    }
+
+    /// Scroll the log detail popup by a given amount, limited so that the final line can't be scrolled past the bottom of the popup
+    pub fn log_detail_scroll(&mut self, amount: isize) {
+        if self.log_detail.is_some() {
+            let (height, total) = self.log_detail_view;
+            let max = total.saturating_sub(height);
+            self.log_detail_offset = self
+                .log_detail_offset
+                .saturating_add_signed(amount)
+                .min(max);
+            self.rerender.update();
+        }
+    }
+
     /// Return a copy of the Status HashSet
     pub fn get_status(&self) -> HashSet<Status> {
         self.status.clone()
@@ -367,11 +492,34 @@ impl GuiState {
             Status::Exec => {
                 self.exec_mode = None;
             }
+            Status::ChartCursor => {
+                self.chart_cursor = 0;
+            }
             _ => (),
         }
         self.rerender.update();
     }
 
+    /// Chart cursor methods
+    /// The offset of the chart cursor from the most recent point, only if the chart cursor is active
+    pub fn get_chart_cursor(&self) -> Option<usize> {
+        self.status
+            .contains(&Status::ChartCursor)
+            .then_some(self.chart_cursor)
+    }
+
+    /// Move the chart cursor back in time, limited by the number of points in the chart
+    pub fn chart_cursor_left(&mut self, points: usize) {
+        self.chart_cursor = (self.chart_cursor + 1).min(points.saturating_sub(1));
+        self.rerender.update();
+    }
+
+    /// Move the chart cursor forward in time, towards the most recent point
+    pub fn chart_cursor_right(&mut self) {
+        self.chart_cursor = self.chart_cursor.saturating_sub(1);
+        self.rerender.update();
+    }
+
     /// Inset the ExecMode into self, and set the Status as exec
     /// Using StatusPush with Status::Exec won't insert into the hash map
     /// To force self.exec_mode to be set
@@ -4,6 +4,7 @@ use crossterm::{
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
+use jiff::tz::TimeZone;
 use parking_lot::Mutex;
 use ratatui::{
     Frame, Terminal,
@@ -30,11 +31,13 @@ pub use self::color_match::*;
 pub use self::gui_state::{DeleteButton, GuiState, SelectablePanel, Status};
 use crate::{
     app_data::{
-        AppData, Columns, ContainerId, ContainerPorts, CpuTuple, FilterBy, Header, MemTuple,
-        SortedOrder, State,
+        AppData, Columns, ContainerId, ContainerName, ContainerPorts, CpuDetail, CpuTuple,
+        FilterBy, Header, HostSummaryTuple, LogDetail, LogFilter, MemDetailTuple, MemTuple,
+        NetworkInterface, OverviewTuple, RateTuple, SearchMode, SortedOrder, State,
     },
     app_error::AppError,
-    config::{AppColors, Keymap},
+    config::{AppColors, CpuMode, Keymap, MemoryFormula, SparklineMetric},
+    docker_data::{ContainerExport, RecreateForm},
     exec::TerminalSize,
     input_handler::InputMessages,
 };
@@ -152,7 +155,7 @@ impl Ui {
                     .terminal
                     .draw(|f| {
                         draw_blocks::error::draw(
-                            colors,
+                            &colors,
                             &AppError::DockerConnect,
                             f,
                             &keymap,
@@ -217,7 +220,14 @@ impl Ui {
                 if self
                     .terminal
                     .draw(|frame| {
-                        draw_frame(&self.app_data, colors, &keymap, frame, &fd, &self.gui_state);
+                        draw_frame(
+                            &self.app_data,
+                            &colors,
+                            &keymap,
+                            frame,
+                            &fd,
+                            &self.gui_state,
+                        );
                     })
                     .is_err()
                 {
@@ -225,30 +235,30 @@ impl Ui {
                 }
             }
 
-            if crossterm::event::poll(POLL_RATE).unwrap_or(false) {
-                if let Ok(event) = event::read() {
-                    if let Event::Key(key) = event {
-                        if key.kind == event::KeyEventKind::Press {
-                            self.input_tx
-                                .send(InputMessages::ButtonPress((key.code, key.modifiers)))
-                                .await
-                                .ok();
-                        }
-                    } else if let Event::Mouse(m) = event {
-                        match m.kind {
-                            event::MouseEventKind::Down(_)
-                            | event::MouseEventKind::ScrollDown
-                            | event::MouseEventKind::ScrollUp => {
-                                self.input_tx.send(InputMessages::MouseEvent(m)).await.ok();
-                            }
-                            _ => (),
+            if crossterm::event::poll(POLL_RATE).unwrap_or(false)
+                && let Ok(event) = event::read()
+            {
+                if let Event::Key(key) = event {
+                    if key.kind == event::KeyEventKind::Press {
+                        self.input_tx
+                            .send(InputMessages::ButtonPress((key.code, key.modifiers)))
+                            .await
+                            .ok();
+                    }
+                } else if let Event::Mouse(m) = event {
+                    match m.kind {
+                        event::MouseEventKind::Down(_)
+                        | event::MouseEventKind::ScrollDown
+                        | event::MouseEventKind::ScrollUp => {
+                            self.input_tx.send(InputMessages::MouseEvent(m)).await.ok();
                         }
-                    } else if let Event::Resize(_, _) = event {
-                        self.gui_state.lock().clear_area_map();
-                        // self.gui_state.lock().set_window_height(row);
-
-                        self.terminal.autoresize().ok();
+                        _ => (),
                     }
+                } else if let Event::Resize(_, _) = event {
+                    self.gui_state.lock().clear_area_map();
+                    // self.gui_state.lock().set_window_height(row);
+
+                    self.terminal.autoresize().ok();
                 }
             }
         }
@@ -272,16 +282,37 @@ impl Ui {
 #[allow(clippy::struct_excessive_bools)]
 pub struct FrameData {
     chart_data: Option<(CpuTuple, MemTuple)>,
+    chart_points: usize,
+    chart_cursor: Option<usize>,
+    chart_times: Vec<i64>,
+    overview_data: Option<OverviewTuple>,
+    timezone: Option<TimeZone>,
+    io_chart_data: Option<RateTuple>,
+    net_chart_data: Option<RateTuple>,
+    networks: Option<(Vec<NetworkInterface>, State)>,
+    cpu_detail: Option<(ContainerName, CpuDetail, State)>,
+    mem_detail: Option<MemDetailTuple>,
     color_logs: bool,
     columns: Columns,
     container_title: String,
     delete_confirm: Option<ContainerId>,
+    export: Option<(ContainerExport, usize)>,
     filter_by: FilterBy,
     filter_term: Option<String>,
     has_containers: bool,
     // container_section_height: u16,
+    log_detail: Option<(LogDetail, usize)>,
+    log_filter: Option<LogFilter>,
     log_height: u16,
+    log_search: Option<(String, SearchMode)>,
     show_logs: bool,
+    show_net_total: bool,
+    cpu_mode: CpuMode,
+    memory_formula: MemoryFormula,
+    show_mem_percent: bool,
+    memory_thresholds: (u8, u8),
+    sparkline: Option<(SparklineMetric, u8)>,
+    host_summary: Option<HostSummaryTuple>,
     has_error: Option<AppError>,
     info_text: Option<(String, Instant)>,
     is_loading: bool,
@@ -289,6 +320,7 @@ pub struct FrameData {
     log_title: String,
     port_max_lens: (usize, usize, usize),
     ports: Option<(Vec<ContainerPorts>, State)>,
+    recreate_form: Option<RecreateForm>,
     selected_panel: SelectablePanel,
     sorted_by: Option<(Header, SortedOrder)>,
     status: HashSet<Status>,
@@ -296,15 +328,49 @@ pub struct FrameData {
 
 impl From<&Ui> for FrameData {
     fn from(ui: &Ui) -> Self {
-        let (app_data, gui_data) = (ui.app_data.lock(), ui.gui_state.lock());
+        let (mut app_data, gui_data) = (ui.app_data.lock(), ui.gui_state.lock());
 
+        // Match any new log lines before the log title is generated
+        app_data.refresh_log_search();
         let (filter_by, filter_term) = app_data.get_filter();
         Self {
             chart_data: app_data.get_chart_data(),
+            io_chart_data: app_data.get_io_chart_data(),
+            net_chart_data: app_data.get_net_chart_data(),
+            networks: app_data.get_selected_networks(),
+            cpu_detail: gui_data
+                .get_status()
+                .contains(&Status::CpuDetail)
+                .then(|| app_data.get_selected_cpu_detail())
+                .flatten(),
+            mem_detail: gui_data
+                .get_status()
+                .contains(&Status::MemDetail)
+                .then(|| app_data.get_selected_mem_detail())
+                .flatten(),
+            show_net_total: app_data.get_show_net_total(),
+            cpu_mode: app_data.get_cpu_mode(),
+            memory_formula: app_data.config.memory_formula,
+            chart_points: app_data.get_chart_points(),
+            chart_cursor: gui_data.get_chart_cursor(),
+            chart_times: app_data.get_chart_times(),
+            overview_data: app_data.get_overview_chart_data(),
+            timezone: app_data.config.timezone.clone(),
+            show_mem_percent: app_data.get_show_mem_percent(),
+            memory_thresholds: (
+                app_data.config.memory_warning_percentage,
+                app_data.config.memory_critical_percentage,
+            ),
+            host_summary: app_data.get_host_summary(),
+            sparkline: app_data
+                .config
+                .sparkline
+                .map(|metric| (metric, app_data.config.sparkline_width)),
             color_logs: app_data.config.color_logs,
             columns: app_data.get_width(),
             container_title: app_data.get_container_title(),
             delete_confirm: gui_data.get_delete_container(),
+            export: gui_data.get_export(),
             filter_by,
             filter_term: filter_term.cloned(),
             has_containers: app_data.get_container_len() > 0,
@@ -313,10 +379,14 @@ impl From<&Ui> for FrameData {
             is_loading: gui_data.is_loading(),
             show_logs: gui_data.get_show_logs(),
             loading_icon: gui_data.get_loading().to_string(),
+            log_detail: gui_data.get_log_detail(),
+            log_filter: app_data.get_log_filter(),
             log_height: gui_data.get_log_height(),
+            log_search: app_data.get_log_search(),
             log_title: app_data.get_log_title(),
             port_max_lens: app_data.get_longest_port(),
             ports: app_data.get_selected_ports(),
+            recreate_form: gui_data.get_recreate_form(),
             selected_panel: gui_data.get_selected_panel(),
             sorted_by: app_data.get_sorted(),
             status: gui_data.get_status(),
@@ -325,28 +395,51 @@ impl From<&Ui> for FrameData {
 }
 
 /// Draw the main ui to a frame of the terminal
+#[allow(clippy::too_many_lines)]
 fn draw_frame(
     app_data: &Arc<Mutex<AppData>>,
-    colors: AppColors,
+    colors: &AppColors,
     keymap: &Keymap,
     f: &mut Frame,
     fd: &FrameData,
     gui_state: &Arc<Mutex<GuiState>>,
 ) {
+    // The optional host summary bar is drawn above the headers bar
+    let header_height = if fd.host_summary.is_some() { 2 } else { 1 };
+    // The container filter, log filter, and log search, bars are all drawn below the main section
+    let input_bar = [Status::Filter, Status::LogFilter, Status::LogSearch]
+        .iter()
+        .any(|i| fd.status.contains(i));
     let whole_layout = Layout::default()
         .direction(Direction::Vertical)
-        .constraints(if fd.status.contains(&Status::Filter) {
-            vec![Constraint::Max(1), Constraint::Min(1), Constraint::Max(1)]
+        .constraints(if input_bar {
+            vec![
+                Constraint::Max(header_height),
+                Constraint::Min(1),
+                Constraint::Max(1),
+            ]
         } else {
-            vec![Constraint::Max(1), Constraint::Min(1)]
+            vec![Constraint::Max(header_height), Constraint::Min(1)]
         })
         .split(f.area());
 
-    draw_blocks::headers::draw(whole_layout[0], colors, f, fd, gui_state, keymap);
+    let header_section = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Max(header_height - 1), Constraint::Max(1)])
+        .split(whole_layout[0]);
+
+    draw_blocks::host_summary::draw(header_section[0], colors, f, fd);
+    draw_blocks::headers::draw(header_section[1], colors, f, fd, gui_state, keymap);
 
-    // If required, draw filter bar
+    // If required, draw filter bar, log filter bar, or log search bar
     if let Some(rect) = whole_layout.get(2) {
-        draw_blocks::filter::draw(*rect, colors, f, fd);
+        if fd.status.contains(&Status::LogSearch) {
+            draw_blocks::log_search::draw(*rect, colors, f, fd);
+        } else if fd.status.contains(&Status::LogFilter) {
+            draw_blocks::log_filter::draw(*rect, colors, f, fd);
+        } else {
+            draw_blocks::filter::draw(*rect, colors, f, fd);
+        }
     }
 
     let upper_main = Layout::default()
@@ -412,13 +505,43 @@ fn draw_frame(
             u16::try_from(fd.port_max_lens.0 + fd.port_max_lens.1 + fd.port_max_lens.2 + 2)
                 .unwrap_or(26);
 
+        // Only show the networks section if the selected container has at least one interface
+        let networks_len = fd.networks.as_ref().map_or(0, |(networks, _)| {
+            draw_blocks::networks::get_width(networks)
+        });
+
         let lower = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Min(1), Constraint::Max(ports_len)])
+            .constraints([
+                Constraint::Min(1),
+                Constraint::Max(networks_len),
+                Constraint::Max(ports_len),
+            ])
             .split(upper_main[1]);
 
         draw_blocks::charts::draw(lower[0], colors, f, fd);
-        draw_blocks::ports::draw(lower[1], colors, f, fd);
+        draw_blocks::networks::draw(lower[1], colors, f, fd);
+        draw_blocks::ports::draw(lower[2], colors, f, fd);
+    }
+
+    if let Some((export, offset)) = fd.export.as_ref() {
+        draw_blocks::export::draw(colors, f, gui_state, keymap, export, *offset);
+    }
+
+    if let Some((log_detail, offset)) = fd.log_detail.as_ref() {
+        draw_blocks::log_detail::draw(colors, f, gui_state, keymap, log_detail, *offset);
+    }
+
+    if let Some((name, cpu_detail, state)) = fd.cpu_detail.as_ref() {
+        draw_blocks::cpu_detail::draw(colors, f, keymap, name, cpu_detail, *state, fd.chart_points);
+    }
+
+    if let Some(mem_detail) = fd.mem_detail.as_ref() {
+        draw_blocks::mem_detail::draw(colors, f, keymap, mem_detail, fd.memory_formula);
+    }
+
+    if let Some(form) = fd.recreate_form.as_ref() {
+        draw_blocks::recreate::draw(colors, f, form);
     }
 
     if let Some((text, instant)) = fd.info_text.as_ref() {